@@ -0,0 +1,138 @@
+use crate::{
+    rest::error::{ErrorCode, ErrorDetails},
+    tools::{rate_limit::RateLimiter, retry::RetryPolicy},
+};
+use std::{sync::Arc, time::Duration};
+
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    pub api_key: String,
+    pub base_url: String,
+    pub timeout: Option<Duration>,
+    pub connect_timeout: Option<Duration>,
+    pub user_agent: String,
+    pub pool_max_idle_per_host: Option<usize>,
+    pub requests_per_minute: Option<u32>,
+    pub retry: RetryPolicy,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: String::new(),
+            base_url: PolygonClient::DEFAULT_BASE_URL.to_string(),
+            timeout: Some(Duration::from_secs(30)),
+            connect_timeout: Some(Duration::from_secs(10)),
+            user_agent: format!("polygon_rs_api/{}", env!("CARGO_PKG_VERSION")),
+            pool_max_idle_per_host: None,
+            requests_per_minute: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PolygonClient {
+    pub api_key: String,
+    pub base_url: String,
+    pub http: reqwest::Client,
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    pub retry: RetryPolicy,
+}
+
+impl PolygonClient {
+    pub const DEFAULT_BASE_URL: &str = "https://api.polygon.io";
+
+    pub fn new(api_key: &str) -> Result<Self, ErrorCode> {
+        Self::with_config(ClientConfig {
+            api_key: api_key.to_string(),
+            ..ClientConfig::default()
+        })
+    }
+
+    pub fn with_config(config: ClientConfig) -> Result<Self, ErrorCode> {
+        let mut builder = reqwest::Client::builder().user_agent(config.user_agent);
+        if let Some(timeout) = config.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(max_idle) = config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        let http = match builder.build() {
+            Ok(http) => http,
+            Err(e) => {
+                return Err(ErrorCode::ClientError(ErrorDetails {
+                    source: Some(Arc::new(e)),
+                    ..ErrorDetails::default()
+                }))
+            }
+        };
+        Ok(Self {
+            api_key: config.api_key,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            http,
+            rate_limiter: config
+                .requests_per_minute
+                .map(|limit| Arc::new(RateLimiter::per_minute(limit))),
+            retry: config.retry,
+        })
+    }
+
+    //Polygon returns next_url pointing at api.polygon.io, keep paging against the configured host
+    pub fn rebase_url(&self, url: &str) -> String {
+        match url.strip_prefix(Self::DEFAULT_BASE_URL) {
+            Some(path) => format!("{}{}", self.base_url, path),
+            None => url.to_string(),
+        }
+    }
+}
+
+#[test]
+fn test_with_config() {
+    let client = PolygonClient::with_config(ClientConfig {
+        api_key: String::from("apiKey"),
+        base_url: String::from("http://localhost:8080/"),
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    assert_eq!(client.api_key, "apiKey");
+    assert_eq!(client.base_url, "http://localhost:8080");
+}
+
+#[test]
+fn test_with_config_error() {
+    use std::error::Error;
+
+    let error = PolygonClient::with_config(ClientConfig {
+        api_key: String::from("apiKey"),
+        user_agent: String::from("polygon\nrs"),
+        ..ClientConfig::default()
+    })
+    .unwrap_err();
+    assert!(matches!(error, ErrorCode::ClientError(_)));
+    assert!(error.source().is_some());
+    assert!(error
+        .to_string()
+        .starts_with("There is an issue building the HTTP client: "));
+}
+
+#[test]
+fn test_rebase_url() {
+    let client = PolygonClient::with_config(ClientConfig {
+        api_key: String::from("apiKey"),
+        base_url: String::from("http://localhost:8080"),
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    assert_eq!(
+        client.rebase_url("https://api.polygon.io/v3/trades/AAPL?cursor=abc"),
+        "http://localhost:8080/v3/trades/AAPL?cursor=abc"
+    );
+    assert_eq!(
+        client.rebase_url("https://example.com/v3/trades/AAPL?cursor=abc"),
+        "https://example.com/v3/trades/AAPL?cursor=abc"
+    );
+}
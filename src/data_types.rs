@@ -21,6 +21,38 @@ pub mod underlying_asset;
 pub mod universal;
 pub mod option_contract;
 pub mod additional_underlying;
+pub mod reference_ticker;
+pub mod address;
+pub mod branding;
+pub mod ticker_details;
+pub mod dividend;
+pub mod split;
+pub mod line_item;
+pub mod income_statement;
+pub mod balance_sheet;
+pub mod cash_flow_statement;
+pub mod comprehensive_income;
+pub mod financial;
+pub mod market_holiday;
+pub mod publisher;
+pub mod insight;
+pub mod news_article;
+pub mod exchange;
+pub mod sip_mapping;
+pub mod update_rule;
+pub mod update_rules;
+pub mod condition;
+pub mod reference_ticker_type;
+pub mod related_company;
+pub mod ticker_change;
+pub mod ticker_event;
+pub mod ticker_events;
+pub mod connection_status;
+pub mod aggregate;
+pub mod fair_market_value;
+pub mod index_value;
+pub mod bollinger_band;
+pub mod stochastic;
 
 pub trait Parse {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self;
@@ -130,6 +162,10 @@ pub trait Parse {
             if !map.contains_key(key) {
                 continue;
             }
+            //WebSocket quotes send a single condition instead of an array
+            if let Some(i) = map.get(key).and_then(|v| v.as_i64()) {
+                return Some(vec![i]);
+            }
             let mut i64_array = Vec::new();
             if let Some(values) = map.get(key).and_then(|v| v.as_array()) {
                 for i64 in values {
@@ -144,6 +180,56 @@ pub trait Parse {
         None
     }
 
+    fn array_string_parse(
+        map: &serde_json::Map<String, serde_json::Value>,
+        keys: Vec<&str>,
+    ) -> Option<Vec<String>> {
+        for key in keys {
+            if !map.contains_key(key) {
+                continue;
+            }
+            let mut string_array = Vec::new();
+            if let Some(values) = map.get(key).and_then(|v| v.as_array()) {
+                for string in values {
+                    if let Some(s) = string.as_str() {
+                        string_array.push(s.to_string());
+                    }
+                }
+            };
+            return Some(string_array);
+        }
+        None
+    }
+
+    //Objects under every key not in known, for payloads whose keys are open ended
+    fn remaining_parse<T: Parse>(
+        map: &serde_json::Map<String, serde_json::Value>,
+        known: &[&str],
+    ) -> HashMap<String, T> {
+        map.iter()
+            .filter(|(key, _)| !known.contains(&key.as_str()))
+            .filter_map(|(key, value)| value.as_object().map(|v| (key.to_string(), T::parse(v))))
+            .collect()
+    }
+
+    fn hashmap_string_parse(
+        map: &serde_json::Map<String, serde_json::Value>,
+        keys: Vec<&str>,
+    ) -> Option<HashMap<String, String>> {
+        for key in keys {
+            if !map.contains_key(key) {
+                continue;
+            }
+            return map.get(key).and_then(|v| v.as_object()).map(|object| {
+                object
+                    .iter()
+                    .filter_map(|(k, v)| v.as_str().map(|v| (k.to_string(), v.to_string())))
+                    .collect()
+            });
+        }
+        None
+    }
+
     fn hashmap_parse(
         map: &serde_json::Map<String, serde_json::Value>,
         keys: Vec<&str>,
@@ -152,7 +238,7 @@ pub trait Parse {
             if !map.contains_key(key) {
                 continue;
             }
-            return match map.get("size").and_then(|v| v.as_object()) {
+            return match map.get(key).and_then(|v| v.as_object()) {
                 Some(size_object) => {
                     let mut ask_hash_map = HashMap::new();
                     size_object.keys().for_each(|v| {
@@ -10,9 +10,9 @@ pub struct AdditionalUnderlying {
 
 impl Parse for AdditionalUnderlying {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
-        let amount = Self::f64_parse(map, vec!["a"]);
-        let asset = Self::string_parse(map, vec!["A"]);
-        let underlying = Self::string_parse(map, vec!["u"]);
+        let amount = Self::f64_parse(map, vec!["a", "amount"]);
+        let asset = Self::string_parse(map, vec!["A", "type"]);
+        let underlying = Self::string_parse(map, vec!["u", "underlying"]);
         AdditionalUnderlying {
             amount,
             assest: asset,
@@ -0,0 +1,28 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Address {
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub city: Option<String>,
+    pub postal_code: Option<String>,
+    pub state: Option<String>,
+}
+
+impl Parse for Address {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let address1 = Self::string_parse(map, vec!["address1"]);
+        let address2 = Self::string_parse(map, vec!["address2"]);
+        let city = Self::string_parse(map, vec!["city"]);
+        let postal_code = Self::string_parse(map, vec!["postal_code"]);
+        let state = Self::string_parse(map, vec!["state"]);
+        Address {
+            address1,
+            address2,
+            city,
+            postal_code,
+            state,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::data_types::{bar::Bar, Parse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Aggregate {
+    pub accumulated_volume: Option<f64>,
+    pub average_trade_size: Option<f64>,
+    pub close: Option<f64>,
+    pub day_volume_weighted: Option<f64>,
+    pub end_timestamp: Option<i64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub official_open: Option<f64>,
+    pub open: Option<f64>,
+    pub otc: Option<bool>,
+    pub start_timestamp: Option<i64>,
+    pub ticker: Option<String>,
+    pub volume: Option<f64>,
+    pub volume_weighted: Option<f64>,
+}
+
+impl Aggregate {
+    pub fn to_bar(&self) -> Bar {
+        Bar {
+            excahnge: self.ticker.clone(),
+            close: self.close,
+            high: self.high,
+            low: self.low,
+            transactions: None,
+            open: self.open,
+            timestamp: self.start_timestamp,
+            volume: self.volume,
+            volume_weighted: self.volume_weighted,
+            otc: self.otc,
+        }
+    }
+}
+
+impl Parse for Aggregate {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let accumulated_volume = Self::f64_parse(map, vec!["av"]);
+        let average_trade_size = Self::f64_parse(map, vec!["z"]);
+        let close = Self::f64_parse(map, vec!["c"]);
+        let day_volume_weighted = Self::f64_parse(map, vec!["a"]);
+        let end_timestamp = Self::i64_parse(map, vec!["e"]);
+        let high = Self::f64_parse(map, vec!["h"]);
+        let low = Self::f64_parse(map, vec!["l"]);
+        let official_open = Self::f64_parse(map, vec!["op"]);
+        let open = Self::f64_parse(map, vec!["o"]);
+        let otc = Self::bool_parse(map, vec!["otc"]);
+        let start_timestamp = Self::i64_parse(map, vec!["s"]);
+        let ticker = Self::string_parse(map, vec!["sym", "pair"]);
+        let volume = Self::f64_parse(map, vec!["v"]);
+        let volume_weighted = Self::f64_parse(map, vec!["vw"]);
+        Aggregate {
+            accumulated_volume,
+            average_trade_size,
+            close,
+            day_volume_weighted,
+            end_timestamp,
+            high,
+            low,
+            official_open,
+            open,
+            otc,
+            start_timestamp,
+            ticker,
+            volume,
+            volume_weighted,
+        }
+    }
+}
+
+#[test]
+fn test_aggregate_parse() {
+    let data = serde_json::json!({
+        "ev": "AM",
+        "sym": "GTE",
+        "v": 4110,
+        "av": 9470157,
+        "op": 0.4372,
+        "vw": 0.4488,
+        "o": 0.4488,
+        "c": 0.4486,
+        "h": 0.4489,
+        "l": 0.4486,
+        "a": 0.4352,
+        "z": 685,
+        "s": 1610144640000i64,
+        "e": 1610144700000i64
+    });
+    let aggregate = Aggregate::parse(&data.as_object().unwrap());
+    assert_eq!(aggregate.ticker.clone().unwrap(), "GTE");
+    assert_eq!(aggregate.volume.unwrap(), 4110.0);
+    assert_eq!(aggregate.accumulated_volume.unwrap(), 9470157.0);
+    assert_eq!(aggregate.official_open.unwrap(), 0.4372);
+    assert_eq!(aggregate.volume_weighted.unwrap(), 0.4488);
+    assert_eq!(aggregate.day_volume_weighted.unwrap(), 0.4352);
+    assert_eq!(aggregate.average_trade_size.unwrap(), 685.0);
+    assert_eq!(aggregate.start_timestamp.unwrap(), 1610144640000);
+    assert_eq!(aggregate.end_timestamp.unwrap(), 1610144700000);
+    let bar = aggregate.to_bar();
+    assert_eq!(bar.open.unwrap(), 0.4488);
+    assert_eq!(bar.high.unwrap(), 0.4489);
+    assert_eq!(bar.low.unwrap(), 0.4486);
+    assert_eq!(bar.close.unwrap(), 0.4486);
+    assert_eq!(bar.volume.unwrap(), 4110.0);
+    assert_eq!(bar.timestamp.unwrap(), 1610144640000);
+    let crypto = serde_json::json!({
+        "ev": "XA",
+        "pair": "BCD-USD",
+        "v": 951.6112,
+        "vw": 0.7756,
+        "z": 73,
+        "o": 0.772,
+        "c": 0.784,
+        "h": 0.784,
+        "l": 0.771,
+        "s": 1610144640000i64,
+        "e": 1610144700000i64
+    });
+    let aggregate = Aggregate::parse(&crypto.as_object().unwrap());
+    assert_eq!(aggregate.ticker.unwrap(), "BCD-USD");
+    assert_eq!(aggregate.volume.unwrap(), 951.6112);
+    assert_eq!(aggregate.accumulated_volume, None);
+}
@@ -10,8 +10,8 @@ pub struct Ask {
 
 impl Parse for Ask {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
-        let price = Self::f64_parse(map, vec!["price"]);
-        let size = Self::hashmap_parse(map, vec!["size"]);
+        let price = Self::f64_parse(map, vec!["price", "p"]);
+        let size = Self::hashmap_parse(map, vec!["size", "x"]);
         Ask { price, size }
     }
 }
@@ -0,0 +1,110 @@
+use crate::data_types::{line_item::LineItem, Parse};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BalanceSheet {
+    pub accounts_payable: Option<LineItem>,
+    pub assets: Option<LineItem>,
+    pub current_assets: Option<LineItem>,
+    pub current_liabilities: Option<LineItem>,
+    pub equity: Option<LineItem>,
+    pub equity_attributable_to_noncontrolling_interest: Option<LineItem>,
+    pub equity_attributable_to_parent: Option<LineItem>,
+    pub fixed_assets: Option<LineItem>,
+    pub intangible_assets: Option<LineItem>,
+    pub inventory: Option<LineItem>,
+    pub liabilities: Option<LineItem>,
+    pub liabilities_and_equity: Option<LineItem>,
+    pub long_term_debt: Option<LineItem>,
+    pub noncurrent_assets: Option<LineItem>,
+    pub noncurrent_liabilities: Option<LineItem>,
+    pub other_current_assets: Option<LineItem>,
+    pub other_current_liabilities: Option<LineItem>,
+    pub other_noncurrent_assets: Option<LineItem>,
+    pub other_noncurrent_liabilities: Option<LineItem>,
+    pub prepaid_expenses: Option<LineItem>,
+    pub wages: Option<LineItem>,
+    //Line items Polygon returns that have no field above, keyed by their Polygon name
+    pub additional_line_items: HashMap<String, LineItem>,
+}
+
+impl BalanceSheet {
+    const LINE_ITEMS: &'static [&'static str] = &[
+        "accounts_payable",
+        "assets",
+        "current_assets",
+        "current_liabilities",
+        "equity",
+        "equity_attributable_to_noncontrolling_interest",
+        "equity_attributable_to_parent",
+        "fixed_assets",
+        "intangible_assets",
+        "inventory",
+        "liabilities",
+        "liabilities_and_equity",
+        "long_term_debt",
+        "noncurrent_assets",
+        "noncurrent_liabilities",
+        "other_current_assets",
+        "other_current_liabilities",
+        "other_noncurrent_assets",
+        "other_noncurrent_liabilities",
+        "prepaid_expenses",
+        "wages",
+    ];
+}
+
+impl Parse for BalanceSheet {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let accounts_payable = Self::object_parse(map, vec!["accounts_payable"]);
+        let assets = Self::object_parse(map, vec!["assets"]);
+        let current_assets = Self::object_parse(map, vec!["current_assets"]);
+        let current_liabilities = Self::object_parse(map, vec!["current_liabilities"]);
+        let equity = Self::object_parse(map, vec!["equity"]);
+        let equity_attributable_to_noncontrolling_interest =
+            Self::object_parse(map, vec!["equity_attributable_to_noncontrolling_interest"]);
+        let equity_attributable_to_parent =
+            Self::object_parse(map, vec!["equity_attributable_to_parent"]);
+        let fixed_assets = Self::object_parse(map, vec!["fixed_assets"]);
+        let intangible_assets = Self::object_parse(map, vec!["intangible_assets"]);
+        let inventory = Self::object_parse(map, vec!["inventory"]);
+        let liabilities = Self::object_parse(map, vec!["liabilities"]);
+        let liabilities_and_equity = Self::object_parse(map, vec!["liabilities_and_equity"]);
+        let long_term_debt = Self::object_parse(map, vec!["long_term_debt"]);
+        let noncurrent_assets = Self::object_parse(map, vec!["noncurrent_assets"]);
+        let noncurrent_liabilities = Self::object_parse(map, vec!["noncurrent_liabilities"]);
+        let other_current_assets = Self::object_parse(map, vec!["other_current_assets"]);
+        let other_current_liabilities = Self::object_parse(map, vec!["other_current_liabilities"]);
+        let other_noncurrent_assets = Self::object_parse(map, vec!["other_noncurrent_assets"]);
+        let other_noncurrent_liabilities =
+            Self::object_parse(map, vec!["other_noncurrent_liabilities"]);
+        let prepaid_expenses = Self::object_parse(map, vec!["prepaid_expenses"]);
+        let wages = Self::object_parse(map, vec!["wages"]);
+        let additional_line_items = Self::remaining_parse(map, Self::LINE_ITEMS);
+        BalanceSheet {
+            accounts_payable,
+            assets,
+            current_assets,
+            current_liabilities,
+            equity,
+            equity_attributable_to_noncontrolling_interest,
+            equity_attributable_to_parent,
+            fixed_assets,
+            intangible_assets,
+            inventory,
+            liabilities,
+            liabilities_and_equity,
+            long_term_debt,
+            noncurrent_assets,
+            noncurrent_liabilities,
+            other_current_assets,
+            other_current_liabilities,
+            other_noncurrent_assets,
+            other_noncurrent_liabilities,
+            prepaid_expenses,
+            wages,
+            additional_line_items,
+        }
+    }
+}
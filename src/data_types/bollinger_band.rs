@@ -0,0 +1,40 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BollingerBand {
+    pub lower: Option<f64>,
+    pub middle: Option<f64>,
+    pub timestamp: Option<i64>,
+    pub upper: Option<f64>,
+}
+
+impl Parse for BollingerBand {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let lower = Self::f64_parse(map, vec!["lower"]);
+        let middle = Self::f64_parse(map, vec!["middle"]);
+        let timestamp = Self::i64_parse(map, vec!["timestamp"]);
+        let upper = Self::f64_parse(map, vec!["upper"]);
+        BollingerBand {
+            lower,
+            middle,
+            timestamp,
+            upper,
+        }
+    }
+}
+
+#[test]
+fn test_bollinger_band_parse() {
+    let data = serde_json::json!({
+        "lower": 1.23,
+        "middle": 2.34,
+        "timestamp": 164545545,
+        "upper": 3.45
+    });
+    let bollinger_band = BollingerBand::parse(&data.as_object().unwrap());
+    assert_eq!(bollinger_band.lower.unwrap(), 1.23);
+    assert_eq!(bollinger_band.middle.unwrap(), 2.34);
+    assert_eq!(bollinger_band.timestamp.unwrap(), 164545545);
+    assert_eq!(bollinger_band.upper.unwrap(), 3.45);
+}
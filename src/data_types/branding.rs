@@ -0,0 +1,16 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Branding {
+    pub icon_url: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+impl Parse for Branding {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let icon_url = Self::string_parse(map, vec!["icon_url"]);
+        let logo_url = Self::string_parse(map, vec!["logo_url"]);
+        Branding { icon_url, logo_url }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::data_types::{line_item::LineItem, Parse};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CashFlowStatement {
+    pub net_cash_flow: Option<LineItem>,
+    pub net_cash_flow_continuing: Option<LineItem>,
+    pub net_cash_flow_from_financing_activities: Option<LineItem>,
+    pub net_cash_flow_from_financing_activities_continuing: Option<LineItem>,
+    pub net_cash_flow_from_investing_activities: Option<LineItem>,
+    pub net_cash_flow_from_investing_activities_continuing: Option<LineItem>,
+    pub net_cash_flow_from_operating_activities: Option<LineItem>,
+    pub net_cash_flow_from_operating_activities_continuing: Option<LineItem>,
+    //Line items Polygon returns that have no field above, keyed by their Polygon name
+    pub additional_line_items: HashMap<String, LineItem>,
+}
+
+impl CashFlowStatement {
+    const LINE_ITEMS: &'static [&'static str] = &[
+        "net_cash_flow",
+        "net_cash_flow_continuing",
+        "net_cash_flow_from_financing_activities",
+        "net_cash_flow_from_financing_activities_continuing",
+        "net_cash_flow_from_investing_activities",
+        "net_cash_flow_from_investing_activities_continuing",
+        "net_cash_flow_from_operating_activities",
+        "net_cash_flow_from_operating_activities_continuing",
+    ];
+}
+
+impl Parse for CashFlowStatement {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let net_cash_flow = Self::object_parse(map, vec!["net_cash_flow"]);
+        let net_cash_flow_continuing = Self::object_parse(map, vec!["net_cash_flow_continuing"]);
+        let net_cash_flow_from_financing_activities =
+            Self::object_parse(map, vec!["net_cash_flow_from_financing_activities"]);
+        let net_cash_flow_from_financing_activities_continuing = Self::object_parse(
+            map,
+            vec!["net_cash_flow_from_financing_activities_continuing"],
+        );
+        let net_cash_flow_from_investing_activities =
+            Self::object_parse(map, vec!["net_cash_flow_from_investing_activities"]);
+        let net_cash_flow_from_investing_activities_continuing = Self::object_parse(
+            map,
+            vec!["net_cash_flow_from_investing_activities_continuing"],
+        );
+        let net_cash_flow_from_operating_activities =
+            Self::object_parse(map, vec!["net_cash_flow_from_operating_activities"]);
+        let net_cash_flow_from_operating_activities_continuing = Self::object_parse(
+            map,
+            vec!["net_cash_flow_from_operating_activities_continuing"],
+        );
+        let additional_line_items = Self::remaining_parse(map, Self::LINE_ITEMS);
+        CashFlowStatement {
+            net_cash_flow,
+            net_cash_flow_continuing,
+            net_cash_flow_from_financing_activities,
+            net_cash_flow_from_financing_activities_continuing,
+            net_cash_flow_from_investing_activities,
+            net_cash_flow_from_investing_activities_continuing,
+            net_cash_flow_from_operating_activities,
+            net_cash_flow_from_operating_activities_continuing,
+            additional_line_items,
+        }
+    }
+}
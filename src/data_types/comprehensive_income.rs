@@ -0,0 +1,53 @@
+use crate::data_types::{line_item::LineItem, Parse};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ComprehensiveIncome {
+    pub comprehensive_income_loss: Option<LineItem>,
+    pub comprehensive_income_loss_attributable_to_noncontrolling_interest: Option<LineItem>,
+    pub comprehensive_income_loss_attributable_to_parent: Option<LineItem>,
+    pub other_comprehensive_income_loss: Option<LineItem>,
+    pub other_comprehensive_income_loss_attributable_to_parent: Option<LineItem>,
+    //Line items Polygon returns that have no field above, keyed by their Polygon name
+    pub additional_line_items: HashMap<String, LineItem>,
+}
+
+impl ComprehensiveIncome {
+    const LINE_ITEMS: &'static [&'static str] = &[
+        "comprehensive_income_loss",
+        "comprehensive_income_loss_attributable_to_noncontrolling_interest",
+        "comprehensive_income_loss_attributable_to_parent",
+        "other_comprehensive_income_loss",
+        "other_comprehensive_income_loss_attributable_to_parent",
+    ];
+}
+
+impl Parse for ComprehensiveIncome {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let comprehensive_income_loss = Self::object_parse(map, vec!["comprehensive_income_loss"]);
+        let comprehensive_income_loss_attributable_to_noncontrolling_interest = Self::object_parse(
+            map,
+            vec!["comprehensive_income_loss_attributable_to_noncontrolling_interest"],
+        );
+        let comprehensive_income_loss_attributable_to_parent = Self::object_parse(
+            map,
+            vec!["comprehensive_income_loss_attributable_to_parent"],
+        );
+        let other_comprehensive_income_loss =
+            Self::object_parse(map, vec!["other_comprehensive_income_loss"]);
+        let other_comprehensive_income_loss_attributable_to_parent = Self::object_parse(
+            map,
+            vec!["other_comprehensive_income_loss_attributable_to_parent"],
+        );
+        let additional_line_items = Self::remaining_parse(map, Self::LINE_ITEMS);
+        ComprehensiveIncome {
+            comprehensive_income_loss,
+            comprehensive_income_loss_attributable_to_noncontrolling_interest,
+            comprehensive_income_loss_attributable_to_parent,
+            other_comprehensive_income_loss,
+            other_comprehensive_income_loss_attributable_to_parent,
+            additional_line_items,
+        }
+    }
+}
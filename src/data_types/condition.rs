@@ -0,0 +1,46 @@
+use crate::data_types::{sip_mapping::SipMapping, update_rules::UpdateRules, Parse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Condition {
+    pub abbreviation: Option<String>,
+    pub asset_class: Option<String>,
+    pub condition_type: Option<String>,
+    pub data_types: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub exchange: Option<i64>,
+    pub id: Option<i64>,
+    pub legacy: Option<bool>,
+    pub name: Option<String>,
+    pub sip_mapping: Option<SipMapping>,
+    pub update_rules: Option<UpdateRules>,
+}
+
+impl Parse for Condition {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let abbreviation = Self::string_parse(map, vec!["abbreviation"]);
+        let asset_class = Self::string_parse(map, vec!["asset_class"]);
+        let condition_type = Self::string_parse(map, vec!["type"]);
+        let data_types = Self::array_string_parse(map, vec!["data_types"]);
+        let description = Self::string_parse(map, vec!["description"]);
+        let exchange = Self::i64_parse(map, vec!["exchange"]);
+        let id = Self::i64_parse(map, vec!["id"]);
+        let legacy = Self::bool_parse(map, vec!["legacy"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        let sip_mapping = Self::object_parse(map, vec!["sip_mapping"]);
+        let update_rules = Self::object_parse(map, vec!["update_rules"]);
+        Condition {
+            abbreviation,
+            asset_class,
+            condition_type,
+            data_types,
+            description,
+            exchange,
+            id,
+            legacy,
+            name,
+            sip_mapping,
+            update_rules,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ConnectionStatus {
+    pub message: Option<String>,
+    pub status: Option<String>,
+    pub timestamp: Option<i64>, //Only set on statuses raised by the client, such as disconnected and resubscribed
+}
+
+impl Parse for ConnectionStatus {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let message = Self::string_parse(map, vec!["message"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        let timestamp = Self::i64_parse(map, vec!["t"]);
+        ConnectionStatus {
+            message,
+            status,
+            timestamp,
+        }
+    }
+}
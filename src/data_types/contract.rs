@@ -26,8 +26,8 @@ impl Parse for Contract {
         let fair_market_value = Self::f64_parse(map, vec!["fair_market_value"]);
         let greeks = Self::object_parse(map, vec!["greeks"]);
         let implied_volatility = Self::f64_parse(map, vec!["implied_volatility"]);
-        let quote = Self::object_parse(map, vec!["quote"]);
-        let trade = Self::object_parse(map, vec!["trade"]);
+        let quote = Self::object_parse(map, vec!["last_quote", "quote"]);
+        let trade = Self::object_parse(map, vec!["last_trade", "trade"]);
         let open_interest = Self::i64_parse(map, vec!["open_interest"]);
         let underlying_asset = Self::object_parse(map, vec!["underlying_asset"]);
         Contract {
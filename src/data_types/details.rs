@@ -17,18 +17,18 @@ pub struct Details {
 impl Parse for Details {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
         let contract_type = match Self::string_parse(map, vec!["contract_type"]) {
-            Some(contract_type) => match contract_type.as_str() {
-                "Call" => Some(ContractType::Call),
-                "Put" => Some(ContractType::Put),
+            Some(contract_type) => match contract_type.to_lowercase().as_str() {
+                "call" => Some(ContractType::Call),
+                "put" => Some(ContractType::Put),
                 _ => None,
             },
             None => None,
         };
-        let contract_style = match Self::string_parse(map, vec!["contract_style"]) {
-            Some(contract_style) => match contract_style.as_str() {
-                "American" => Some(ContractStyle::American),
-                "European" => Some(ContractStyle::European),
-                "Bermudan" => Some(ContractStyle::Bermudan),
+        let contract_style = match Self::string_parse(map, vec!["contract_style", "exercise_style"]) {
+            Some(contract_style) => match contract_style.to_lowercase().as_str() {
+                "american" => Some(ContractStyle::American),
+                "european" => Some(ContractStyle::European),
+                "bermudan" => Some(ContractStyle::Bermudan),
                 _ => None,
             },
             None => None,
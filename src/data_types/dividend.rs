@@ -0,0 +1,65 @@
+use crate::data_types::Parse;
+use crate::rest::parameters::{DividendType, Frequency};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Dividend {
+    pub cash_amount: Option<f64>,
+    pub currency: Option<String>,
+    pub declaration_date: Option<String>,
+    pub dividend_type: Option<DividendType>,
+    pub ex_dividend_date: Option<String>,
+    pub frequency: Option<Frequency>,
+    pub id: Option<String>,
+    pub pay_date: Option<String>,
+    pub record_date: Option<String>,
+    pub ticker: Option<String>,
+}
+
+impl Parse for Dividend {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let cash_amount = Self::f64_parse(map, vec!["cash_amount"]);
+        let currency = Self::string_parse(map, vec!["currency"]);
+        let declaration_date = Self::string_parse(map, vec!["declaration_date"]);
+        let dividend_type = match Self::string_parse(map, vec!["dividend_type"]) {
+            Some(dividend_type) => match dividend_type.as_str() {
+                "CD" => Some(DividendType::CD),
+                "SC" => Some(DividendType::SC),
+                "LT" => Some(DividendType::LT),
+                "ST" => Some(DividendType::ST),
+                _ => None,
+            },
+            None => None,
+        };
+        let ex_dividend_date = Self::string_parse(map, vec!["ex_dividend_date"]);
+        let frequency = match Self::i64_parse(map, vec!["frequency"]) {
+            Some(frequency) => match frequency {
+                0 => Some(Frequency::OneTime),
+                1 => Some(Frequency::Annually),
+                2 => Some(Frequency::BiAnnually),
+                4 => Some(Frequency::Quarterly),
+                12 => Some(Frequency::Monthly),
+                24 => Some(Frequency::BiMonthly),
+                52 => Some(Frequency::Weekly),
+                _ => None,
+            },
+            None => None,
+        };
+        let id = Self::string_parse(map, vec!["id"]);
+        let pay_date = Self::string_parse(map, vec!["pay_date"]);
+        let record_date = Self::string_parse(map, vec!["record_date"]);
+        let ticker = Self::string_parse(map, vec!["ticker"]);
+        Dividend {
+            cash_amount,
+            currency,
+            declaration_date,
+            dividend_type,
+            ex_dividend_date,
+            frequency,
+            id,
+            pay_date,
+            record_date,
+            ticker,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Exchange {
+    pub acronym: Option<String>,
+    pub asset_class: Option<String>,
+    pub id: Option<i64>,
+    pub locale: Option<String>,
+    pub mic: Option<String>,
+    pub name: Option<String>,
+    pub operating_mic: Option<String>,
+    pub participant_id: Option<String>,
+    pub exchange_type: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Parse for Exchange {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let acronym = Self::string_parse(map, vec!["acronym"]);
+        let asset_class = Self::string_parse(map, vec!["asset_class"]);
+        let id = Self::i64_parse(map, vec!["id"]);
+        let locale = Self::string_parse(map, vec!["locale"]);
+        let mic = Self::string_parse(map, vec!["mic"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        let operating_mic = Self::string_parse(map, vec!["operating_mic"]);
+        let participant_id = Self::string_parse(map, vec!["participant_id"]);
+        let exchange_type = Self::string_parse(map, vec!["type"]);
+        let url = Self::string_parse(map, vec!["url"]);
+        Exchange {
+            acronym,
+            asset_class,
+            id,
+            locale,
+            mic,
+            name,
+            operating_mic,
+            participant_id,
+            exchange_type,
+            url,
+        }
+    }
+}
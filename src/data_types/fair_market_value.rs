@@ -0,0 +1,36 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FairMarketValue {
+    pub fair_market_value: Option<f64>,
+    pub ticker: Option<String>,
+    pub timestamp: Option<i64>,
+}
+
+impl Parse for FairMarketValue {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let fair_market_value = Self::f64_parse(map, vec!["fmv"]);
+        let ticker = Self::string_parse(map, vec!["sym", "ticker"]);
+        let timestamp = Self::i64_parse(map, vec!["t"]);
+        FairMarketValue {
+            fair_market_value,
+            ticker,
+            timestamp,
+        }
+    }
+}
+
+#[test]
+fn test_fair_market_value_parse() {
+    let data = serde_json::json!({
+        "ev": "FMV",
+        "fmv": 189.22,
+        "sym": "AAPL",
+        "t": 1678220098130i64
+    });
+    let fair_market_value = FairMarketValue::parse(&data.as_object().unwrap());
+    assert_eq!(fair_market_value.fair_market_value.unwrap(), 189.22);
+    assert_eq!(fair_market_value.ticker.unwrap(), "AAPL");
+    assert_eq!(fair_market_value.timestamp.unwrap(), 1678220098130);
+}
@@ -0,0 +1,80 @@
+use crate::data_types::{
+    balance_sheet::BalanceSheet, cash_flow_statement::CashFlowStatement,
+    comprehensive_income::ComprehensiveIncome, income_statement::IncomeStatement, Parse,
+};
+use crate::rest::parameters::FinancialTimeframe;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Financial {
+    pub acceptance_datetime: Option<String>,
+    pub balance_sheet: Option<BalanceSheet>,
+    pub cash_flow_statement: Option<CashFlowStatement>,
+    pub cik: Option<String>,
+    pub company_name: Option<String>,
+    pub comprehensive_income: Option<ComprehensiveIncome>,
+    pub end_date: Option<String>,
+    pub filing_date: Option<String>,
+    pub fiscal_period: Option<String>,
+    pub fiscal_year: Option<String>,
+    pub income_statement: Option<IncomeStatement>,
+    pub sic: Option<String>,
+    pub source_filing_file_url: Option<String>,
+    pub source_filing_url: Option<String>,
+    pub start_date: Option<String>,
+    pub tickers: Option<Vec<String>>,
+    pub timeframe: Option<FinancialTimeframe>,
+}
+
+impl Parse for Financial {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let acceptance_datetime = Self::string_parse(map, vec!["acceptance_datetime"]);
+        let statements = Self::object(map, vec!["financials"]);
+        let balance_sheet = statements.and_then(|s| Self::object_parse(s, vec!["balance_sheet"]));
+        let cash_flow_statement =
+            statements.and_then(|s| Self::object_parse(s, vec!["cash_flow_statement"]));
+        let comprehensive_income =
+            statements.and_then(|s| Self::object_parse(s, vec!["comprehensive_income"]));
+        let income_statement =
+            statements.and_then(|s| Self::object_parse(s, vec!["income_statement"]));
+        let cik = Self::string_parse(map, vec!["cik"]);
+        let company_name = Self::string_parse(map, vec!["company_name"]);
+        let end_date = Self::string_parse(map, vec!["end_date"]);
+        let filing_date = Self::string_parse(map, vec!["filing_date"]);
+        let fiscal_period = Self::string_parse(map, vec!["fiscal_period"]);
+        let fiscal_year = Self::string_parse(map, vec!["fiscal_year"]);
+        let sic = Self::string_parse(map, vec!["sic"]);
+        let source_filing_file_url = Self::string_parse(map, vec!["source_filing_file_url"]);
+        let source_filing_url = Self::string_parse(map, vec!["source_filing_url"]);
+        let start_date = Self::string_parse(map, vec!["start_date"]);
+        let tickers = Self::array_string_parse(map, vec!["tickers"]);
+        let timeframe = match Self::string_parse(map, vec!["timeframe"]) {
+            Some(timeframe) => match timeframe.as_str() {
+                "annual" => Some(FinancialTimeframe::Annual),
+                "quarterly" => Some(FinancialTimeframe::Quarterly),
+                "ttm" => Some(FinancialTimeframe::TTM),
+                _ => None,
+            },
+            None => None,
+        };
+        Financial {
+            acceptance_datetime,
+            balance_sheet,
+            cash_flow_statement,
+            cik,
+            company_name,
+            comprehensive_income,
+            end_date,
+            filing_date,
+            fiscal_period,
+            fiscal_year,
+            income_statement,
+            sic,
+            source_filing_file_url,
+            source_filing_url,
+            start_date,
+            tickers,
+            timeframe,
+        }
+    }
+}
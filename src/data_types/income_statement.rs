@@ -0,0 +1,142 @@
+use crate::data_types::{line_item::LineItem, Parse};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IncomeStatement {
+    pub basic_average_shares: Option<LineItem>,
+    pub basic_earnings_per_share: Option<LineItem>,
+    pub benefits_costs_expenses: Option<LineItem>,
+    pub cost_of_revenue: Option<LineItem>,
+    pub costs_and_expenses: Option<LineItem>,
+    pub diluted_average_shares: Option<LineItem>,
+    pub diluted_earnings_per_share: Option<LineItem>,
+    pub gross_profit: Option<LineItem>,
+    pub income_loss_from_continuing_operations_after_tax: Option<LineItem>,
+    pub income_loss_from_continuing_operations_before_tax: Option<LineItem>,
+    pub income_tax_expense_benefit: Option<LineItem>,
+    pub interest_expense_operating: Option<LineItem>,
+    pub net_income_loss: Option<LineItem>,
+    pub net_income_loss_attributable_to_noncontrolling_interest: Option<LineItem>,
+    pub net_income_loss_attributable_to_parent: Option<LineItem>,
+    pub net_income_loss_available_to_common_stockholders_basic: Option<LineItem>,
+    pub nonoperating_income_loss: Option<LineItem>,
+    pub operating_expenses: Option<LineItem>,
+    pub operating_income_loss: Option<LineItem>,
+    pub participating_securities_distributed_and_undistributed_earnings_loss_basic:
+        Option<LineItem>,
+    pub preferred_stock_dividends_and_other_adjustments: Option<LineItem>,
+    pub research_and_development: Option<LineItem>,
+    pub revenues: Option<LineItem>,
+    pub selling_general_and_administrative_expenses: Option<LineItem>,
+    //Line items Polygon returns that have no field above, keyed by their Polygon name
+    pub additional_line_items: HashMap<String, LineItem>,
+}
+
+impl IncomeStatement {
+    const LINE_ITEMS: &'static [&'static str] = &[
+        "basic_average_shares",
+        "basic_earnings_per_share",
+        "benefits_costs_expenses",
+        "cost_of_revenue",
+        "costs_and_expenses",
+        "diluted_average_shares",
+        "diluted_earnings_per_share",
+        "gross_profit",
+        "income_loss_from_continuing_operations_after_tax",
+        "income_loss_from_continuing_operations_before_tax",
+        "income_tax_expense_benefit",
+        "interest_expense_operating",
+        "net_income_loss",
+        "net_income_loss_attributable_to_noncontrolling_interest",
+        "net_income_loss_attributable_to_parent",
+        "net_income_loss_available_to_common_stockholders_basic",
+        "nonoperating_income_loss",
+        "operating_expenses",
+        "operating_income_loss",
+        "participating_securities_distributed_and_undistributed_earnings_loss_basic",
+        "preferred_stock_dividends_and_other_adjustments",
+        "research_and_development",
+        "revenues",
+        "selling_general_and_administrative_expenses",
+    ];
+}
+
+impl Parse for IncomeStatement {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let basic_average_shares = Self::object_parse(map, vec!["basic_average_shares"]);
+        let basic_earnings_per_share = Self::object_parse(map, vec!["basic_earnings_per_share"]);
+        let benefits_costs_expenses = Self::object_parse(map, vec!["benefits_costs_expenses"]);
+        let cost_of_revenue = Self::object_parse(map, vec!["cost_of_revenue"]);
+        let costs_and_expenses = Self::object_parse(map, vec!["costs_and_expenses"]);
+        let diluted_average_shares = Self::object_parse(map, vec!["diluted_average_shares"]);
+        let diluted_earnings_per_share =
+            Self::object_parse(map, vec!["diluted_earnings_per_share"]);
+        let gross_profit = Self::object_parse(map, vec!["gross_profit"]);
+        let income_loss_from_continuing_operations_after_tax = Self::object_parse(
+            map,
+            vec!["income_loss_from_continuing_operations_after_tax"],
+        );
+        let income_loss_from_continuing_operations_before_tax = Self::object_parse(
+            map,
+            vec!["income_loss_from_continuing_operations_before_tax"],
+        );
+        let income_tax_expense_benefit =
+            Self::object_parse(map, vec!["income_tax_expense_benefit"]);
+        let interest_expense_operating =
+            Self::object_parse(map, vec!["interest_expense_operating"]);
+        let net_income_loss = Self::object_parse(map, vec!["net_income_loss"]);
+        let net_income_loss_attributable_to_noncontrolling_interest = Self::object_parse(
+            map,
+            vec!["net_income_loss_attributable_to_noncontrolling_interest"],
+        );
+        let net_income_loss_attributable_to_parent =
+            Self::object_parse(map, vec!["net_income_loss_attributable_to_parent"]);
+        let net_income_loss_available_to_common_stockholders_basic = Self::object_parse(
+            map,
+            vec!["net_income_loss_available_to_common_stockholders_basic"],
+        );
+        let nonoperating_income_loss = Self::object_parse(map, vec!["nonoperating_income_loss"]);
+        let operating_expenses = Self::object_parse(map, vec!["operating_expenses"]);
+        let operating_income_loss = Self::object_parse(map, vec!["operating_income_loss"]);
+        let participating_securities_distributed_and_undistributed_earnings_loss_basic =
+            Self::object_parse(
+                map,
+                vec!["participating_securities_distributed_and_undistributed_earnings_loss_basic"],
+            );
+        let preferred_stock_dividends_and_other_adjustments =
+            Self::object_parse(map, vec!["preferred_stock_dividends_and_other_adjustments"]);
+        let research_and_development = Self::object_parse(map, vec!["research_and_development"]);
+        let revenues = Self::object_parse(map, vec!["revenues"]);
+        let selling_general_and_administrative_expenses =
+            Self::object_parse(map, vec!["selling_general_and_administrative_expenses"]);
+        let additional_line_items = Self::remaining_parse(map, Self::LINE_ITEMS);
+        IncomeStatement {
+            basic_average_shares,
+            basic_earnings_per_share,
+            benefits_costs_expenses,
+            cost_of_revenue,
+            costs_and_expenses,
+            diluted_average_shares,
+            diluted_earnings_per_share,
+            gross_profit,
+            income_loss_from_continuing_operations_after_tax,
+            income_loss_from_continuing_operations_before_tax,
+            income_tax_expense_benefit,
+            interest_expense_operating,
+            net_income_loss,
+            net_income_loss_attributable_to_noncontrolling_interest,
+            net_income_loss_attributable_to_parent,
+            net_income_loss_available_to_common_stockholders_basic,
+            nonoperating_income_loss,
+            operating_expenses,
+            operating_income_loss,
+            participating_securities_distributed_and_undistributed_earnings_loss_basic,
+            preferred_stock_dividends_and_other_adjustments,
+            research_and_development,
+            revenues,
+            selling_general_and_administrative_expenses,
+            additional_line_items,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IndexValue {
+    pub ticker: Option<String>,
+    pub timestamp: Option<i64>,
+    pub value: Option<f64>,
+}
+
+impl Parse for IndexValue {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let ticker = Self::string_parse(map, vec!["T", "ticker"]);
+        let timestamp = Self::i64_parse(map, vec!["t"]);
+        let value = Self::f64_parse(map, vec!["val", "value"]);
+        IndexValue {
+            ticker,
+            timestamp,
+            value,
+        }
+    }
+}
+
+#[test]
+fn test_index_value_parse() {
+    let data = serde_json::json!({
+        "ev": "V",
+        "val": 3988.5,
+        "T": "I:SPX",
+        "t": 1678220098130i64
+    });
+    let index_value = IndexValue::parse(&data.as_object().unwrap());
+    assert_eq!(index_value.ticker.unwrap(), "I:SPX");
+    assert_eq!(index_value.value.unwrap(), 3988.5);
+    assert_eq!(index_value.timestamp.unwrap(), 1678220098130);
+}
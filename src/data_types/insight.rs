@@ -0,0 +1,31 @@
+use crate::data_types::Parse;
+use crate::rest::parameters::Sentiment;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Insight {
+    pub sentiment: Option<Sentiment>,
+    pub sentiment_reasoning: Option<String>,
+    pub ticker: Option<String>,
+}
+
+impl Parse for Insight {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let sentiment = match Self::string_parse(map, vec!["sentiment"]) {
+            Some(sentiment) => match sentiment.as_str() {
+                "positive" => Some(Sentiment::Positive),
+                "neutral" => Some(Sentiment::Neutral),
+                "negative" => Some(Sentiment::Negative),
+                _ => None,
+            },
+            None => None,
+        };
+        let sentiment_reasoning = Self::string_parse(map, vec!["sentiment_reasoning"]);
+        let ticker = Self::string_parse(map, vec!["ticker"]);
+        Insight {
+            sentiment,
+            sentiment_reasoning,
+            ticker,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::data_types::{ask::Ask, bid::Bid, Parse};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct L2 {
@@ -14,13 +15,32 @@ pub struct L2 {
 
 impl Parse for L2 {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
-        let bids = Self::array_parse(map, vec!["bids"]);
-        let asks = Self::array_parse(map, vec!["asks"]);
-        let bid_count = Self::f64_parse(map, vec!["bid_count"]);
-        let ask_count = Self::f64_parse(map, vec!["ask_count"]);
-        let timestamp = Self::i64_parse(map, vec!["timestamp"]);
+        let exchange_id = Self::i64_parse(map, vec!["x"]);
+        let bids = match Self::array_parse(map, vec!["bids"]) {
+            Some(bids) => Some(bids),
+            None => Self::levels(map, "b", exchange_id),
+        };
+        let asks = match Self::array_parse(map, vec!["asks"]) {
+            Some(asks) => Some(asks),
+            None => Self::levels(map, "a", exchange_id).map(|levels| {
+                levels
+                    .into_iter()
+                    .map(|level| Ask {
+                        price: level.price,
+                        size: level.size,
+                    })
+                    .collect()
+            }),
+        };
+        let bid_count = Self::f64_parse(map, vec!["bid_count", "bidCount"]);
+        let ask_count = Self::f64_parse(map, vec!["ask_count", "askCount"]);
+        //The REST book reports updated in nanoseconds while XL2 reports t in milliseconds, keep both in milliseconds
+        let timestamp = match Self::i64_parse(map, vec!["updated"]) {
+            Some(updated) => Some(updated / 1_000_000),
+            None => Self::i64_parse(map, vec!["timestamp", "t"]),
+        };
         let spread = Self::f64_parse(map, vec!["spread"]);
-        let ticker = Self::string_parse(map, vec!["ticker"]);
+        let ticker = Self::string_parse(map, vec!["ticker", "pair"]);
         L2 {
             bids,
             asks,
@@ -33,6 +53,30 @@ impl Parse for L2 {
     }
 }
 
+impl L2 {
+    //XL2 sends [price, size] pairs for a single exchange, keyed the same way as the REST book sizes
+    fn levels(
+        map: &serde_json::Map<String, serde_json::Value>,
+        key: &str,
+        exchange_id: Option<i64>,
+    ) -> Option<Vec<Bid>> {
+        let exchange = exchange_id.map(|x| x.to_string()).unwrap_or_default();
+        map.get(key).and_then(|v| v.as_array()).map(|levels| {
+            levels
+                .iter()
+                .filter_map(|level| level.as_array())
+                .map(|level| {
+                    let price = level.first().and_then(|v| v.as_f64());
+                    let size = level
+                        .get(1)
+                        .and_then(|v| v.as_f64())
+                        .map(|size| HashMap::from([(exchange.clone(), size)]));
+                    Bid { price, size }
+                })
+                .collect()
+        })
+    }
+}
 
 #[test]
 fn test_l2_parse() {
@@ -70,3 +114,23 @@ fn test_l2_parse() {
     assert_eq!(l2.spread.unwrap(), 3.33);
     assert_eq!(l2.ticker.unwrap(), "TEST");
 }
+
+#[test]
+fn test_l2_parse_stream() {
+    let data = serde_json::json!({
+        "ev": "XL2",
+        "pair": "BTC-USD",
+        "t": 1598045632000i64,
+        "x": 1,
+        "b": [[11450.38, 0.0128], [11450.1, 0.5]],
+        "a": [[11450.64, 0.0009]]
+    });
+    let l2 = L2::parse(&data.as_object().unwrap());
+    assert_eq!(l2.ticker.unwrap(), "BTC-USD");
+    assert_eq!(l2.timestamp.unwrap(), 1598045632000);
+    let bids = l2.bids.unwrap();
+    assert_eq!(bids.len(), 2);
+    assert_eq!(bids[1].price.unwrap(), 11450.1);
+    assert_eq!(bids[1].size.clone().unwrap().get("1").unwrap(), &0.5);
+    assert_eq!(l2.asks.unwrap()[0].price.unwrap(), 11450.64);
+}
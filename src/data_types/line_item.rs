@@ -0,0 +1,25 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LineItem {
+    pub label: Option<String>,
+    pub order: Option<i64>,
+    pub unit: Option<String>,
+    pub value: Option<f64>,
+}
+
+impl Parse for LineItem {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let label = Self::string_parse(map, vec!["label"]);
+        let order = Self::i64_parse(map, vec!["order"]);
+        let unit = Self::string_parse(map, vec!["unit"]);
+        let value = Self::f64_parse(map, vec!["value"]);
+        LineItem {
+            label,
+            order,
+            unit,
+            value,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MarketHoliday {
+    pub close: Option<String>,
+    pub date: Option<String>,
+    pub exchange: Option<String>,
+    pub name: Option<String>,
+    pub open: Option<String>,
+    pub status: Option<String>,
+}
+
+impl Parse for MarketHoliday {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let close = Self::string_parse(map, vec!["close"]);
+        let date = Self::string_parse(map, vec!["date"]);
+        let exchange = Self::string_parse(map, vec!["exchange"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        let open = Self::string_parse(map, vec!["open"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        MarketHoliday {
+            close,
+            date,
+            exchange,
+            name,
+            open,
+            status,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::data_types::{insight::Insight, publisher::Publisher, Parse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NewsArticle {
+    pub amp_url: Option<String>,
+    pub article_url: Option<String>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub id: Option<String>,
+    pub image_url: Option<String>,
+    pub insights: Option<Vec<Insight>>,
+    pub keywords: Option<Vec<String>>,
+    pub published_utc: Option<String>,
+    pub publisher: Option<Publisher>,
+    pub tickers: Option<Vec<String>>,
+    pub title: Option<String>,
+}
+
+impl Parse for NewsArticle {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let amp_url = Self::string_parse(map, vec!["amp_url"]);
+        let article_url = Self::string_parse(map, vec!["article_url"]);
+        let author = Self::string_parse(map, vec!["author"]);
+        let description = Self::string_parse(map, vec!["description"]);
+        let id = Self::string_parse(map, vec!["id"]);
+        let image_url = Self::string_parse(map, vec!["image_url"]);
+        let insights = Self::array_parse(map, vec!["insights"]);
+        let keywords = Self::array_string_parse(map, vec!["keywords"]);
+        let published_utc = Self::string_parse(map, vec!["published_utc"]);
+        let publisher = Self::object_parse(map, vec!["publisher"]);
+        let tickers = Self::array_string_parse(map, vec!["tickers"]);
+        let title = Self::string_parse(map, vec!["title"]);
+        NewsArticle {
+            amp_url,
+            article_url,
+            author,
+            description,
+            id,
+            image_url,
+            insights,
+            keywords,
+            published_utc,
+            publisher,
+            tickers,
+            title,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::data_types::{Parse, additional_underlying::AdditionalUnderlying};
-use crate::rest::parameters::ContractStyle;
+use crate::rest::parameters::{ContractStyle, ContractType};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -7,6 +7,7 @@ pub struct OptionContract {
     pub additional_underlyings: Option<Vec<AdditionalUnderlying>>,
     pub cfi: Option<String>,
     pub contract: Option<String>,
+    pub contract_type: Option<ContractType>,
     pub correction: Option<i64>,
     pub exercise_style: Option<ContractStyle>,
     pub expiration_date: Option<String>,
@@ -23,11 +24,20 @@ impl Parse for OptionContract {
         let cfi = Self::string_parse(map, vec!["cfi"]);
         let contract = Self::string_parse(map, vec!["contract"]);
         let correction = Self::i64_parse(map, vec!["correction"]);
-        let contract_style = match Self::string_parse(map, vec!["contract_type"]) {
-            Some(contract_style) => match contract_style.as_str() {
-                "American" => Some(ContractStyle::American),
-                "European" => Some(ContractStyle::European),
-                "Bermudan" => Some(ContractStyle::Bermudan),
+        let contract_type = match Self::string_parse(map, vec!["contract_type"]) {
+            Some(contract_type) => match contract_type.to_lowercase().as_str() {
+                "call" => Some(ContractType::Call),
+                "put" => Some(ContractType::Put),
+                "other" => Some(ContractType::Other),
+                _ => None,
+            },
+            None => None,
+        };
+        let exercise_style = match Self::string_parse(map, vec!["exercise_style"]) {
+            Some(exercise_style) => match exercise_style.to_lowercase().as_str() {
+                "american" => Some(ContractStyle::American),
+                "european" => Some(ContractStyle::European),
+                "bermudan" => Some(ContractStyle::Bermudan),
                 _ => None,
             },
             None => None,
@@ -43,8 +53,9 @@ impl Parse for OptionContract {
             additional_underlyings,
             cfi,
             contract,
+            contract_type,
             correction,
-            exercise_style: contract_style,
+            exercise_style,
             expiration_date,
             primary_exchange,
             shares_per_contract,
@@ -0,0 +1,25 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Publisher {
+    pub favicon_url: Option<String>,
+    pub homepage_url: Option<String>,
+    pub logo_url: Option<String>,
+    pub name: Option<String>,
+}
+
+impl Parse for Publisher {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let favicon_url = Self::string_parse(map, vec!["favicon_url"]);
+        let homepage_url = Self::string_parse(map, vec!["homepage_url"]);
+        let logo_url = Self::string_parse(map, vec!["logo_url"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        Publisher {
+            favicon_url,
+            homepage_url,
+            logo_url,
+            name,
+        }
+    }
+}
@@ -21,19 +21,20 @@ pub struct Quote {
     pub sequence_number: Option<i64>,
     pub participant_timestamp: Option<i64>,
     pub tape: Option<i64>,
+    pub ticker: Option<String>,
 }
 
 impl Parse for Quote {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
-        let bid = Self::f64_parse(map, vec!["p", "b", "bid_price", "bid"]);
-        let bid_size = Self::i64_parse(map, vec!["s", "bid_size"]);
-        let ask = Self::f64_parse(map, vec!["P", "a", "ask_price", "ask"]);
-        let ask_size = Self::i64_parse(map, vec!["S", "ask_size"]);
-        let bid_exchange_id = Self::i64_parse(map, vec!["bid_exchange", "bid_exchange_id"]);
-        let ask_exchange_id = Self::i64_parse(map, vec!["ask_exchange", "ask_exchange_id"]);
+        let bid = Self::f64_parse(map, vec!["p", "b", "bp", "bid_price", "bid"]);
+        let bid_size = Self::i64_parse(map, vec!["s", "bs", "bid_size"]);
+        let ask = Self::f64_parse(map, vec!["P", "a", "ap", "ask_price", "ask"]);
+        let ask_size = Self::i64_parse(map, vec!["S", "as", "ask_size"]);
+        let bid_exchange_id = Self::i64_parse(map, vec!["bx", "bid_exchange", "bid_exchange_id"]);
+        let ask_exchange_id = Self::i64_parse(map, vec!["ax", "ask_exchange", "ask_exchange_id"]);
         let last_updated =
             Self::i64_parse(map, vec!["t", "sip_timestamp", "timestamp", "last_updated"]);
-        let mid_point = Self::f64_parse(map, vec!["mid_point"]);
+        let mid_point = Self::f64_parse(map, vec!["mid_point", "midpoint"]);
         let timeframe = match Self::string_parse(map, vec!["timeframe"]) {
             Some(timeframe) => match timeframe.as_str() {
                 "DELAYED" => Some(Timeframe::Delayed),
@@ -50,6 +51,7 @@ impl Parse for Quote {
         let sequence_number = Self::i64_parse(map, vec!["q", "sequence_number"]);
         let participant_timestamp = Self::i64_parse(map, vec!["y", "participant_timestamp"]);
         let tape = Self::i64_parse(map, vec!["z", "tape"]);
+        let ticker = Self::string_parse(map, vec!["sym", "T", "ticker"]);
 
         Quote {
             bid,
@@ -69,6 +71,7 @@ impl Parse for Quote {
             sequence_number,
             participant_timestamp,
             tape,
+            ticker,
         }
     }
 }
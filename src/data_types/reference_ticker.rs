@@ -0,0 +1,61 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ReferenceTicker {
+    pub active: Option<bool>,
+    pub base_currency_name: Option<String>,
+    pub base_currency_symbol: Option<String>,
+    pub cik: Option<String>,
+    pub composite_figi: Option<String>,
+    pub currency_name: Option<String>,
+    pub currency_symbol: Option<String>,
+    pub delisted_utc: Option<String>,
+    pub last_updated_utc: Option<String>,
+    pub locale: Option<String>,
+    pub market: Option<String>,
+    pub name: Option<String>,
+    pub primary_exchange: Option<String>,
+    pub share_class_figi: Option<String>,
+    pub ticker: Option<String>,
+    pub ticker_type: Option<String>,
+}
+
+impl Parse for ReferenceTicker {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let active = Self::bool_parse(map, vec!["active"]);
+        let base_currency_name = Self::string_parse(map, vec!["base_currency_name"]);
+        let base_currency_symbol = Self::string_parse(map, vec!["base_currency_symbol"]);
+        let cik = Self::string_parse(map, vec!["cik"]);
+        let composite_figi = Self::string_parse(map, vec!["composite_figi"]);
+        let currency_name = Self::string_parse(map, vec!["currency_name"]);
+        let currency_symbol = Self::string_parse(map, vec!["currency_symbol"]);
+        let delisted_utc = Self::string_parse(map, vec!["delisted_utc"]);
+        let last_updated_utc = Self::string_parse(map, vec!["last_updated_utc"]);
+        let locale = Self::string_parse(map, vec!["locale"]);
+        let market = Self::string_parse(map, vec!["market"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        let primary_exchange = Self::string_parse(map, vec!["primary_exchange"]);
+        let share_class_figi = Self::string_parse(map, vec!["share_class_figi"]);
+        let ticker = Self::string_parse(map, vec!["ticker"]);
+        let ticker_type = Self::string_parse(map, vec!["type"]);
+        ReferenceTicker {
+            active,
+            base_currency_name,
+            base_currency_symbol,
+            cik,
+            composite_figi,
+            currency_name,
+            currency_symbol,
+            delisted_utc,
+            last_updated_utc,
+            locale,
+            market,
+            name,
+            primary_exchange,
+            share_class_figi,
+            ticker,
+            ticker_type,
+        }
+    }
+}
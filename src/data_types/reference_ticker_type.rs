@@ -0,0 +1,25 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ReferenceTickerType {
+    pub asset_class: Option<String>,
+    pub code: Option<String>,
+    pub description: Option<String>,
+    pub locale: Option<String>,
+}
+
+impl Parse for ReferenceTickerType {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let asset_class = Self::string_parse(map, vec!["asset_class"]);
+        let code = Self::string_parse(map, vec!["code"]);
+        let description = Self::string_parse(map, vec!["description"]);
+        let locale = Self::string_parse(map, vec!["locale"]);
+        ReferenceTickerType {
+            asset_class,
+            code,
+            description,
+            locale,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelatedCompany {
+    pub ticker: Option<String>,
+}
+
+impl Parse for RelatedCompany {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let ticker = Self::string_parse(map, vec!["ticker"]);
+        RelatedCompany { ticker }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SipMapping {
+    pub cta: Option<String>,
+    pub opra: Option<String>,
+    pub utp: Option<String>,
+}
+
+impl Parse for SipMapping {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let cta = Self::string_parse(map, vec!["CTA"]);
+        let opra = Self::string_parse(map, vec!["OPRA"]);
+        let utp = Self::string_parse(map, vec!["UTP"]);
+        SipMapping { cta, opra, utp }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Split {
+    pub execution_date: Option<String>,
+    pub id: Option<String>,
+    pub split_from: Option<f64>,
+    pub split_to: Option<f64>,
+    pub ticker: Option<String>,
+}
+
+impl Parse for Split {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let execution_date = Self::string_parse(map, vec!["execution_date"]);
+        let id = Self::string_parse(map, vec!["id"]);
+        let split_from = Self::f64_parse(map, vec!["split_from"]);
+        let split_to = Self::f64_parse(map, vec!["split_to"]);
+        let ticker = Self::string_parse(map, vec!["ticker"]);
+        Split {
+            execution_date,
+            id,
+            split_from,
+            split_to,
+            ticker,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Stochastic {
+    pub d: Option<f64>,
+    pub k: Option<f64>,
+    pub timestamp: Option<i64>,
+}
+
+impl Parse for Stochastic {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let d = Self::f64_parse(map, vec!["d"]);
+        let k = Self::f64_parse(map, vec!["k"]);
+        let timestamp = Self::i64_parse(map, vec!["timestamp"]);
+        Stochastic { d, k, timestamp }
+    }
+}
+
+#[test]
+fn test_stochastic_parse() {
+    let data = serde_json::json!({
+        "d": 1.23,
+        "k": 2.34,
+        "timestamp": 164545545
+    });
+    let stochastic = Stochastic::parse(&data.as_object().unwrap());
+    assert_eq!(stochastic.d.unwrap(), 1.23);
+    assert_eq!(stochastic.k.unwrap(), 2.34);
+    assert_eq!(stochastic.timestamp.unwrap(), 164545545);
+}
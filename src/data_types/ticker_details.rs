@@ -0,0 +1,95 @@
+use crate::data_types::{address::Address, branding::Branding, Parse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TickerDetails {
+    pub active: Option<bool>,
+    pub address: Option<Address>,
+    pub branding: Option<Branding>,
+    pub cik: Option<String>,
+    pub composite_figi: Option<String>,
+    pub currency_name: Option<String>,
+    pub delisted_utc: Option<String>,
+    pub description: Option<String>,
+    pub homepage_url: Option<String>,
+    pub list_date: Option<String>,
+    pub locale: Option<String>,
+    pub market: Option<String>,
+    pub market_cap: Option<f64>,
+    pub name: Option<String>,
+    pub phone_number: Option<String>,
+    pub primary_exchange: Option<String>,
+    pub round_lot: Option<i64>,
+    pub share_class_figi: Option<String>,
+    pub share_class_shares_outstanding: Option<i64>,
+    pub sic_code: Option<String>,
+    pub sic_description: Option<String>,
+    pub ticker: Option<String>,
+    pub ticker_root: Option<String>,
+    pub ticker_suffix: Option<String>,
+    pub ticker_type: Option<String>,
+    pub total_employees: Option<i64>,
+    pub weighted_shares_outstanding: Option<i64>,
+}
+
+impl Parse for TickerDetails {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let active = Self::bool_parse(map, vec!["active"]);
+        let address = Self::object_parse(map, vec!["address"]);
+        let branding = Self::object_parse(map, vec!["branding"]);
+        let cik = Self::string_parse(map, vec!["cik"]);
+        let composite_figi = Self::string_parse(map, vec!["composite_figi"]);
+        let currency_name = Self::string_parse(map, vec!["currency_name"]);
+        let delisted_utc = Self::string_parse(map, vec!["delisted_utc"]);
+        let description = Self::string_parse(map, vec!["description"]);
+        let homepage_url = Self::string_parse(map, vec!["homepage_url"]);
+        let list_date = Self::string_parse(map, vec!["list_date"]);
+        let locale = Self::string_parse(map, vec!["locale"]);
+        let market = Self::string_parse(map, vec!["market"]);
+        let market_cap = Self::f64_parse(map, vec!["market_cap"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        let phone_number = Self::string_parse(map, vec!["phone_number"]);
+        let primary_exchange = Self::string_parse(map, vec!["primary_exchange"]);
+        let round_lot = Self::i64_parse(map, vec!["round_lot"]);
+        let share_class_figi = Self::string_parse(map, vec!["share_class_figi"]);
+        let share_class_shares_outstanding =
+            Self::i64_parse(map, vec!["share_class_shares_outstanding"]);
+        let sic_code = Self::string_parse(map, vec!["sic_code"]);
+        let sic_description = Self::string_parse(map, vec!["sic_description"]);
+        let ticker = Self::string_parse(map, vec!["ticker"]);
+        let ticker_root = Self::string_parse(map, vec!["ticker_root"]);
+        let ticker_suffix = Self::string_parse(map, vec!["ticker_suffix"]);
+        let ticker_type = Self::string_parse(map, vec!["type"]);
+        let total_employees = Self::i64_parse(map, vec!["total_employees"]);
+        let weighted_shares_outstanding = Self::i64_parse(map, vec!["weighted_shares_outstanding"]);
+        TickerDetails {
+            active,
+            address,
+            branding,
+            cik,
+            composite_figi,
+            currency_name,
+            delisted_utc,
+            description,
+            homepage_url,
+            list_date,
+            locale,
+            market,
+            market_cap,
+            name,
+            phone_number,
+            primary_exchange,
+            round_lot,
+            share_class_figi,
+            share_class_shares_outstanding,
+            sic_code,
+            sic_description,
+            ticker,
+            ticker_root,
+            ticker_suffix,
+            ticker_type,
+            total_employees,
+            weighted_shares_outstanding,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::data_types::{ticker_change::TickerChange, Parse};
+use crate::rest::parameters::EventType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TickerEvent {
+    pub date: Option<String>,
+    pub event_type: Option<EventType>,
+    pub ticker_change: Option<TickerChange>,
+}
+
+impl Parse for TickerEvent {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let date = Self::string_parse(map, vec!["date"]);
+        let event_type = match Self::string_parse(map, vec!["type"]) {
+            Some(event_type) => match event_type.as_str() {
+                "ticker_change" => Some(EventType::TickerChange),
+                _ => None,
+            },
+            None => None,
+        };
+        let ticker_change = Self::object_parse(map, vec!["ticker_change"]);
+        TickerEvent {
+            date,
+            event_type,
+            ticker_change,
+        }
+    }
+}
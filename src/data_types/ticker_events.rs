@@ -0,0 +1,38 @@
+use crate::data_types::{ticker_event::TickerEvent, Parse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TickerEvents {
+    pub cik: Option<String>,
+    pub composite_figi: Option<String>,
+    pub events: Option<Vec<TickerEvent>>,
+    pub name: Option<String>,
+}
+
+impl TickerEvents {
+    //Ticker the company traded under on the given YYYY-MM-DD date, None if it predates the first known ticker
+    pub fn ticker_on(&self, date: &str) -> Option<String> {
+        self.events
+            .iter()
+            .flatten()
+            .filter(|e| e.date.as_deref().is_some_and(|d| d <= date))
+            .filter_map(|e| e.ticker_change.as_ref().map(|c| (&e.date, c)))
+            .max_by(|a, b| a.0.cmp(b.0))
+            .and_then(|(_, c)| c.ticker.clone())
+    }
+}
+
+impl Parse for TickerEvents {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let cik = Self::string_parse(map, vec!["cik"]);
+        let composite_figi = Self::string_parse(map, vec!["composite_figi"]);
+        let events = Self::array_parse(map, vec!["events"]);
+        let name = Self::string_parse(map, vec!["name"]);
+        TickerEvents {
+            cik,
+            composite_figi,
+            events,
+            name,
+        }
+    }
+}
@@ -18,6 +18,7 @@ pub struct Trade {
     pub trf_id: Option<i64>,
     pub participant_timestamp: Option<i64>,
     pub tape: Option<i64>,
+    pub ticker: Option<String>,
 }
 
 impl Parse for Trade {
@@ -38,11 +39,12 @@ impl Parse for Trade {
         };
         let exchange = Self::string_parse(map, vec!["T"]);
         let trade_correction = Self::i64_parse(map, vec!["e", "correction"]);
-        let trf_timestamp = Self::i64_parse(map, vec!["f", "trf_timestamp"]);
-        let trf_id = Self::i64_parse(map, vec!["r", "trf_id"]);
+        let trf_timestamp = Self::i64_parse(map, vec!["f", "trft", "trf_timestamp"]);
+        let trf_id = Self::i64_parse(map, vec!["r", "trfi", "trf_id"]);
         let sequence_number = Self::i64_parse(map, vec!["q", "sequence_number"]);
         let participant_timestamp = Self::i64_parse(map, vec!["y", "participant_timestamp"]);
         let tape = Self::i64_parse(map, vec!["z", "tape"]);
+        let ticker = Self::string_parse(map, vec!["sym", "T", "ticker"]);
 
         Trade {
             conditions,
@@ -59,6 +61,7 @@ impl Parse for Trade {
             trf_id,
             participant_timestamp,
             tape,
+            ticker,
         }
     }
 }
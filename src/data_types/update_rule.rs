@@ -0,0 +1,22 @@
+use crate::data_types::Parse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdateRule {
+    pub updates_high_low: Option<bool>,
+    pub updates_open_close: Option<bool>,
+    pub updates_volume: Option<bool>,
+}
+
+impl Parse for UpdateRule {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let updates_high_low = Self::bool_parse(map, vec!["updates_high_low"]);
+        let updates_open_close = Self::bool_parse(map, vec!["updates_open_close"]);
+        let updates_volume = Self::bool_parse(map, vec!["updates_volume"]);
+        UpdateRule {
+            updates_high_low,
+            updates_open_close,
+            updates_volume,
+        }
+    }
+}
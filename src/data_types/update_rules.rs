@@ -0,0 +1,19 @@
+use crate::data_types::{update_rule::UpdateRule, Parse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdateRules {
+    pub consolidated: Option<UpdateRule>,
+    pub market_center: Option<UpdateRule>,
+}
+
+impl Parse for UpdateRules {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let consolidated = Self::object_parse(map, vec!["consolidated"]);
+        let market_center = Self::object_parse(map, vec!["market_center"]);
+        UpdateRules {
+            consolidated,
+            market_center,
+        }
+    }
+}
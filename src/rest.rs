@@ -6,6 +6,7 @@ pub mod reference;
 #[derive(Serialize, Deserialize)]
 pub enum Rest {
     Market(market::Market),
+    Reference(reference::Reference),
 }
 
 use market::aggregates::AggregatesRequest;
@@ -32,6 +33,21 @@ use market::technical_indicators::moving_average_converge_divergence::MovingAver
 use market::technical_indicators::relative_strength_index::RelativeStrengthIndexRequest;
 use market::technical_indicators::simple_moving_average::SimpleMovingAverageRequest;
 use market::trades::TradesRequest;
+use reference::conditions::ConditionsRequest;
+use reference::contract::ContractRequest;
+use reference::contracts::OptionContractsRequest;
+use reference::details::DetailsRequest;
+use reference::dividends::DividendsRequest;
+use reference::events::EventsRequest;
+use reference::exchanges::ExchangesRequest;
+use reference::financials::FinancialsRequest;
+use reference::holidays::HolidaysRequest;
+use reference::news::NewsRequest;
+use reference::related::RelatedRequest;
+use reference::splits::SplitsRequest;
+use reference::status::StatusRequest;
+use reference::tickers::TickersRequest;
+use reference::types::TypesRequest;
 use serde::{Deserialize, Serialize};
 
 pub struct RestRequest {}
@@ -83,3 +99,33 @@ impl MovingAverageConvergenceDivergenceRequest for RestRequest {}
 impl RelativeStrengthIndexRequest for RestRequest {}
 
 impl SimpleMovingAverageRequest for RestRequest {}
+
+impl ConditionsRequest for RestRequest {}
+
+impl ContractRequest for RestRequest {}
+
+impl DetailsRequest for RestRequest {}
+
+impl DividendsRequest for RestRequest {}
+
+impl EventsRequest for RestRequest {}
+
+impl ExchangesRequest for RestRequest {}
+
+impl FinancialsRequest for RestRequest {}
+
+impl HolidaysRequest for RestRequest {}
+
+impl NewsRequest for RestRequest {}
+
+impl OptionContractsRequest for RestRequest {}
+
+impl RelatedRequest for RestRequest {}
+
+impl SplitsRequest for RestRequest {}
+
+impl StatusRequest for RestRequest {}
+
+impl TickersRequest for RestRequest {}
+
+impl TypesRequest for RestRequest {}
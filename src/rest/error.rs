@@ -1,4 +1,71 @@
-use std::fmt;
+use std::{error::Error, fmt, sync::Arc};
+
+#[derive(Clone, Debug, Default)]
+pub struct ErrorDetails {
+    pub status: Option<u16>,
+    pub error: Option<String>,
+    pub message: Option<String>,
+    pub request_id: Option<String>,
+    pub retry_after: Option<u64>,
+    pub source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl ErrorDetails {
+    pub fn from_response(status: u16, retry_after: Option<u64>, body: &str) -> Self {
+        let map = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(body).ok();
+        let field = |key: &str| {
+            map.as_ref()
+                .and_then(|m| m.get(key))
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string())
+        };
+        let message = match field("message") {
+            Some(message) => Some(message),
+            None if map.is_none() && !body.is_empty() => Some(body.to_string()),
+            None => None,
+        };
+        Self {
+            status: Some(status),
+            error: field("error"),
+            message,
+            request_id: field("request_id"),
+            retry_after,
+            source: None,
+        }
+    }
+}
+
+//The source is not comparable, two details are equal when everything Polygon returned matches
+impl PartialEq for ErrorDetails {
+    fn eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.error == other.error
+            && self.message == other.message
+            && self.request_id == other.request_id
+            && self.retry_after == other.retry_after
+    }
+}
+
+impl fmt::Display for ErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(status) = self.status {
+            write!(f, " (HTTP {})", status)?;
+        }
+        match (&self.error, &self.message) {
+            (_, Some(message)) => write!(f, ": {}", message)?,
+            (Some(error), None) => write!(f, ": {}", error)?,
+            (None, None) => {
+                if let Some(source) = &self.source {
+                    write!(f, ": {}", source)?;
+                }
+            }
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " [request_id: {}]", request_id)?;
+        }
+        Ok(())
+    }
+}
 
 #[derive(Debug, PartialEq)]
 pub enum ErrorCode {
@@ -6,7 +73,7 @@ pub enum ErrorCode {
     OptionsTickerError,
     TickerNotSet,
     APIError,
-    RequestError,
+    RequestError(ErrorDetails),
     FormatError,
     DateError,
     DateNotSet,
@@ -26,7 +93,7 @@ pub enum ErrorCode {
     OrderNotSet,
     TimestampNotSet,
     ContractTypeNotSet,
-    JSONParseError,
+    JSONParseError(ErrorDetails),
     StrikePriceNotSet,
     WrongParameterType,
     TickerNotValidForAPICall,
@@ -41,16 +108,169 @@ pub enum ErrorCode {
     DirectionNotSet,
     TickerTypeNotSet,
     NoNextURL,
+    ClientError(ErrorDetails),
+    Unauthorized(ErrorDetails),
+    Forbidden(ErrorDetails),
+    NotFound(ErrorDetails),
+    RateLimited(ErrorDetails),
+    ServerError(ErrorDetails),
+    HTTPError(ErrorDetails),
+    Timeout(ErrorDetails),
+    ExchangeError,
+    CIKError,
+    CUSIPError,
+    SearchNotSet,
+    MarketNotSet,
+    ExchangeNotSet,
+    TypeNotSet,
+    CIKNotSet,
+    CUSIPNotSet,
+    ActiveNotSet,
+    PayDateError,
+    RecordDateError,
+    DeclarationDateError,
+    CashAmountToError,
+    PayDateNotSet,
+    RecordDateNotSet,
+    DeclarationDateNotSet,
+    CashAmountNotSet,
+    FrequencyNotSet,
+    DividendTypeNotSet,
+    ReverseSplitNotSet,
+    SICError,
+    PeriodOfReportDateError,
+    CompanyNameNotSet,
+    SICNotSet,
+    PeriodOfReportDateNotSet,
+    FinancialTimeframeNotSet,
+    IncludeSourcesNotSet,
+    AssetClassNotSet,
+    LocaleNotSet,
+    DataTypeNotSet,
+    IdNotSet,
+    SipNotSet,
+    IdentifierError,
+    IdentifierNotSet,
+    EventTypeNotSet,
+    PublishedUtcError,
+    PublishedUtcNotSet,
+    TickerRangeError,
+    PublishedUtcRangeError,
+    WebSocketError(ErrorDetails),
+    WebSocketAuthenticationError(ErrorDetails),
+    WebSocketClosed,
+    WebSocketQueueFull,
+    ChannelNotValidForCluster,
+}
+
+impl ErrorCode {
+    pub fn from_status(details: ErrorDetails) -> Self {
+        match details.status {
+            Some(401) => ErrorCode::Unauthorized(details),
+            Some(403) => ErrorCode::Forbidden(details),
+            Some(404) => ErrorCode::NotFound(details),
+            Some(429) => ErrorCode::RateLimited(details),
+            Some(500..=599) => ErrorCode::ServerError(details),
+            _ => ErrorCode::HTTPError(details),
+        }
+    }
+
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        match self {
+            ErrorCode::RequestError(details)
+            | ErrorCode::ClientError(details)
+            | ErrorCode::JSONParseError(details)
+            | ErrorCode::Unauthorized(details)
+            | ErrorCode::Forbidden(details)
+            | ErrorCode::NotFound(details)
+            | ErrorCode::RateLimited(details)
+            | ErrorCode::ServerError(details)
+            | ErrorCode::HTTPError(details)
+            | ErrorCode::Timeout(details)
+            | ErrorCode::WebSocketError(details)
+            | ErrorCode::WebSocketAuthenticationError(details) => Some(details),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<u16> {
+        self.details().and_then(|details| details.status)
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.details().and_then(|details| details.request_id.as_deref())
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ErrorCode::RateLimited(_) | ErrorCode::ServerError(_) | ErrorCode::Timeout(_) => true,
+            ErrorCode::WebSocketError(_) | ErrorCode::WebSocketClosed => true,
+            ErrorCode::RequestError(details) => match &details.source {
+                Some(source) => match source.downcast_ref::<reqwest::Error>() {
+                    Some(e) => e.is_connect() || e.is_request(),
+                    None => false,
+                },
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ErrorCode {
+    fn from(e: reqwest::Error) -> Self {
+        let timeout = e.is_timeout();
+        let decode = e.is_decode();
+        //The URL carries the apiKey query parameter, so it must not reach logs through the source chain
+        let details = ErrorDetails {
+            status: e.status().map(|status| status.as_u16()),
+            source: Some(Arc::new(e.without_url())),
+            ..ErrorDetails::default()
+        };
+        if timeout {
+            ErrorCode::Timeout(details)
+        } else if decode {
+            ErrorCode::JSONParseError(details)
+        } else {
+            ErrorCode::RequestError(details)
+        }
+    }
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for ErrorCode {
+    fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
+        ErrorCode::WebSocketError(ErrorDetails {
+            source: Some(Arc::new(e)),
+            ..ErrorDetails::default()
+        })
+    }
+}
+
+impl From<serde_json::Error> for ErrorCode {
+    fn from(e: serde_json::Error) -> Self {
+        ErrorCode::JSONParseError(ErrorDetails {
+            source: Some(Arc::new(e)),
+            ..ErrorDetails::default()
+        })
+    }
+}
+
+impl Error for ErrorCode {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.details()
+            .and_then(|details| details.source.as_ref())
+            .map(|source| source.as_ref() as &(dyn Error + 'static))
+    }
 }
 
 impl fmt::Display for ErrorCode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
+        match self {
             ErrorCode::TickerError => f.write_str("There is an issue with the Ticker format"),
             ErrorCode::OptionsTickerError => f.write_str("There is an issue with the Options Ticker format"),
             ErrorCode::TickerNotSet => f.write_str("The ticker does not appear to have any value set, please set a value for the ticker"),
             ErrorCode::APIError => f.write_str("There is an issue with the API Key"),
-            ErrorCode::RequestError => f.write_str("There is an issue with the Request"),
+            ErrorCode::RequestError(details) => write!(f, "There is an issue with the Request{}", details),
             ErrorCode::FormatError => f.write_str("There is an issue with the Format"),
             ErrorCode::DateError => f.write_str("There is an issue with the Date"),
             ErrorCode::DateNotSet => f.write_str("There is no date set"),
@@ -66,7 +286,7 @@ impl fmt::Display for ErrorCode {
             ErrorCode::OrderNotSet => f.write_str("There is no order set"),
             ErrorCode::TimestampNotSet => f.write_str("There is no timestamp set"),
             ErrorCode::ContractTypeNotSet => f.write_str("There is no contract type set"),
-            ErrorCode::JSONParseError => f.write_str("There is an issue with parsing the JSON"),
+            ErrorCode::JSONParseError(details) => write!(f, "There is an issue with parsing the JSON{}", details),
             ErrorCode::StrikePriceNotSet => f.write_str("There is no strike price set"),
             ErrorCode::DateToNotSet => f.write_str("There is no to date set"),
             ErrorCode::DateFromNotSet => f.write_str("There is no from date set"),
@@ -85,6 +305,85 @@ impl fmt::Display for ErrorCode {
             ErrorCode::DirectionNotSet => f.write_str("There is no direction set"),
             ErrorCode::TickerTypeNotSet => f.write_str("There is no ticker type set"),
             ErrorCode::NoNextURL => f.write_str("The is no next URL"),
+            ErrorCode::ClientError(details) => write!(f, "There is an issue building the HTTP client{}", details),
+            ErrorCode::Unauthorized(details) => write!(f, "The API Key was not accepted{}", details),
+            ErrorCode::Forbidden(details) => write!(f, "The API Key's plan does not include this data{}", details),
+            ErrorCode::NotFound(details) => write!(f, "The requested resource was not found{}", details),
+            ErrorCode::RateLimited(details) => write!(f, "The request rate limit has been exceeded{}", details),
+            ErrorCode::ServerError(details) => write!(f, "There is an issue with the Polygon server{}", details),
+            ErrorCode::HTTPError(details) => write!(f, "The request was not successful{}", details),
+            ErrorCode::Timeout(details) => write!(f, "The request timed out{}", details),
+            ErrorCode::ExchangeError => f.write_str("There is an issue with the Exchange MIC format"),
+            ErrorCode::CIKError => f.write_str("There is an issue with the CIK format"),
+            ErrorCode::CUSIPError => f.write_str("There is an issue with the CUSIP format"),
+            ErrorCode::SearchNotSet => f.write_str("There is no search set"),
+            ErrorCode::MarketNotSet => f.write_str("There is no market set"),
+            ErrorCode::ExchangeNotSet => f.write_str("There is no exchange set"),
+            ErrorCode::TypeNotSet => f.write_str("There is no type set"),
+            ErrorCode::CIKNotSet => f.write_str("There is no CIK set"),
+            ErrorCode::CUSIPNotSet => f.write_str("There is no CUSIP set"),
+            ErrorCode::ActiveNotSet => f.write_str("There is no active set"),
+            ErrorCode::PayDateError => f.write_str("There is an issue with the pay date"),
+            ErrorCode::RecordDateError => f.write_str("There is an issue with the record date"),
+            ErrorCode::DeclarationDateError => f.write_str("There is an issue with the declaration date"),
+            ErrorCode::CashAmountToError => f.write_str("There is an issue with the cash amount to"),
+            ErrorCode::PayDateNotSet => f.write_str("There is no pay date set"),
+            ErrorCode::RecordDateNotSet => f.write_str("There is no record date set"),
+            ErrorCode::DeclarationDateNotSet => f.write_str("There is no declaration date set"),
+            ErrorCode::CashAmountNotSet => f.write_str("There is no cash amount set"),
+            ErrorCode::FrequencyNotSet => f.write_str("There is no frequency set"),
+            ErrorCode::DividendTypeNotSet => f.write_str("There is no dividend type set"),
+            ErrorCode::ReverseSplitNotSet => f.write_str("There is no reverse split set"),
+            ErrorCode::SICError => f.write_str("There is an issue with the SIC code format"),
+            ErrorCode::PeriodOfReportDateError => f.write_str("There is an issue with the period of report date"),
+            ErrorCode::CompanyNameNotSet => f.write_str("There is no company name set"),
+            ErrorCode::SICNotSet => f.write_str("There is no SIC code set"),
+            ErrorCode::PeriodOfReportDateNotSet => f.write_str("There is no period of report date set"),
+            ErrorCode::FinancialTimeframeNotSet => f.write_str("There is no financial timeframe set"),
+            ErrorCode::IncludeSourcesNotSet => f.write_str("There is no include sources set"),
+            ErrorCode::AssetClassNotSet => f.write_str("There is no asset class set"),
+            ErrorCode::LocaleNotSet => f.write_str("There is no locale set"),
+            ErrorCode::DataTypeNotSet => f.write_str("There is no data type set"),
+            ErrorCode::IdNotSet => f.write_str("There is no id set"),
+            ErrorCode::SipNotSet => f.write_str("There is no SIP set"),
+            ErrorCode::IdentifierError => f.write_str("The identifier is not a valid ticker, CUSIP or composite FIGI"),
+            ErrorCode::IdentifierNotSet => f.write_str("There is no identifier set"),
+            ErrorCode::EventTypeNotSet => f.write_str("There is no event type set"),
+            ErrorCode::PublishedUtcError => f.write_str("There is an issue with the published timestamp, use YYYY-MM-DD or RFC3339"),
+            ErrorCode::PublishedUtcNotSet => f.write_str("There is no published timestamp set"),
+            ErrorCode::TickerRangeError => f.write_str("A ticker can't be set together with a ticker range"),
+            ErrorCode::PublishedUtcRangeError => f.write_str("A published timestamp can't be set together with a published timestamp range"),
+            ErrorCode::WebSocketError(details) => write!(f, "There is an issue with the WebSocket connection{}", details),
+            ErrorCode::WebSocketAuthenticationError(details) => write!(f, "The WebSocket authentication failed{}", details),
+            ErrorCode::WebSocketClosed => f.write_str("The WebSocket connection is closed"),
+            ErrorCode::WebSocketQueueFull => f.write_str("The WebSocket command queue is full"),
+            ErrorCode::ChannelNotValidForCluster => f.write_str("The channel is not available on this cluster"),
         }
     }
 }
+
+#[test]
+fn test_error_details_from_response() {
+    let details = ErrorDetails::from_response(
+        403,
+        None,
+        r#"{"status":"NOT_AUTHORIZED","request_id":"req12345","message":"You are not entitled to this data."}"#,
+    );
+    assert_eq!(details.status, Some(403));
+    assert_eq!(details.request_id.as_deref(), Some("req12345"));
+    assert_eq!(
+        details.message.as_deref(),
+        Some("You are not entitled to this data.")
+    );
+    let error = ErrorCode::from_status(details);
+    assert_eq!(
+        error.to_string(),
+        "The API Key's plan does not include this data (HTTP 403): You are not entitled to this data. [request_id: req12345]"
+    );
+    let details = ErrorDetails::from_response(502, None, "Bad Gateway");
+    assert_eq!(details.message.as_deref(), Some("Bad Gateway"));
+    assert!(matches!(
+        ErrorCode::from_status(details),
+        ErrorCode::ServerError(_)
+    ));
+}
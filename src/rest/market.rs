@@ -52,7 +52,7 @@ pub enum Market {
     PairTrade(pair_trade::PairTrade),
     Previous(previous::Previous),
     Quotes(quotes::Quotes),
-    Snapshots(snapshot::Snapshot),
+    Snapshots(Box<snapshot::Snapshot>),
     TechnicalIndicators(technical_indicators::TechnicalIndicators),
     Trades(trades::Trades),
 }
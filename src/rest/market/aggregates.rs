@@ -1,12 +1,14 @@
 use crate::{
+    client::PolygonClient,
     data_types::{bar::Bar, Parse},
     rest::{
         error::ErrorCode,
         parameters::{Parameter, ParameterRequirment, Parameters, Sort, TickerTypes, Timespan},
     },
-    tools::{request::Request, verification::Verification},
+    tools::{pagination::Paginate, request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Aggregates {
@@ -45,9 +47,63 @@ impl Parse for Aggregates {
     }
 }
 
+impl Paginate for Aggregates {
+    type Item = Bar;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Bar> {
+        self.results.unwrap_or_default()
+    }
+}
+
 pub trait AggregatesRequest {
+    fn get_aggregates_async(
+        client: &PolygonClient,
+        ticker: String,
+        multiplier: u16,
+        timespan: Timespan,
+        from: String,
+        to: String,
+        sort: Option<Sort>,
+        limit: Option<u16>,
+        adjusted: Option<bool>,
+    ) -> impl Future<Output = Result<Aggregates, ErrorCode>> + Send {
+        async move {
+            let aggregates_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                adjusted: adjusted,
+                multiplier: Some(multiplier),
+                timespan: Some(timespan),
+                from: Some(from),
+                to: Some(to),
+                sort: sort,
+                limit: limit,
+                ..Parameters::default()
+            };
+            if let Err(check) =
+                Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &aggregates_parameters)
+            {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &aggregates_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(Aggregates::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_aggregates(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         multiplier: u16,
         timespan: Timespan,
@@ -57,31 +113,9 @@ pub trait AggregatesRequest {
         limit: Option<u16>,
         adjusted: Option<bool>,
     ) -> Result<Aggregates, ErrorCode> {
-        let aggregates_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            adjusted: adjusted,
-            multiplier: Some(multiplier),
-            timespan: Some(timespan),
-            from: Some(from),
-            to: Some(to),
-            sort: sort,
-            limit: limit,
-            ..Parameters::default()
-        };
-        if let Err(check) =
-            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &aggregates_parameters)
-        {
-            return Err(check);
-        }
-        let url = match url(&aggregates_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(Aggregates::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_aggregates_async(
+            client, ticker, multiplier, timespan, from, to, sort, limit, adjusted,
+        ))
     }
 }
 
@@ -120,9 +154,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/aggs/ticker/{}/range/{}/{}/{}/{}?{}{}{}apiKey={}",
+        "{}/v2/aggs/ticker/{}/range/{}/{}/{}/{}?{}{}{}apiKey={}",
+        base_url,
         match &parameters.ticker {
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -208,6 +243,42 @@ fn test_url() {
     parameters.adjusted = Some(true);
     parameters.sort = Some(Sort::Asc);
     parameters.limit = Some(5000);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/aggs/ticker/AAPL/range/1/day/2023-03-01/2023-04-01?adjusted=true&sort=asc&limit=5000&apiKey=apiKey");
 }
+
+#[tokio::test]
+async fn test_get_aggregates_async() {
+    let client = PolygonClient::new("apiKey").unwrap();
+    let aggregates = Aggregates::get_aggregates_async(
+        &client,
+        String::from("AAPL"),
+        1,
+        Timespan::Day,
+        String::from("2023-03-01"),
+        String::from("2023-04-01"),
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(aggregates.err(), Some(ErrorCode::APIError));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_get_aggregates() {
+    let client = PolygonClient::new("apiKey").unwrap();
+    let aggregates = Aggregates::get_aggregates(
+        &client,
+        String::from("AAPL"),
+        1,
+        Timespan::Day,
+        String::from("2023-03-01"),
+        String::from("2023-04-01"),
+        None,
+        None,
+        None,
+    );
+    assert_eq!(aggregates.err(), Some(ErrorCode::APIError));
+}
@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{quote::Quote, Parse},
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CurrencyConversion {
@@ -45,34 +47,46 @@ impl Parse for CurrencyConversion {
 }
 
 pub trait CurrencyConversionRequest {
+    fn get_currency_conversion_async(
+        client: &PolygonClient,
+        ticker: String,
+        amount: Option<f64>,
+        precision: Option<u8>,
+    ) -> impl Future<Output = Result<CurrencyConversion, ErrorCode>> + Send {
+        async move {
+            let currency_conversion_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                amount: amount,
+                precision: precision,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::forex(),
+                PARAMETERS,
+                &currency_conversion_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &currency_conversion_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(CurrencyConversion::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_currency_conversion(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         amount: Option<f64>,
         precision: Option<u8>,
     ) -> Result<CurrencyConversion, ErrorCode> {
-        let currency_conversion_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            amount: amount,
-            precision: precision,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::forex(),
-            PARAMETERS,
-            &currency_conversion_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&currency_conversion_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(CurrencyConversion::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_currency_conversion_async(client, ticker, amount, precision))
     }
 }
 
@@ -91,7 +105,7 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let from = match &parameters.ticker {
         Some(ticker) => ticker[2..5].to_string(),
         None => return Err(ErrorCode::TickerNotSet),
@@ -101,7 +115,8 @@ fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
         None => return Err(ErrorCode::TickerNotSet),
     };
     let url = String::from(format!(
-        "https://api.polygon.io/v1/conversion/{}/{}?{}{}apiKey={}",
+        "{}/v1/conversion/{}/{}?{}{}apiKey={}",
+        base_url,
         from,
         to,
         if let Some(s) = &parameters.amount {
@@ -153,6 +168,6 @@ fn test_url() {
     parameters.ticker = Some(String::from("C:EURUSD"));
     parameters.amount = Some(100.0);
     parameters.precision = Some(2);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v1/conversion/EUR/USD?amount=100&precision=2&apiKey=apiKey");
 }
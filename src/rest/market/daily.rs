@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::Parse,
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Daily {
@@ -52,34 +54,46 @@ impl Parse for Daily {
 }
 
 pub trait DailyRequest {
+    fn get_daily_async(
+        client: &PolygonClient,
+        ticker: String,
+        date: String,
+        adjusted: Option<bool>,
+    ) -> impl Future<Output = Result<Daily, ErrorCode>> + Send {
+        async move {
+            let daily_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                date: Some(date),
+                adjusted: adjusted,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::set(true, true, false, false, true),
+                PARAMETERS,
+                &daily_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &daily_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(Daily::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_daily(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         date: String,
         adjusted: Option<bool>,
     ) -> Result<Daily, ErrorCode> {
-        let daily_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            date: Some(date),
-            adjusted: adjusted,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::set(true, true, false, false, true),
-            PARAMETERS,
-            &daily_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&daily_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(Daily::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_daily_async(client, ticker, date, adjusted))
     }
 }
 
@@ -97,9 +111,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
         parameter: Parameter::Adjusted,
     },
 ];
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v1/open-close/{}/{}?{}apiKey={}",
+        "{}/v1/open-close/{}/{}?{}apiKey={}",
+        base_url,
         match &parameters.ticker {
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -151,6 +166,6 @@ fn test_url() {
     parameters.ticker = Some(String::from("AAPL"));
     parameters.date = Some(String::from("2023-04-01"));
     parameters.adjusted = Some(true);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v1/open-close/AAPL/2023-04-01?adjusted=true&apiKey=apiKey");
 }
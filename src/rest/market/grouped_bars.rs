@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{bar::Bar, Parse},
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct GroupedBars {
@@ -38,34 +40,46 @@ impl Parse for GroupedBars {
 }
 
 pub trait GroupedBarsRequest {
+    fn get_grouped_bars_async(
+        client: &PolygonClient,
+        date: String,
+        include_otc: Option<bool>,
+        adjusted: Option<bool>,
+    ) -> impl Future<Output = Result<GroupedBars, ErrorCode>> + Send {
+        async move {
+            let grouped_bars_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                date: Some(date),
+                adjusted: adjusted,
+                include_otc: include_otc,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::set(true, false, false, true, true),
+                PARAMETERS,
+                &grouped_bars_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &grouped_bars_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(GroupedBars::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_grouped_bars(
-        api_key: &String,
+        client: &PolygonClient,
         date: String,
         include_otc: Option<bool>,
         adjusted: Option<bool>,
     ) -> Result<GroupedBars, ErrorCode> {
-        let grouped_bars_parameters = Parameters {
-            api_key: api_key.to_string(),
-            date: Some(date),
-            adjusted: adjusted,
-            include_otc: include_otc,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::set(true, false, false, true, true),
-            PARAMETERS,
-            &grouped_bars_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&grouped_bars_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(GroupedBars::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_grouped_bars_async(client, date, include_otc, adjusted))
     }
 }
 
@@ -84,9 +98,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/aggs/grouped/locale/us/market/stocks/{}?{}{}apiKey={}",
+        "{}/v2/aggs/grouped/locale/us/market/stocks/{}?{}{}apiKey={}",
+        base_url,
         match &parameters.date {
             Some(date) => date,
             None => return Err(ErrorCode::DateNotSet),
@@ -142,6 +157,6 @@ fn test_url() {
     parameters.date = Some(String::from("2023-04-01"));
     parameters.adjusted = Some(true);
     parameters.include_otc = Some(true);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/aggs/grouped/locale/us/market/stocks/2023-04-01?adjusted=true&include_otc=true&apiKey=apiKey");
 }
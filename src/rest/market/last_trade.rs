@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{trade::Trade, Parse},
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LastTrade {
@@ -32,28 +34,38 @@ impl Parse for LastTrade {
 }
 
 pub trait LastTradeRequest {
-    fn get_last_trade(api_key: &String, ticker: String) -> Result<LastTrade, ErrorCode> {
-        let last_trade_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::set(true, true, false, false, false),
-            PARAMETERS,
-            &last_trade_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&last_trade_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(LastTrade::parse(&mut map)),
-            Err(e) => return Err(e),
+    fn get_last_trade_async(
+        client: &PolygonClient,
+        ticker: String,
+    ) -> impl Future<Output = Result<LastTrade, ErrorCode>> + Send {
+        async move {
+            let last_trade_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::set(true, true, false, false, false),
+                PARAMETERS,
+                &last_trade_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &last_trade_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(LastTrade::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
         }
     }
+
+    #[cfg(feature = "blocking")]
+    fn get_last_trade(client: &PolygonClient, ticker: String) -> Result<LastTrade, ErrorCode> {
+        Request::blocking(Self::get_last_trade_async(client, ticker))
+    }
 }
 
 const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirment {
@@ -61,9 +73,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirme
     parameter: Parameter::Ticker,
 }];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/last/trade/{}apiKey={}",
+        "{}/v2/last/trade/{}apiKey={}",
+        base_url,
         match &parameters.ticker {
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -107,6 +120,6 @@ fn test_url() {
     let mut parameters = Parameters::default();
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("AAPL"));
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/last/trade/AAPLapiKey=apiKey");
 }
@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{quote::Quote, Parse},
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PairQuote {
@@ -35,28 +37,38 @@ impl Parse for PairQuote {
 }
 
 pub trait PairQuoteRequest {
-    fn get_pair_quote(api_key: &String, ticker: String) -> Result<PairQuote, ErrorCode> {
-        let pair_quote_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::forex(),
-            PARAMETERS,
-            &pair_quote_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&pair_quote_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(PairQuote::parse(&mut map)),
-            Err(e) => return Err(e),
+    fn get_pair_quote_async(
+        client: &PolygonClient,
+        ticker: String,
+    ) -> impl Future<Output = Result<PairQuote, ErrorCode>> + Send {
+        async move {
+            let pair_quote_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::forex(),
+                PARAMETERS,
+                &pair_quote_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &pair_quote_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(PairQuote::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
         }
     }
+
+    #[cfg(feature = "blocking")]
+    fn get_pair_quote(client: &PolygonClient, ticker: String) -> Result<PairQuote, ErrorCode> {
+        Request::blocking(Self::get_pair_quote_async(client, ticker))
+    }
 }
 
 const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirment {
@@ -64,7 +76,7 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirme
     parameter: Parameter::Ticker,
 }];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let from = match &parameters.ticker {
         Some(ticker) => ticker[2..4].to_string(),
         None => return Err(ErrorCode::TickerNotSet),
@@ -74,7 +86,8 @@ fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
         None => return Err(ErrorCode::TickerNotSet),
     };
     let url = String::from(format!(
-        "https://api.polygon.io/v1/lastquote/currencies/{}/{}?apiKey={}",
+        "{}/v1/lastquote/currencies/{}/{}?apiKey={}",
+        base_url,
         from, to, &parameters.api_key,
     ));
     Ok(url)
@@ -105,7 +118,7 @@ fn test_url() {
     let mut parameters = Parameters::default();
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("C:EURUSD"));
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v1/lastquote/currencies/EU/US?apiKey=apiKey");
 }
 
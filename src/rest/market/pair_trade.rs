@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{trade::Trade, Parse},
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PairTrade {
@@ -36,28 +38,43 @@ impl Parse for PairTrade {
 
 pub trait PairTradeRequest {
     //Once reference to tickers is complete extract to and from via ticker reference
-    fn get_pair_trade(api_key: &String, from: String, to: String) -> Result<PairTrade, ErrorCode> {
-        let pair_trade_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(format!("X:{}{}",from,to)),
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &&TickerTypes::crypto(),
-            PARAMETERS,
-            &pair_trade_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&pair_trade_parameters, from, to) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(PairTrade::parse(&mut map)),
-            Err(e) => return Err(e),
+    fn get_pair_trade_async(
+        client: &PolygonClient,
+        from: String,
+        to: String,
+    ) -> impl Future<Output = Result<PairTrade, ErrorCode>> + Send {
+        async move {
+            let pair_trade_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(format!("X:{}{}",from,to)),
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &&TickerTypes::crypto(),
+                PARAMETERS,
+                &pair_trade_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &pair_trade_parameters, from, to) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(PairTrade::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
         }
     }
+
+    #[cfg(feature = "blocking")]
+    fn get_pair_trade(
+        client: &PolygonClient,
+        from: String,
+        to: String,
+    ) -> Result<PairTrade, ErrorCode> {
+        Request::blocking(Self::get_pair_trade_async(client, from, to))
+    }
 }
 
 const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirment {
@@ -65,10 +82,15 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirme
     parameter: Parameter::Ticker,
 }];
 
-fn url(parameters: &Parameters, from: String, to: String) -> Result<String, ErrorCode> {
+fn url(
+    base_url: &str,
+    parameters: &Parameters,
+    from: String,
+    to: String,
+) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v1/last/crypto/{}/{}?apiKey={}",
-        from, to, &parameters.api_key,
+        "{}/v1/last/crypto/{}/{}?apiKey={}",
+        base_url, from, to, &parameters.api_key,
     ));
     Ok(url)
 }
@@ -99,6 +121,12 @@ fn test_url() {
     let mut parameters = Parameters::default();
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("X:BTCUSD"));
-    let url = url(&parameters, String::from("BTC"), String::from("USD")).unwrap();
+    let url = url(
+        PolygonClient::DEFAULT_BASE_URL,
+        &parameters,
+        String::from("BTC"),
+        String::from("USD"),
+    )
+    .unwrap();
     assert_eq!(url, "https://api.polygon.io/v1/last/crypto/BTC/USD?apiKey=apiKey");
 }
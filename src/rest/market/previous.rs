@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{bar::Bar, Parse},
     rest::{
         error::ErrorCode,
@@ -7,6 +8,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Previous {
@@ -44,30 +46,41 @@ impl Parse for Previous {
 }
 
 pub trait PreviousRequest {
+    fn get_previous_async(
+        client: &PolygonClient,
+        ticker: String,
+        adjusted: Option<bool>,
+    ) -> impl Future<Output = Result<Previous, ErrorCode>> + Send {
+        async move {
+            let previous_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                adjusted: adjusted,
+                ..Parameters::default()
+            };
+            if let Err(check) =
+                Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &previous_parameters)
+            {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &previous_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(Previous::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_previous(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         adjusted: Option<bool>,
     ) -> Result<Previous, ErrorCode> {
-        let previous_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            adjusted: adjusted,
-            ..Parameters::default()
-        };
-        if let Err(check) =
-            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &previous_parameters)
-        {
-            return Err(check);
-        }
-        let url = match url(&previous_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(Previous::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_previous_async(client, ticker, adjusted))
     }
 }
 
@@ -82,9 +95,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/aggs/ticker/{}/prev?{}apiKey={}",
+        "{}/v2/aggs/ticker/{}/prev?{}apiKey={}",
+        base_url,
         match &parameters.ticker {
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -136,6 +150,6 @@ fn test_url() {
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("AAPL"));
     parameters.adjusted = Some(true);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/aggs/ticker/AAPL/prev?adjusted=true&apiKey=apiKey");
 }
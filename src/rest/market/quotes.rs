@@ -1,12 +1,14 @@
 use crate::{
+    client::PolygonClient,
     data_types::{quote::Quote, Parse},
     rest::{
         error::ErrorCode,
         parameters::{Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes},
     },
-    tools::{request::Request, verification::Verification},
+    tools::{pagination::Paginate, request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Quotes {
@@ -34,9 +36,68 @@ impl Parse for Quotes {
     }
 }
 
+impl Paginate for Quotes {
+    type Item = Quote;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Quote> {
+        self.quotes.unwrap_or_default()
+    }
+}
+
 pub trait QuotesRequest {
+    fn get_quotes_async(
+        client: &PolygonClient,
+        ticker: String,
+        timestamp: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        sort: Option<Sortv3>,
+        limit: Option<u16>,
+        order: Option<Order>,
+    ) -> impl Future<Output = Result<Quotes, ErrorCode>> + Send {
+        async move {
+            let ts = if to.is_some() || from.is_some() {
+                None
+            } else {
+                timestamp
+            };
+            let quotes_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                timestamp: ts,
+                from: from,
+                to: to,
+                sortv3: sort,
+                limit: limit,
+                order: order,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::set(true, true, false, false, false),
+                PARAMETERS,
+                &quotes_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &quotes_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(Quotes::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_quotes(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         timestamp: Option<String>,
         from: Option<String>,
@@ -45,37 +106,9 @@ pub trait QuotesRequest {
         limit: Option<u16>,
         order: Option<Order>,
     ) -> Result<Quotes, ErrorCode> {
-        let ts = if to.is_some() || from.is_some() {
-            None
-        } else {
-            timestamp
-        };
-        let quotes_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            timestamp: ts,
-            from: from,
-            to: to,
-            sortv3: sort,
-            limit: limit,
-            order: order,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::set(true, true, false, false, false),
-            PARAMETERS,
-            &quotes_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&quotes_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(Quotes::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_quotes_async(
+            client, ticker, timestamp, from, to, sort, limit, order,
+        ))
     }
 }
 
@@ -110,9 +143,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v3/quotes/{}?{}{}{}{}{}{}apiKey={}",
+        "{}/v3/quotes/{}?{}{}{}{}{}{}apiKey={}",
+        base_url,
         match &parameters.ticker {
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -200,6 +234,6 @@ fn test_url() {
     parameters.sortv3 = Some(Sortv3::Timestamp);
     parameters.limit = Some(1);
     parameters.order = Some(Order::Asc);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v3/quotes/AAPL?timestamp.gte=2023-03-01&timestamp.lte=2023-04-01&order=asc&limit=1&sort=timestamp&apiKey=apiKey");
 }
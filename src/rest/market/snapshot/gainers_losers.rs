@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{ticker::Ticker, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,6 +6,7 @@ use crate::rest::{
 };
 use crate::tools::{request::Request, verification::Verification};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct GainersLosers {
@@ -23,46 +25,63 @@ impl Parse for GainersLosers {
 }
 
 pub trait GainersLosersRequest {
+    fn get_gainers_losers_async(
+        client: &PolygonClient,
+        direction: Direction,
+        include_otc: Option<bool>,
+        ticker_type: TickerType,
+    ) -> impl Future<Output = Result<GainersLosers, ErrorCode>> + Send {
+        async move {
+            let ticker_types = match ticker_type {
+                TickerType::Stocks => TickerTypes::stocks(),
+                TickerType::Forex => TickerTypes::forex(),
+                TickerType::Crypto => TickerTypes::crypto(),
+                _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
+            };
+            let includeotc = match ticker_type {
+                TickerType::Forex | TickerType::Crypto => None,
+                _ => include_otc,
+            };
+            let gainers_losers_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                direction: Some(direction),
+                include_otc: includeotc,
+                ..Parameters::default()
+            };
+            if let Err(check) =
+                Verification::check_parameters(&ticker_types, PARAMETERS, &gainers_losers_parameters)
+            {
+                return Err(check);
+            }
+            let locale = match ticker_type {
+                TickerType::Stocks => String::from("us"),
+                TickerType::Forex | TickerType::Crypto => String::from("global"),
+                _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
+            };
+            let url = match url(&client.base_url, &gainers_losers_parameters, locale, ticker_type) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(GainersLosers::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_gainers_losers(
-        api_key: &String,
+        client: &PolygonClient,
         direction: Direction,
         include_otc: Option<bool>,
         ticker_type: TickerType,
     ) -> Result<GainersLosers, ErrorCode> {
-        let ticker_types = match ticker_type {
-            TickerType::Stocks => TickerTypes::stocks(),
-            TickerType::Forex => TickerTypes::forex(),
-            TickerType::Crypto => TickerTypes::crypto(),
-            _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
-        };
-        let includeotc = match ticker_type {
-            TickerType::Forex | TickerType::Crypto => None,
-            _ => include_otc,
-        };
-        let gainers_losers_parameters = Parameters {
-            api_key: api_key.to_string(),
-            direction: Some(direction),
-            include_otc: includeotc,
-            ..Parameters::default()
-        };
-        if let Err(check) =
-            Verification::check_parameters(&ticker_types, PARAMETERS, &gainers_losers_parameters)
-        {
-            return Err(check);
-        }
-        let locale = match ticker_type {
-            TickerType::Stocks => String::from("us"),
-            TickerType::Forex | TickerType::Crypto => String::from("global"),
-            _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
-        };
-        let url = match url(&gainers_losers_parameters, locale, ticker_type) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(GainersLosers::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_gainers_losers_async(
+            client,
+            direction,
+            include_otc,
+            ticker_type,
+        ))
     }
 }
 
@@ -78,12 +97,14 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
 ];
 
 fn url(
+    base_url: &str,
     parameters: &Parameters,
     locale: String,
     ticker_type: TickerType,
 ) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/snapshot/locale/{}/markets/{}/{}?{}apiKey={}",
+        "{}/v2/snapshot/locale/{}/markets/{}/{}?{}apiKey={}",
+        base_url,
         locale,
         ticker_type.to_string().to_lowercase(),
         if let Some(s) = &parameters.direction {
@@ -204,6 +225,12 @@ fn test_url() {
     parameters.api_key = String::from("apiKey");
     parameters.direction = Some(Direction::Gainers);
     parameters.include_otc = Some(true);
-    let url = url(&parameters, String::from("us"), TickerType::Stocks).unwrap();
+    let url = url(
+        PolygonClient::DEFAULT_BASE_URL,
+        &parameters,
+        String::from("us"),
+        TickerType::Stocks,
+    )
+    .unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/snapshot/locale/us/markets/stocks/gainers?include_otc=true&apiKey=apiKey");
 }
\ No newline at end of file
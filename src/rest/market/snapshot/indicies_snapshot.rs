@@ -1,10 +1,12 @@
+use crate::client::PolygonClient;
 use crate::data_types::{indicie::Indicie, Parse};
 use crate::rest::{
     error::ErrorCode,
     parameters::{Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes},
 };
-use crate::tools::{request::Request, verification::Verification};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct IndiciesSnapshot {
@@ -32,9 +34,65 @@ impl Parse for IndiciesSnapshot {
     }
 }
 
+impl Paginate for IndiciesSnapshot {
+    type Item = Indicie;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Indicie> {
+        self.indicies.unwrap_or_default()
+    }
+}
+
 pub trait IndiciesSnapshotRequest {
+    fn get_indicie_snapshot_async(
+        client: &PolygonClient,
+        tickers: Option<Vec<String>>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        sort: Option<Sortv3>,
+        limit: Option<u16>,
+        order: Option<Order>,
+    ) -> impl Future<Output = Result<IndiciesSnapshot, ErrorCode>> + Send {
+        async move {
+            let tickers = if ticker_from.is_some() || ticker_to.is_some() {
+                None
+            } else {
+                tickers
+            };
+            let indicies_snapshot_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                tickers: tickers,
+                ticker_from: ticker_from,
+                ticker_to: ticker_to,
+                sortv3: sort,
+                limit: limit,
+                order: order,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::indicies(),
+                PARAMETERS,
+                &indicies_snapshot_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &indicies_snapshot_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(IndiciesSnapshot::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_indicie_snapshot(
-        api_key: &String,
+        client: &PolygonClient,
         tickers: Option<Vec<String>>,
         ticker_from: Option<String>,
         ticker_to: Option<String>,
@@ -42,36 +100,15 @@ pub trait IndiciesSnapshotRequest {
         limit: Option<u16>,
         order: Option<Order>,
     ) -> Result<IndiciesSnapshot, ErrorCode> {
-        let tickers = if ticker_from.is_some() || ticker_to.is_some() {
-            None
-        } else {
-            tickers
-        };
-        let indicies_snapshot_parameters = Parameters {
-            api_key: api_key.to_string(),
-            tickers: tickers,
-            ticker_from: ticker_from,
-            ticker_to: ticker_to,
-            sortv3: sort,
-            limit: limit,
-            order: order,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::indicies(),
-            PARAMETERS,
-            &indicies_snapshot_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&indicies_snapshot_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(IndiciesSnapshot::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_indicie_snapshot_async(
+            client,
+            tickers,
+            ticker_from,
+            ticker_to,
+            sort,
+            limit,
+            order,
+        ))
     }
 }
 
@@ -102,7 +139,7 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let tickers = {
         let mut tickers_flattened = String::new();
         if let Some(tickers) = &parameters.tickers {
@@ -115,7 +152,8 @@ fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
         tickers_flattened
     };
     let url = String::from(format!(
-        "https://api.polygon.io/v3/snapshot/indicies?{}{}{}{}{}{}apiKey={}",
+        "{}/v3/snapshot/indicies?{}{}{}{}{}{}apiKey={}",
+        base_url,
         tickers,
         if let Some(tf) = &parameters.ticker_from {
             format!("ticker.gte={}&", tf)
@@ -188,6 +226,6 @@ fn test_url() {
     parameters.sortv3 = Some(Sortv3::Ticker);
     parameters.limit = Some(1);
     parameters.order = Some(Order::Asc);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v3/snapshot/indicies?tickers.any_of=I:DJI,I:SPX&order=asc&limit=1&sort=ticker&apiKey=apiKey");
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{l2::L2, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,6 +6,7 @@ use crate::rest::{
 };
 use crate::tools::{request::Request, verification::Verification};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct L2Snapshot {
@@ -17,35 +19,49 @@ impl L2SnapshotRequest for L2Snapshot {}
 impl Parse for L2Snapshot {
     fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
         let status = Self::string_parse(map, vec!["status"]);
-        let l2 = Self::array_parse(map, vec!["data"]);
+        //The book endpoint returns a single object under data rather than an array
+        let l2 = match Self::object_parse(map, vec!["data"]) {
+            Some(l2) => Some(vec![l2]),
+            None => Self::array_parse(map, vec!["data"]),
+        };
 
         L2Snapshot { status, l2 }
     }
 }
 
 pub trait L2SnapshotRequest {
-    fn get_l2(api_key: &String, ticker: String) -> Result<L2Snapshot, ErrorCode> {
-        let l2_snapshot_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::crypto(),
-            PARAMETERS,
-            &l2_snapshot_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&l2_snapshot_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(L2Snapshot::parse(&mut map)),
-            Err(e) => return Err(e),
+    fn get_l2_async(
+        client: &PolygonClient,
+        ticker: String,
+    ) -> impl Future<Output = Result<L2Snapshot, ErrorCode>> + Send {
+        async move {
+            let l2_snapshot_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::crypto(),
+                PARAMETERS,
+                &l2_snapshot_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &l2_snapshot_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(L2Snapshot::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
         }
     }
+
+    #[cfg(feature = "blocking")]
+    fn get_l2(client: &PolygonClient, ticker: String) -> Result<L2Snapshot, ErrorCode> {
+        Request::blocking(Self::get_l2_async(client, ticker))
+    }
 }
 
 const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirment {
@@ -53,9 +69,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirme
     parameter: Parameter::Ticker,
 }];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/snapshot/locale/global/markets/crypto/tickers/{}/book?apiKey={}",
+        "{}/v2/snapshot/locale/global/markets/crypto/tickers/{}/book?apiKey={}",
+        base_url,
         match &parameters.ticker {
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -100,6 +117,26 @@ fn test_l2_snapshot_parse() {
     let l2_snapshot = L2Snapshot::parse(&data.as_object().unwrap());
     assert_eq!(l2_snapshot.status.unwrap(), "OK");
     assert_eq!(l2_snapshot.l2.unwrap()[0].bids.clone().unwrap()[0].price.unwrap(), 1.23);
+    let data = serde_json::json!({
+        "status": "OK",
+        "data": {
+            "askCount": 593.1412981600005,
+            "asks": [{"p": 11454, "x": {"2": 1}}],
+            "bidCount": 694.951789670001,
+            "bids": [{"p": 16303.17, "x": {"1": 2}}],
+            "spread": -4849.17,
+            "ticker": "X:BTCUSD",
+            "updated": 1605295074162365000i64
+        }
+    });
+    let l2_snapshot = L2Snapshot::parse(&data.as_object().unwrap());
+    let l2 = l2_snapshot.l2.unwrap()[0].clone();
+    assert_eq!(l2.ticker.unwrap(), "X:BTCUSD");
+    assert_eq!(l2.bid_count.unwrap(), 694.951789670001);
+    assert_eq!(l2.timestamp.unwrap(), 1605295074162);
+    let bids = l2.bids.unwrap();
+    assert_eq!(bids[0].price.unwrap(), 16303.17);
+    assert_eq!(bids[0].size.clone().unwrap().get("1").unwrap(), &2.0);
 }
 
 #[test]
@@ -107,6 +144,6 @@ fn test_url() {
     let mut parameters = Parameters::default();
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("X:BTCUSD"));
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/snapshot/locale/global/markets/crypto/tickers/X:BTCUSD/book?apiKey=apiKey");
 }
@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{contract::Contract, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,7 +6,8 @@ use crate::rest::{
         ContractType, Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes,
     },
 };
-use crate::tools::{request::Request, verification::Verification};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use std::future::Future;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct OptionsChain {
@@ -32,10 +34,81 @@ impl Parse for OptionsChain {
     }
 }
 
+impl Paginate for OptionsChain {
+    type Item = Contract;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Contract> {
+        self.chain.unwrap_or_default()
+    }
+}
+
 pub trait OptionsChainRequest {
+    fn get_options_chain_async(
+        client: &PolygonClient,
+        underlying_asset: String,
+        date: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        strike_price: Option<f64>,
+        strike_price_from: Option<f64>,
+        strike_price_to: Option<f64>,
+        contract_type: Option<ContractType>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<OptionsChain, ErrorCode>> + Send {
+        async move {
+            let ts = if from.is_some() || from.is_some() {
+                None
+            } else {
+                date
+            };
+            let sp = if strike_price_from.is_some() || strike_price_to.is_some() {
+                None
+            } else {
+                strike_price
+            };
+            let options_chain_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                underlying_asset: Some(underlying_asset),
+                date: ts,
+                from: from,
+                to: to,
+                contract_type: contract_type,
+                order: order,
+                limit: limit,
+                sortv3: sort,
+                strike_price: sp,
+                strike_price_from: strike_price_from,
+                strike_price_to: strike_price_to,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::options(),
+                PARAMETERS,
+                &options_chain_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &options_chain_parameters){
+                Ok(url) => url,
+                Err(e) => return Err(e)
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(OptionsChain::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_options_chain(
-        
-        api_key: &String,
+        client: &PolygonClient,
         underlying_asset: String,
         date: Option<String>,
         from: Option<String>,
@@ -48,46 +121,20 @@ pub trait OptionsChainRequest {
         limit: Option<u16>,
         sort: Option<Sortv3>,
     ) -> Result<OptionsChain, ErrorCode> {
-        let ts = if from.is_some() || from.is_some() {
-            None
-        } else {
-            date
-        };
-        let sp = if strike_price_from.is_some() || strike_price_to.is_some() {
-            None
-        } else {
-            strike_price
-        };
-        let options_chain_parameters = Parameters {
-            api_key: api_key.to_string(),
-            underlying_asset: Some(underlying_asset),
-            date: ts,
-            from: from,
-            to: to,
-            contract_type: contract_type,
-            order: order,
-            limit: limit,
-            sortv3: sort,
-            strike_price: sp,
-            strike_price_from: strike_price_from,
-            strike_price_to: strike_price_to,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::options(),
-            PARAMETERS,
-            &options_chain_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&options_chain_parameters){
-            Ok(url) => url,
-            Err(e) => return Err(e)
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(OptionsChain::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_options_chain_async(
+            client,
+            underlying_asset,
+            date,
+            from,
+            to,
+            strike_price,
+            strike_price_from,
+            strike_price_to,
+            contract_type,
+            order,
+            limit,
+            sort,
+        ))
     }
 }
 
@@ -138,9 +185,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v3/snapshot/options/{}?{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        "{}/v3/snapshot/options/{}?{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
         match &parameters.underlying_asset {
             Some(underlying_asset) => underlying_asset,
             None => return Err(ErrorCode::UnderlyingAssetNotSet),
@@ -317,6 +365,6 @@ fn test_url() {
     parameters.order = Some(Order::Asc);
     parameters.limit = Some(1);
     parameters.sortv3 = Some(Sortv3::ExpirationDate);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v3/snapshot/options/AAPL?strike_price=100&strike_price.gte=90&strike_price.lte=110&expiration_date=2023-04-01&expiration_date.gte=2023-03-01&expiration_date.lte=2023-05-01&contract_type=call&order=asc&limit=1&sort=expiration_date&apiKey=apiKey");
 }
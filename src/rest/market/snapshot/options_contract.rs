@@ -1,9 +1,11 @@
+use crate::client::PolygonClient;
 use crate::data_types::{contract::Contract, Parse};
 use crate::rest::{
     error::ErrorCode,
     parameters::{Parameter, ParameterRequirment, Parameters, TickerTypes},
 };
 use crate::tools::{request::Request, verification::Verification};
+use std::future::Future;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct OptionsContract {
@@ -31,33 +33,43 @@ impl Parse for OptionsContract {
 }
 
 pub trait OptionsContractRequest {
+    fn options_contract_request_async(
+        client: &PolygonClient,
+        ticker: String,
+        underlying_asset: String,
+    ) -> impl Future<Output = Result<OptionsContract, ErrorCode>> + Send {
+        async move {
+            let options_contract_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                underlying_asset: Some(underlying_asset),
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::options(),
+                PARAMETERS,
+                &options_contract_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &options_contract_parameters){
+                Ok(url) => url,
+                Err(e) => return Err(e)
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(OptionsContract::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn options_contract_request(
-        
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         underlying_asset: String,
     ) -> Result<OptionsContract, ErrorCode> {
-        let options_contract_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            underlying_asset: Some(underlying_asset),
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::options(),
-            PARAMETERS,
-            &options_contract_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&options_contract_parameters){
-            Ok(url) => url,
-            Err(e) => return Err(e)
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(OptionsContract::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::options_contract_request_async(client, ticker, underlying_asset))
     }
 }
 
@@ -72,9 +84,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v3/snapshot/options/{}/{}?apiKey={}",
+        "{}/v3/snapshot/options/{}/{}?apiKey={}",
+        base_url,
         match &parameters.underlying_asset{
             Some(asset) => asset,
             None => return Err(ErrorCode::UnderlyingAssetNotSet)
@@ -188,6 +201,6 @@ fn test_url() {
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("O:AAL210820C00014000"));
     parameters.underlying_asset = Some(String::from("AAL"));
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v3/snapshot/options/AAL/O:AAL210820C00014000?apiKey=apiKey");
 }
@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{ticker::Ticker, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,6 +6,7 @@ use crate::rest::{
 };
 use crate::tools::{request::Request, verification::Verification};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TickerSnapshot {
@@ -30,37 +32,47 @@ impl Parse for TickerSnapshot {
 }
 
 pub trait TickerSnapshotRequest {
+    fn get_ticker_snapshot_async(
+        client: &PolygonClient,
+        ticker: String,
+        ticker_type: TickerType,
+    ) -> impl Future<Output = Result<TickerSnapshot, ErrorCode>> + Send {
+        async move {
+            let ticker_snapshot_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::set(true, false, false, true, true),
+                PARAMETERS,
+                &ticker_snapshot_parameters,
+            ) {
+                return Err(check);
+            }
+            let locale = match ticker_type {
+                TickerType::Stocks => String::from("us"),
+                TickerType::Forex | TickerType::Crypto => String::from("global"),
+                _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
+            };
+            let url = match url(&client.base_url, &ticker_snapshot_parameters, locale, ticker_type){
+                Ok(url) => url,
+                Err(e) => return Err(e)
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(TickerSnapshot::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_ticker_snapshot(
-        
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         ticker_type: TickerType,
     ) -> Result<TickerSnapshot, ErrorCode> {
-        let ticker_snapshot_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::set(true, false, false, true, true),
-            PARAMETERS,
-            &ticker_snapshot_parameters,
-        ) {
-            return Err(check);
-        }
-        let locale = match ticker_type {
-            TickerType::Stocks => String::from("us"),
-            TickerType::Forex | TickerType::Crypto => String::from("global"),
-            _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
-        };
-        let url = match url(&ticker_snapshot_parameters, locale, ticker_type){
-            Ok(url) => url,
-            Err(e) => return Err(e)
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(TickerSnapshot::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_ticker_snapshot_async(client, ticker, ticker_type))
     }
 }
 
@@ -69,9 +81,15 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[&ParameterRequirme
     parameter: Parameter::Ticker,
 }];
 
-fn url(parameters: &Parameters, locale: String, ticker_type: TickerType) -> Result<String, ErrorCode> {
+fn url(
+    base_url: &str,
+    parameters: &Parameters,
+    locale: String,
+    ticker_type: TickerType,
+) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v2/snapshot/locale/{}/markets/{}/tickers/{}?apiKey={}",
+        "{}/v2/snapshot/locale/{}/markets/{}/tickers/{}?apiKey={}",
+        base_url,
         locale,
         ticker_type.to_string().to_lowercase(),
         match &parameters.ticker{
@@ -184,6 +202,12 @@ fn test_url() {
     let mut parameters = Parameters::default();
     parameters.api_key = String::from("apiKey");
     parameters.ticker = Some(String::from("AAPL"));
-    let url = url(&parameters, String::from("us"), TickerType::Stocks).unwrap();
+    let url = url(
+        PolygonClient::DEFAULT_BASE_URL,
+        &parameters,
+        String::from("us"),
+        TickerType::Stocks,
+    )
+    .unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/snapshot/locale/us/markets/stocks/tickers/AAPL?apiKey=apiKey");
 }
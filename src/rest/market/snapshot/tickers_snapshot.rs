@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{ticker::Ticker, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,6 +6,7 @@ use crate::rest::{
 };
 use crate::tools::{request::Request, verification::Verification};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TickersSnapshot {
@@ -30,46 +32,68 @@ impl Parse for TickersSnapshot {
 }
 
 pub trait TickersSnapshotRequest {
+    fn get_tickers_snapshot_async(
+        client: &PolygonClient,
+        tickers: Option<Vec<String>>,
+        include_otc: Option<bool>,
+        ticker_type: TickerType,
+    ) -> impl Future<Output = Result<TickersSnapshot, ErrorCode>> + Send {
+        async move {
+            let ticker_types = match ticker_type {
+                TickerType::Stocks => TickerTypes::stocks(),
+                TickerType::Forex => TickerTypes::forex(),
+                TickerType::Crypto => TickerTypes::crypto(),
+                _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
+            };
+            let includeotc = match ticker_type {
+                TickerType::Forex | TickerType::Crypto => None,
+                _ => include_otc,
+            };
+            let tickers_snapshot_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                tickers: tickers,
+                include_otc: includeotc,
+                ..Parameters::default()
+            };
+            if let Err(check) =
+                Verification::check_parameters(&ticker_types, PARAMETERS, &tickers_snapshot_parameters)
+            {
+                return Err(check);
+            }
+            let locale = match ticker_type {
+                TickerType::Stocks => String::from("us"),
+                TickerType::Forex | TickerType::Crypto => String::from("global"),
+                _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
+            };
+            let url = match url(
+                &client.base_url,
+                &tickers_snapshot_parameters,
+                locale,
+                ticker_type,
+            ) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(TickersSnapshot::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
     fn get_tickers_snapshot(
-        api_key: &String,
+        client: &PolygonClient,
         tickers: Option<Vec<String>>,
         include_otc: Option<bool>,
         ticker_type: TickerType,
     ) -> Result<TickersSnapshot, ErrorCode> {
-        let ticker_types = match ticker_type {
-            TickerType::Stocks => TickerTypes::stocks(),
-            TickerType::Forex => TickerTypes::forex(),
-            TickerType::Crypto => TickerTypes::crypto(),
-            _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
-        };
-        let includeotc = match ticker_type {
-            TickerType::Forex | TickerType::Crypto => None,
-            _ => include_otc,
-        };
-        let tickers_snapshot_parameters = Parameters {
-            api_key: api_key.to_string(),
-            tickers: tickers,
-            include_otc: includeotc,
-            ..Parameters::default()
-        };
-        if let Err(check) =
-            Verification::check_parameters(&ticker_types, PARAMETERS, &tickers_snapshot_parameters)
-        {
-            return Err(check);
-        }
-        let locale = match ticker_type {
-            TickerType::Stocks => String::from("us"),
-            TickerType::Forex | TickerType::Crypto => String::from("global"),
-            _ => return Err(ErrorCode::TickerTypeeNotValidForAPICall),
-        };
-        let url = match url(&tickers_snapshot_parameters, locale, ticker_type) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(TickersSnapshot::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_tickers_snapshot_async(
+            client,
+            tickers,
+            include_otc,
+            ticker_type,
+        ))
     }
 }
 
@@ -85,6 +109,7 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
 ];
 
 fn url(
+    base_url: &str,
     parameters: &Parameters,
     locale: String,
     ticker_type: TickerType,
@@ -101,7 +126,8 @@ fn url(
         tickers_flattened
     };
     let url = String::from(format!(
-        "https://api.polygon.io/v2/snapshot/locale/{}/markets/{}/tickers?{}{}apiKey={}",
+        "{}/v2/snapshot/locale/{}/markets/{}/tickers?{}{}apiKey={}",
+        base_url,
         locale,
         ticker_type.to_string().to_lowercase(),
         tickers,
@@ -220,6 +246,12 @@ fn test_url() {
     parameters.api_key = String::from("apiKey");
     parameters.tickers = Some(vec![String::from("AAPL"), String::from("MSFT")]);
     parameters.include_otc = Some(true);
-    let url = url(&parameters, String::from("us"), TickerType::Stocks).unwrap();
+    let url = url(
+        PolygonClient::DEFAULT_BASE_URL,
+        &parameters,
+        String::from("us"),
+        TickerType::Stocks,
+    )
+    .unwrap();
     assert_eq!(url, "https://api.polygon.io/v2/snapshot/locale/us/markets/stocks/tickers?tickers=AAPL,MSFT&include_otc=true&apiKey=apiKey");
 }
@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{universal::Universal, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,8 +6,9 @@ use crate::rest::{
         Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerType, TickerTypes,
     },
 };
-use crate::tools::{request::Request, verification::Verification};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct UniversalSnapshot {
@@ -34,9 +36,78 @@ impl Parse for UniversalSnapshot {
     }
 }
 
+impl Paginate for UniversalSnapshot {
+    type Item = Universal;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Universal> {
+        self.universal.unwrap_or_default()
+    }
+}
+
 pub trait UniversalSnapshotRequest {
+    fn get_universal_snapshot_async(
+        client: &PolygonClient,
+        tickers: Option<Vec<String>>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        ticker_type: Option<TickerType>,
+        sort: Option<Sortv3>,
+        limit: Option<u16>,
+        order: Option<Order>,
+    ) -> impl Future<Output = Result<UniversalSnapshot, ErrorCode>> + Send {
+        async move {
+            let tickers = if ticker_from.is_some() || ticker_to.is_some() {
+                None
+            } else {
+                tickers
+            };
+            let universal_snapshot_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                tickers: tickers,
+                ticker_from: ticker_from,
+                ticker_to: ticker_to,
+                ticker_type: ticker_type,
+                sortv3: sort,
+                limit: limit,
+                order: order,
+                ..Parameters::default()
+            };
+            let ticker_types = match ticker_type {
+                Some(t) => match t {
+                    TickerType::Indicies => TickerTypes::indicies(),
+                    TickerType::Stocks => TickerTypes::stocks(),
+                    TickerType::Crypto => TickerTypes::crypto(),
+                    TickerType::Forex => TickerTypes::forex(),
+                    TickerType::Options => TickerTypes::options(),
+                },
+                None => TickerTypes::all(),
+            };
+            if let Err(check) = Verification::check_parameters(
+                &ticker_types,
+                PARAMETERS,
+                &universal_snapshot_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &universal_snapshot_parameters) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(UniversalSnapshot::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_universal_snapshot(
-        api_key: &String,
+        client: &PolygonClient,
         tickers: Option<Vec<String>>,
         ticker_from: Option<String>,
         ticker_to: Option<String>,
@@ -45,47 +116,16 @@ pub trait UniversalSnapshotRequest {
         limit: Option<u16>,
         order: Option<Order>,
     ) -> Result<UniversalSnapshot, ErrorCode> {
-        let tickers = if ticker_from.is_some() || ticker_to.is_some() {
-            None
-        } else {
-            tickers
-        };
-        let universal_snapshot_parameters = Parameters {
-            api_key: api_key.to_string(),
-            tickers: tickers,
-            ticker_from: ticker_from,
-            ticker_to: ticker_to,
-            ticker_type: ticker_type,
-            sortv3: sort,
-            limit: limit,
-            order: order,
-            ..Parameters::default()
-        };
-        let ticker_types = match ticker_type {
-            Some(t) => match t {
-                TickerType::Indicies => TickerTypes::indicies(),
-                TickerType::Stocks => TickerTypes::stocks(),
-                TickerType::Crypto => TickerTypes::crypto(),
-                TickerType::Forex => TickerTypes::forex(),
-                TickerType::Options => TickerTypes::options(),
-            },
-            None => TickerTypes::all(),
-        };
-        if let Err(check) = Verification::check_parameters(
-            &ticker_types,
-            PARAMETERS,
-            &universal_snapshot_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&universal_snapshot_parameters) {
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(UniversalSnapshot::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_universal_snapshot_async(
+            client,
+            tickers,
+            ticker_from,
+            ticker_to,
+            ticker_type,
+            sort,
+            limit,
+            order,
+        ))
     }
 }
 
@@ -120,7 +160,7 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let tickers = {
         let mut tickers_flattened = String::new();
         if let Some(tickers) = &parameters.tickers {
@@ -133,7 +173,8 @@ fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
         tickers_flattened
     };
     let url = String::from(format!(
-        "https://api.polygon.io/v3/snapshot?{}{}{}{}{}{}{}apiKey={}",
+        "{}/v3/snapshot?{}{}{}{}{}{}{}apiKey={}",
+        base_url,
         tickers,
         if let Some(tf) = &parameters.ticker_from {
             format!("ticker.gte={}&", tf)
@@ -220,6 +261,6 @@ fn test_url() {
     parameters.sortv3 = Some(Sortv3::Ticker);
     parameters.limit = Some(1);
     parameters.order = Some(Order::Asc);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v3/snapshot?tickers.any_of=I:DJI,I:SPX&ticker.gte=A&ticker.lte=B&type=indicies&order=Asc&limit=1&sort=Ticker&apiKey=apiKey");
 }
@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{bar::Bar, macd::MACD, Parse},
     rest::{
         error::ErrorCode,
@@ -9,6 +10,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct MovingAverageConvergenceDivergence {
@@ -53,8 +55,70 @@ impl Parse for MovingAverageConvergenceDivergence {
 }
 
 pub trait MovingAverageConvergenceDivergenceRequest {
+    fn get_relatvie_strength_async(
+        client: &PolygonClient,
+        ticker: String,
+        timestamp: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        timespan: Option<Timespan>,
+        adjusted: Option<bool>,
+        long_window: Option<i64>,
+        short_window: Option<i64>,
+        signal_window: Option<i64>,
+        series_type: Option<SeriesType>,
+        expand_underlying: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+    ) -> impl Future<Output = Result<MovingAverageConvergenceDivergence, ErrorCode>> + Send {
+        async move {
+            let ts = if to.is_some() || from.is_some() {
+                None
+            } else {
+                timestamp
+            };
+            let moving_average_convergence_divergence_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                timestamp: ts,
+                from: from,
+                to: to,
+                timespan: timespan,
+                adjusted: adjusted,
+                long_window: long_window,
+                short_window: short_window,
+                signal_window: signal_window,
+                series_type: series_type,
+                expand_underlying: expand_underlying,
+                order: order,
+                limit: limit,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::all(),
+                PARAMETERS,
+                &moving_average_convergence_divergence_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(
+                &client.base_url,
+                &moving_average_convergence_divergence_parameters,
+            ) {
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(MovingAverageConvergenceDivergence::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_relatvie_strength(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         timestamp: Option<String>,
         from: Option<String>,
@@ -69,43 +133,22 @@ pub trait MovingAverageConvergenceDivergenceRequest {
         order: Option<Order>,
         limit: Option<u16>,
     ) -> Result<MovingAverageConvergenceDivergence, ErrorCode> {
-        let ts = if to.is_some() || from.is_some() {
-            None
-        } else {
-            timestamp
-        };
-        let moving_average_convergence_divergence_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            timestamp: ts,
-            from: from,
-            to: to,
-            timespan: timespan,
-            adjusted: adjusted,
-            long_window: long_window,
-            short_window: short_window,
-            signal_window: signal_window,
-            series_type: series_type,
-            expand_underlying: expand_underlying,
-            order: order,
-            limit: limit,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::all(),
-            PARAMETERS,
-            &moving_average_convergence_divergence_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&moving_average_convergence_divergence_parameters){
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(MovingAverageConvergenceDivergence::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_relatvie_strength_async(
+            client,
+            ticker,
+            timestamp,
+            from,
+            to,
+            timespan,
+            adjusted,
+            long_window,
+            short_window,
+            signal_window,
+            series_type,
+            expand_underlying,
+            order,
+            limit,
+        ))
     }
 }
 
@@ -164,9 +207,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v1/indicators/macd/{}?{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        "{}/v1/indicators/macd/{}?{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
         match &parameters.ticker{
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -265,7 +309,8 @@ fn test_moving_average_converge_divergence_parse() {
             "next_url": "https://api.polygon.io/v1/indicators/macd/AAPL?cursor=YWN0aXZlPXRydWUmZGF0ZT0yMDIzLTA0LTAxJmxpbWl0PTEmb3JkZXI9YXNjJnBhZ2VfbWFya2VyPUElMjBWU1MjQyMCU3QzIwMjMtMDQtMDElN0M5JTNBNDElN0MwMCUzQTAwJnNvcnQ9dGlja2Vy"
         }
     });
-    let moving_average_convergence_divergence = MovingAverageConvergenceDivergence::parse(&data.as_object().unwrap());
+    let moving_average_convergence_divergence =
+        MovingAverageConvergenceDivergence::parse(&data.as_object().unwrap());
     assert_eq!(moving_average_convergence_divergence.next_url.unwrap(), "https://api.polygon.io/v1/indicators/macd/AAPL?cursor=YWN0aXZlPXRydWUmZGF0ZT0yMDIzLTA0LTAxJmxpbWl0PTEmb3JkZXI9YXNjJnBhZ2VfbWFya2VyPUElMjBWU1MjQyMCU3QzIwMjMtMDQtMDElN0M5JTNBNDElN0MwMCUzQTAwJnNvcnQ9dGlja2Vy");
     assert_eq!(moving_average_convergence_divergence.request_id.unwrap(), "req12345");
     assert_eq!(moving_average_convergence_divergence.status.unwrap(), "OK");
@@ -289,6 +334,6 @@ fn test_url() {
     parameters.expand_underlying = Some(true);
     parameters.order = Some(Order::Asc);
     parameters.limit = Some(1000);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v1/indicators/macd/AAPL?timestamp.gte=2023-03-01&timestamp.lte=2023-04-01&timespan=minute&adjusted=true&long_window=26&short_window=12&signal_window=9&series_type=close&expand_underlying=true&order=asc&limit=1000&apiKey=apiKey");
 }
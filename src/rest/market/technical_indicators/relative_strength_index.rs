@@ -1,4 +1,5 @@
 use crate::{
+    client::PolygonClient,
     data_types::{bar::Bar, relative_strength::RelativeStrength, Parse},
     rest::{
         error::ErrorCode,
@@ -9,6 +10,7 @@ use crate::{
     tools::{request::Request, verification::Verification},
 };
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct RelativeStrengthIndex {
@@ -53,8 +55,63 @@ impl Parse for RelativeStrengthIndex {
 }
 
 pub trait RelativeStrengthIndexRequest {
+    fn get_relatvie_strength_async(
+        client: &PolygonClient,
+        ticker: String,
+        timestamp: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        timespan: Option<Timespan>,
+        adjusted: Option<bool>,
+        window: Option<i64>,
+        series_type: Option<SeriesType>,
+        expand_underlying: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+    ) -> impl Future<Output = Result<RelativeStrengthIndex, ErrorCode>> + Send {
+        async move {
+            let ts = if to.is_some() || from.is_some() {
+                None
+            } else {
+                timestamp
+            };
+            let relatvie_strength_index_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                timestamp: ts,
+                from: from,
+                to: to,
+                timespan: timespan,
+                adjusted: adjusted,
+                window: window,
+                series_type: series_type,
+                expand_underlying: expand_underlying,
+                order: order,
+                limit: limit,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::all(),
+                PARAMETERS,
+                &relatvie_strength_index_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &relatvie_strength_index_parameters){
+                Ok(url) => url,
+                Err(e) => return Err(e),
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(RelativeStrengthIndex::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_relatvie_strength(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: String,
         timestamp: Option<String>,
         from: Option<String>,
@@ -67,41 +124,20 @@ pub trait RelativeStrengthIndexRequest {
         order: Option<Order>,
         limit: Option<u16>,
     ) -> Result<RelativeStrengthIndex, ErrorCode> {
-        let ts = if to.is_some() || from.is_some() {
-            None
-        } else {
-            timestamp
-        };
-        let relatvie_strength_index_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: Some(ticker),
-            timestamp: ts,
-            from: from,
-            to: to,
-            timespan: timespan,
-            adjusted: adjusted,
-            window: window,
-            series_type: series_type,
-            expand_underlying: expand_underlying,
-            order: order,
-            limit: limit,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::all(),
-            PARAMETERS,
-            &relatvie_strength_index_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&relatvie_strength_index_parameters){
-            Ok(url) => url,
-            Err(e) => return Err(e),
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(RelativeStrengthIndex::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_relatvie_strength_async(
+            client,
+            ticker,
+            timestamp,
+            from,
+            to,
+            timespan,
+            adjusted,
+            window,
+            series_type,
+            expand_underlying,
+            order,
+            limit,
+        ))
     }
 }
 
@@ -152,9 +188,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v1/indicators/rsi/{}?{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        "{}/v1/indicators/rsi/{}?{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
         match &parameters.ticker{
             Some(ticker) => ticker,
             None => return Err(ErrorCode::TickerNotSet),
@@ -263,6 +300,6 @@ fn test_url() {
     parameters.expand_underlying = Some(true);
     parameters.order = Some(Order::Asc);
     parameters.limit = Some(1000);
-    let url = url(&parameters).unwrap();
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
     assert_eq!(url, "https://api.polygon.io/v1/indicators/rsi/AAPL?timestamp.gte=2023-03-01&timestamp.lte=2023-04-01&timespan=minute&adjusted=true&window=10&series_type=close&expand_underlying=true&order=asc&limit=1000&apiKey=apiKey");
 }
@@ -126,6 +126,45 @@ pub enum Parameter {
     SignalWindow,
     Expired,
     AsOf,
+    Search,
+    Market,
+    Exchange,
+    Type,
+    Cik,
+    Cusip,
+    Active,
+    PayDate,
+    PayDateFrom,
+    PayDateTo,
+    RecordDate,
+    RecordDateFrom,
+    RecordDateTo,
+    DeclarationDate,
+    DeclarationDateFrom,
+    DeclarationDateTo,
+    CashAmount,
+    CashAmountFrom,
+    CashAmountTo,
+    Frequency,
+    DividendType,
+    ReverseSplit,
+    CompanyName,
+    Sic,
+    PeriodOfReportDate,
+    PeriodOfReportDateFrom,
+    PeriodOfReportDateTo,
+    FinancialTimeframe,
+    IncludeSources,
+    AssetClass,
+    Locale,
+    DataType,
+    Id,
+    Sip,
+    Identifier,
+    EventType,
+    PublishedUtc,
+    PublishedUtcFrom,
+    PublishedUtcTo,
 }
 
 #[derive(Clone, Debug)]
@@ -171,6 +210,45 @@ pub struct Parameters {
     pub expand_underlying: Option<bool>,
     pub expired: Option<bool>,
     pub as_of: Option<String>,
+    pub search: Option<String>,
+    pub market: Option<Market>,
+    pub exchange: Option<String>,
+    pub type_code: Option<String>,
+    pub cik: Option<String>,
+    pub cusip: Option<String>,
+    pub active: Option<bool>,
+    pub pay_date: Option<String>,
+    pub pay_date_from: Option<String>,
+    pub pay_date_to: Option<String>,
+    pub record_date: Option<String>,
+    pub record_date_from: Option<String>,
+    pub record_date_to: Option<String>,
+    pub declaration_date: Option<String>,
+    pub declaration_date_from: Option<String>,
+    pub declaration_date_to: Option<String>,
+    pub cash_amount: Option<f64>,
+    pub cash_amount_from: Option<f64>,
+    pub cash_amount_to: Option<f64>,
+    pub frequency: Option<Frequency>,
+    pub dividend_type: Option<DividendType>,
+    pub reverse_split: Option<bool>,
+    pub company_name: Option<String>,
+    pub sic: Option<String>,
+    pub period_of_report_date: Option<String>,
+    pub period_of_report_date_from: Option<String>,
+    pub period_of_report_date_to: Option<String>,
+    pub financial_timeframe: Option<FinancialTimeframe>,
+    pub include_sources: Option<bool>,
+    pub asset_class: Option<AssetClass>,
+    pub locale: Option<Locale>,
+    pub data_type: Option<DataType>,
+    pub id: Option<i64>,
+    pub sip: Option<Sip>,
+    pub identifier: Option<String>,
+    pub event_type: Option<EventType>,
+    pub published_utc: Option<String>,
+    pub published_utc_from: Option<String>,
+    pub published_utc_to: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
@@ -193,6 +271,20 @@ pub enum Timeframe {
     RealTime,
 }
 
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum FinancialTimeframe {
+    Annual,
+    Quarterly,
+    TTM,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum Sentiment {
+    Positive,
+    Neutral,
+    Negative,
+}
+
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Display)]
 pub enum Sort {
     Asc,
@@ -210,6 +302,94 @@ pub enum Sortv3 {
     Ticker,
     ExpirationDate,
     StrikePrice,
+    Name,
+    Market,
+    Locale,
+    PrimaryExchange,
+    Type,
+    CurrencySymbol,
+    CurrencyName,
+    BaseCurrencySymbol,
+    BaseCurrencyName,
+    Cik,
+    CompositeFigi,
+    ShareClassFigi,
+    LastUpdatedUtc,
+    DelistedUtc,
+    ExDividendDate,
+    PayDate,
+    DeclarationDate,
+    RecordDate,
+    CashAmount,
+    ExecutionDate,
+    FilingDate,
+    PeriodOfReportDate,
+    PublishedUtc,
+    AssetClass,
+    Id,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum Market {
+    Stocks,
+    Crypto,
+    Fx,
+    Otc,
+    Indices,
+}
+
+//Number of times per year the dividend is paid out
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum Frequency {
+    OneTime = 0,
+    Annually = 1,
+    BiAnnually = 2,
+    Quarterly = 4,
+    Monthly = 12,
+    BiMonthly = 24,
+    Weekly = 52,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum DividendType {
+    CD, //Consistent schedule
+    SC, //Special cash
+    LT, //Long-term capital gain
+    ST, //Short-term capital gain
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum AssetClass {
+    Stocks,
+    Options,
+    Crypto,
+    Fx,
+    Indices,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum Locale {
+    Us,
+    Global,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum DataType {
+    Trade,
+    BBO,  //Best bid and offer
+    NBBO, //National best bid and offer
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum Sip {
+    CTA,
+    UTP,
+    OPRA,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq)]
+pub enum EventType {
+    TickerChange,
 }
 
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Display)]
@@ -13,3 +13,71 @@ pub mod splits;
 pub mod status;
 pub mod tickers;
 pub mod types;
+
+use conditions::ConditionsRequest;
+use contract::ContractRequest;
+use contracts::OptionContractsRequest;
+use details::DetailsRequest;
+use dividends::DividendsRequest;
+use events::EventsRequest;
+use exchanges::ExchangesRequest;
+use financials::FinancialsRequest;
+use holidays::HolidaysRequest;
+use news::NewsRequest;
+use related::RelatedRequest;
+use serde::{Deserialize, Serialize};
+use splits::SplitsRequest;
+use status::StatusRequest;
+use tickers::TickersRequest;
+use types::TypesRequest;
+
+#[derive(Serialize, Deserialize)]
+pub enum Reference {
+    Conditions(conditions::Conditions),
+    Contract(contract::Contract),
+    Details(Box<details::Details>),
+    Dividends(dividends::Dividends),
+    Events(events::Events),
+    Exchanges(exchanges::Exchanges),
+    Financials(financials::Financials),
+    Holidays(holidays::Holidays),
+    News(news::News),
+    OptionContracts(contracts::OptionContracts),
+    Related(related::Related),
+    Splits(splits::Splits),
+    Status(status::Status),
+    Tickers(tickers::Tickers),
+    Types(types::Types),
+}
+
+pub struct ReferenceRequest {}
+
+impl ConditionsRequest for ReferenceRequest {}
+
+impl ContractRequest for ReferenceRequest {}
+
+impl DetailsRequest for ReferenceRequest {}
+
+impl DividendsRequest for ReferenceRequest {}
+
+impl EventsRequest for ReferenceRequest {}
+
+impl ExchangesRequest for ReferenceRequest {}
+
+impl FinancialsRequest for ReferenceRequest {}
+
+impl HolidaysRequest for ReferenceRequest {}
+
+impl NewsRequest for ReferenceRequest {}
+
+impl OptionContractsRequest for ReferenceRequest {}
+
+impl RelatedRequest for ReferenceRequest {}
+
+impl SplitsRequest for ReferenceRequest {}
+
+impl StatusRequest for ReferenceRequest {}
+
+impl TickersRequest for ReferenceRequest {}
+
+impl TypesRequest for ReferenceRequest {}
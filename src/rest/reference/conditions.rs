@@ -1 +1,274 @@
+use crate::client::PolygonClient;
+use crate::data_types::{condition::Condition, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{
+        AssetClass, DataType, Order, Parameter, ParameterRequirment, Parameters, Sip, Sortv3,
+        TickerTypes,
+    },
+};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Conditions {
+    pub conditions: Option<Vec<Condition>>,
+    pub count: Option<i64>,
+    pub next_url: Option<String>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl ConditionsRequest for Conditions {}
+
+impl Parse for Conditions {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let conditions = Self::array_parse(map, vec!["results"]);
+        let count = Self::i64_parse(map, vec!["count"]);
+        let next_url = Self::string_parse(map, vec!["next_url"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        Conditions {
+            conditions,
+            count,
+            next_url,
+            request_id,
+            status,
+        }
+    }
+}
+
+impl Paginate for Conditions {
+    type Item = Condition;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Condition> {
+        self.conditions.unwrap_or_default()
+    }
+}
+
+pub trait ConditionsRequest {
+    #[allow(clippy::too_many_arguments)]
+    fn get_conditions_async(
+        client: &PolygonClient,
+        asset_class: Option<AssetClass>,
+        data_type: Option<DataType>,
+        id: Option<i64>,
+        sip: Option<Sip>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<Conditions, ErrorCode>> + Send {
+        async move {
+            let conditions_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                asset_class,
+                data_type,
+                id,
+                sip,
+                order,
+                limit,
+                sortv3: sort,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(
+                &TickerTypes::all(),
+                PARAMETERS,
+                &conditions_parameters,
+            )?;
+            let url = url(&client.base_url, &conditions_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Conditions::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
+    fn get_conditions(
+        client: &PolygonClient,
+        asset_class: Option<AssetClass>,
+        data_type: Option<DataType>,
+        id: Option<i64>,
+        sip: Option<Sip>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> Result<Conditions, ErrorCode> {
+        Request::blocking(Self::get_conditions_async(
+            client,
+            asset_class,
+            data_type,
+            id,
+            sip,
+            order,
+            limit,
+            sort,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::AssetClass,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::DataType,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Id,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sip,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Order,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Limit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sortv3,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/conditions?{}{}{}{}{}{}{}apiKey={}",
+        base_url,
+        if let Some(asset_class) = &parameters.asset_class {
+            format!("asset_class={}&", asset_class.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(data_type) = &parameters.data_type {
+            format!("data_type={}&", data_type.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(id) = &parameters.id {
+            format!("id={}&", id)
+        } else {
+            "".to_string()
+        },
+        if let Some(sip) = &parameters.sip {
+            format!("sip={}&", sip)
+        } else {
+            "".to_string()
+        },
+        if let Some(order) = &parameters.order {
+            format!("order={}&", order.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(limit) = &parameters.limit {
+            format!("limit={}&", limit)
+        } else {
+            "".to_string()
+        },
+        if let Some(sort) = &parameters.sortv3 {
+            match sort {
+                Sortv3::AssetClass => "sort=asset_class&".to_string(),
+                _ => format!("sort={}&", sort.to_string().to_lowercase()),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_conditions_parse() {
+    let data = serde_json::json!({
+        "count": 1,
+        "request_id": "31d59dda-80e5-4721-8496-d0d32a654afe",
+        "results": [
+            {
+                "asset_class": "stocks",
+                "data_types": ["trade"],
+                "description": "A trade that is executed and reported out of sequence.",
+                "id": 2,
+                "legacy": false,
+                "name": "Average Price Trade",
+                "sip_mapping": {
+                    "CTA": "B",
+                    "UTP": "W"
+                },
+                "type": "sale_condition",
+                "update_rules": {
+                    "consolidated": {
+                        "updates_high_low": false,
+                        "updates_open_close": false,
+                        "updates_volume": true
+                    },
+                    "market_center": {
+                        "updates_high_low": false,
+                        "updates_open_close": false,
+                        "updates_volume": true
+                    }
+                }
+            }
+        ],
+        "status": "OK"
+    });
+    let conditions = Conditions::parse(&data.as_object().unwrap());
+    assert_eq!(conditions.count.unwrap(), 1);
+    assert_eq!(conditions.status.unwrap(), "OK");
+    assert_eq!(
+        conditions.request_id.unwrap(),
+        "31d59dda-80e5-4721-8496-d0d32a654afe"
+    );
+    let conditions = conditions.conditions.unwrap();
+    assert_eq!(conditions[0].asset_class.clone().unwrap(), "stocks");
+    assert_eq!(
+        conditions[0].data_types.clone().unwrap(),
+        vec![String::from("trade")]
+    );
+    assert_eq!(conditions[0].id.unwrap(), 2);
+    assert_eq!(conditions[0].legacy.unwrap(), false);
+    assert_eq!(conditions[0].name.clone().unwrap(), "Average Price Trade");
+    assert_eq!(
+        conditions[0].condition_type.clone().unwrap(),
+        "sale_condition"
+    );
+    let sip_mapping = conditions[0].sip_mapping.clone().unwrap();
+    assert_eq!(sip_mapping.cta.unwrap(), "B");
+    assert_eq!(sip_mapping.utp.unwrap(), "W");
+    assert_eq!(sip_mapping.opra, None);
+    let update_rules = conditions[0].update_rules.clone().unwrap();
+    let consolidated = update_rules.consolidated.unwrap();
+    assert_eq!(consolidated.updates_high_low.unwrap(), false);
+    assert_eq!(consolidated.updates_open_close.unwrap(), false);
+    assert_eq!(consolidated.updates_volume.unwrap(), true);
+    assert_eq!(
+        update_rules.market_center.unwrap().updates_volume.unwrap(),
+        true
+    );
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.asset_class = Some(AssetClass::Stocks);
+    parameters.data_type = Some(DataType::Trade);
+    parameters.id = Some(2);
+    parameters.sip = Some(Sip::CTA);
+    parameters.order = Some(Order::Asc);
+    parameters.limit = Some(10);
+    parameters.sortv3 = Some(Sortv3::AssetClass);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/v3/reference/conditions?asset_class=stocks&data_type=trade&id=2&sip=CTA&order=asc&limit=10&sort=asset_class&apiKey=apiKey");
+}
@@ -1 +1,163 @@
+use crate::client::PolygonClient;
+use crate::data_types::{option_contract::OptionContract, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{Parameter, ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Contract {
+    pub contract: Option<OptionContract>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl ContractRequest for Contract {}
+
+impl Parse for Contract {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let contract = Self::object_parse(map, vec!["results"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        Contract {
+            contract,
+            request_id,
+            status,
+        }
+    }
+}
+
+pub trait ContractRequest {
+    fn get_options_contract_async(
+        client: &PolygonClient,
+        options_ticker: String,
+        as_of: Option<String>,
+    ) -> impl Future<Output = Result<Contract, ErrorCode>> + Send {
+        async move {
+            let contract_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(options_ticker),
+                as_of,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(
+                &TickerTypes::options(),
+                PARAMETERS,
+                &contract_parameters,
+            )?;
+            let url = url(&client.base_url, &contract_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Contract::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_options_contract(
+        client: &PolygonClient,
+        options_ticker: String,
+        as_of: Option<String>,
+    ) -> Result<Contract, ErrorCode> {
+        Request::blocking(Self::get_options_contract_async(
+            client,
+            options_ticker,
+            as_of,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: true,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::AsOf,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/options/contracts/{}?{}apiKey={}",
+        base_url,
+        match &parameters.ticker {
+            Some(ticker) => ticker,
+            None => return Err(ErrorCode::TickerNotSet),
+        },
+        if let Some(as_of) = &parameters.as_of {
+            format!("as_of={}&", as_of)
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_contract_parse() {
+    use crate::rest::parameters::{ContractStyle, ContractType};
+
+    let data = serde_json::json!({
+        "request_id": "603902c0-a5a5-406f-bd08-f030f92418fa",
+        "results": {
+            "additional_underlyings": [
+                {
+                    "amount": 44,
+                    "type": "equity",
+                    "underlying": "VMW"
+                },
+                {
+                    "amount": 6.53,
+                    "type": "currency",
+                    "underlying": "USD"
+                }
+            ],
+            "cfi": "OCASPS",
+            "contract_type": "call",
+            "exercise_style": "american",
+            "expiration_date": "2021-11-19",
+            "primary_exchange": "BATO",
+            "shares_per_contract": 100,
+            "strike_price": 85,
+            "ticker": "O:AAPL211119C00085000",
+            "underlying_ticker": "AAPL"
+        },
+        "status": "OK"
+    });
+    let contract = Contract::parse(&data.as_object().unwrap());
+    assert_eq!(contract.status.unwrap(), "OK");
+    assert_eq!(
+        contract.request_id.unwrap(),
+        "603902c0-a5a5-406f-bd08-f030f92418fa"
+    );
+    let contract = contract.contract.unwrap();
+    let additional_underlyings = contract.additional_underlyings.unwrap();
+    assert_eq!(additional_underlyings.len(), 2);
+    assert_eq!(additional_underlyings[0].amount.unwrap(), 44.0);
+    assert_eq!(additional_underlyings[0].assest.clone().unwrap(), "equity");
+    assert_eq!(additional_underlyings[0].underlying.clone().unwrap(), "VMW");
+    assert_eq!(additional_underlyings[1].amount.unwrap(), 6.53);
+    assert_eq!(contract.cfi.unwrap(), "OCASPS");
+    assert_eq!(contract.contract_type.unwrap(), ContractType::Call);
+    assert_eq!(contract.exercise_style.unwrap(), ContractStyle::American);
+    assert_eq!(contract.expiration_date.unwrap(), "2021-11-19");
+    assert_eq!(contract.primary_exchange.unwrap(), "BATO");
+    assert_eq!(contract.shares_per_contract.unwrap(), 100.0);
+    assert_eq!(contract.strike_price.unwrap(), 85.0);
+    assert_eq!(contract.ticker.unwrap(), "O:AAPL211119C00085000");
+    assert_eq!(contract.underlying_ticker.unwrap(), "AAPL");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("O:AAPL211119C00085000"));
+    parameters.as_of = Some(String::from("2021-11-01"));
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/v3/reference/options/contracts/O:AAPL211119C00085000?as_of=2021-11-01&apiKey=apiKey");
+}
@@ -1,3 +1,4 @@
+use crate::client::PolygonClient;
 use crate::data_types::{option_contract::OptionContract, Parse};
 use crate::rest::{
     error::ErrorCode,
@@ -5,7 +6,8 @@ use crate::rest::{
         ContractType, Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes,
     },
 };
-use crate::tools::{request::Request, verification::Verification};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use std::future::Future;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct OptionContracts {
@@ -32,9 +34,94 @@ impl Parse for OptionContracts {
     }
 }
 
+impl Paginate for OptionContracts {
+    type Item = OptionContract;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<OptionContract> {
+        self.contracts.unwrap_or_default()
+    }
+}
+
 pub trait OptionContractsRequest {
+    fn get_options_contracts_async(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        date: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        as_of: Option<String>,
+        expired: Option<bool>,
+        strike_price: Option<f64>,
+        strike_price_from: Option<f64>,
+        strike_price_to: Option<f64>,
+        contract_type: Option<ContractType>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<OptionContracts, ErrorCode>> + Send {
+        async move {
+            let ts = if from.is_some() || from.is_some() {
+                None
+            } else {
+                date
+            };
+            let sp = if strike_price_from.is_some() || strike_price_to.is_some() {
+                None
+            } else {
+                strike_price
+            };
+            let ticker = if ticker_from.is_some() || ticker_to.is_some() {
+                None
+            } else {
+                ticker
+            };
+            let options_chain_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: ticker,
+                ticker_from: ticker_from,
+                ticker_to: ticker_to,
+                date: ts,
+                from: from,
+                to: to,
+                contract_type: contract_type,
+                as_of: as_of,
+                expired: expired,
+                order: order,
+                limit: limit,
+                sortv3: sort,
+                strike_price: sp,
+                strike_price_from: strike_price_from,
+                strike_price_to: strike_price_to,
+                ..Parameters::default()
+            };
+            if let Err(check) = Verification::check_parameters(
+                &TickerTypes::set(true,true,true,false,false),
+                PARAMETERS,
+                &options_chain_parameters,
+            ) {
+                return Err(check);
+            }
+            let url = match url(&client.base_url, &options_chain_parameters){
+                Ok(url) => url,
+                Err(e) => return Err(e)
+            };
+            match Request::request(client, url).await {
+                Ok(mut map) => Ok(OptionContracts::parse(&mut map)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
     fn get_options_contracts(
-        api_key: &String,
+        client: &PolygonClient,
         ticker: Option<String>,
         ticker_from: Option<String>,
         ticker_to: Option<String>,
@@ -51,55 +138,24 @@ pub trait OptionContractsRequest {
         limit: Option<u16>,
         sort: Option<Sortv3>,
     ) -> Result<OptionContracts, ErrorCode> {
-        let ts = if from.is_some() || from.is_some() {
-            None
-        } else {
-            date
-        };
-        let sp = if strike_price_from.is_some() || strike_price_to.is_some() {
-            None
-        } else {
-            strike_price
-        };
-        let ticker = if ticker_from.is_some() || ticker_to.is_some() {
-            None
-        } else {
-            ticker
-        };
-        let options_chain_parameters = Parameters {
-            api_key: api_key.to_string(),
-            ticker: ticker,
-            ticker_from: ticker_from,
-            ticker_to: ticker_to,
-            date: ts,
-            from: from,
-            to: to,
-            contract_type: contract_type,
-            as_of: as_of,
-            expired: expired,
-            order: order,
-            limit: limit,
-            sortv3: sort,
-            strike_price: sp,
-            strike_price_from: strike_price_from,
-            strike_price_to: strike_price_to,
-            ..Parameters::default()
-        };
-        if let Err(check) = Verification::check_parameters(
-            &TickerTypes::set(true,true,true,false,false),
-            PARAMETERS,
-            &options_chain_parameters,
-        ) {
-            return Err(check);
-        }
-        let url = match url(&options_chain_parameters){
-            Ok(url) => url,
-            Err(e) => return Err(e)
-        };
-        match Request::request(url) {
-            Ok(mut map) => Ok(OptionContracts::parse(&mut map)),
-            Err(e) => return Err(e),
-        }
+        Request::blocking(Self::get_options_contracts_async(
+            client,
+            ticker,
+            ticker_from,
+            ticker_to,
+            date,
+            from,
+            to,
+            as_of,
+            expired,
+            strike_price,
+            strike_price_from,
+            strike_price_to,
+            contract_type,
+            order,
+            limit,
+            sort,
+        ))
     }
 }
 
@@ -166,9 +222,10 @@ const PARAMETERS: &'static [&'static ParameterRequirment] = &[
     },
 ];
 
-fn url(parameters: &Parameters) -> Result<String, ErrorCode> {
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
     let url = String::from(format!(
-        "https://api.polygon.io/v3/reference/options/contracts?{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        "{}/v3/reference/options/contracts?{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
         if let Some(ticker) = &parameters.ticker {
             format!("underlying_ticker={}&", ticker)
         } else {
@@ -1 +1,195 @@
+use crate::client::PolygonClient;
+use crate::data_types::{ticker_details::TickerDetails, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{Parameter, ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Details {
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+    pub ticker_details: Option<TickerDetails>,
+}
+
+impl DetailsRequest for Details {}
+
+impl Parse for Details {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        let ticker_details = Self::object_parse(map, vec!["results"]);
+        Details {
+            request_id,
+            status,
+            ticker_details,
+        }
+    }
+}
+
+pub trait DetailsRequest {
+    fn get_ticker_details_async(
+        client: &PolygonClient,
+        ticker: String,
+        date: Option<String>,
+    ) -> impl Future<Output = Result<Details, ErrorCode>> + Send {
+        async move {
+            let details_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                date,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(
+                &TickerTypes::set(true, false, true, true, true),
+                PARAMETERS,
+                &details_parameters,
+            )?;
+            let url = url(&client.base_url, &details_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Details::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_ticker_details(
+        client: &PolygonClient,
+        ticker: String,
+        date: Option<String>,
+    ) -> Result<Details, ErrorCode> {
+        Request::blocking(Self::get_ticker_details_async(client, ticker, date))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: true,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Date,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/tickers/{}?{}apiKey={}",
+        base_url,
+        match &parameters.ticker {
+            Some(ticker) => ticker,
+            None => return Err(ErrorCode::TickerNotSet),
+        },
+        if let Some(date) = &parameters.date {
+            format!("date={}&", date)
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_details_parse() {
+    let data = serde_json::json!({
+        "request_id": "31d59dda-80e5-4721-8496-d0d32a654afe",
+        "results": {
+            "active": true,
+            "address": {
+                "address1": "One Apple Park Way",
+                "city": "Cupertino",
+                "postal_code": "95014",
+                "state": "CA"
+            },
+            "branding": {
+                "icon_url": "https://api.polygon.io/v1/reference/company-branding/d3d3LmFwcGxlLmNvbQ/images/2022-01-10_icon.png",
+                "logo_url": "https://api.polygon.io/v1/reference/company-branding/d3d3LmFwcGxlLmNvbQ/images/2022-01-10_logo.svg"
+            },
+            "cik": "0000320193",
+            "composite_figi": "BBG000B9XRY4",
+            "currency_name": "usd",
+            "description": "Apple designs a wide variety of consumer electronic devices.",
+            "homepage_url": "https://www.apple.com",
+            "list_date": "1980-12-12",
+            "locale": "us",
+            "market": "stocks",
+            "market_cap": 2771126040150.0,
+            "name": "Apple Inc.",
+            "phone_number": "(408) 996-1010",
+            "primary_exchange": "XNAS",
+            "round_lot": 100,
+            "share_class_figi": "BBG001S5N8V8",
+            "share_class_shares_outstanding": 16406400000i64,
+            "sic_code": "3571",
+            "sic_description": "ELECTRONIC COMPUTERS",
+            "ticker": "AAPL",
+            "ticker_root": "AAPL",
+            "total_employees": 154000,
+            "type": "CS",
+            "weighted_shares_outstanding": 16334371000i64
+        },
+        "status": "OK"
+    });
+    let details = Details::parse(&data.as_object().unwrap());
+    assert_eq!(details.status.unwrap(), "OK");
+    assert_eq!(
+        details.request_id.unwrap(),
+        "31d59dda-80e5-4721-8496-d0d32a654afe"
+    );
+    let ticker_details = details.ticker_details.unwrap();
+    assert_eq!(ticker_details.active.unwrap(), true);
+    let address = ticker_details.address.unwrap();
+    assert_eq!(address.address1.unwrap(), "One Apple Park Way");
+    assert_eq!(address.address2, None);
+    assert_eq!(address.city.unwrap(), "Cupertino");
+    assert_eq!(address.postal_code.unwrap(), "95014");
+    assert_eq!(address.state.unwrap(), "CA");
+    let branding = ticker_details.branding.unwrap();
+    assert_eq!(branding.icon_url.unwrap(), "https://api.polygon.io/v1/reference/company-branding/d3d3LmFwcGxlLmNvbQ/images/2022-01-10_icon.png");
+    assert_eq!(branding.logo_url.unwrap(), "https://api.polygon.io/v1/reference/company-branding/d3d3LmFwcGxlLmNvbQ/images/2022-01-10_logo.svg");
+    assert_eq!(ticker_details.cik.unwrap(), "0000320193");
+    assert_eq!(ticker_details.delisted_utc, None);
+    assert_eq!(ticker_details.list_date.unwrap(), "1980-12-12");
+    assert_eq!(ticker_details.market_cap.unwrap(), 2771126040150.0);
+    assert_eq!(ticker_details.name.unwrap(), "Apple Inc.");
+    assert_eq!(ticker_details.primary_exchange.unwrap(), "XNAS");
+    assert_eq!(ticker_details.round_lot.unwrap(), 100);
+    assert_eq!(
+        ticker_details.share_class_shares_outstanding.unwrap(),
+        16406400000
+    );
+    assert_eq!(ticker_details.sic_code.unwrap(), "3571");
+    assert_eq!(
+        ticker_details.sic_description.unwrap(),
+        "ELECTRONIC COMPUTERS"
+    );
+    assert_eq!(ticker_details.ticker.unwrap(), "AAPL");
+    assert_eq!(ticker_details.ticker_type.unwrap(), "CS");
+    assert_eq!(ticker_details.total_employees.unwrap(), 154000);
+    assert_eq!(
+        ticker_details.weighted_shares_outstanding.unwrap(),
+        16334371000
+    );
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("AAPL"));
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/v3/reference/tickers/AAPL?apiKey=apiKey"
+    );
+    parameters.date = Some(String::from("2023-01-09"));
+    let date_url = self::url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        date_url,
+        "https://api.polygon.io/v3/reference/tickers/AAPL?date=2023-01-09&apiKey=apiKey"
+    );
+}
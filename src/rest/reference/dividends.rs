@@ -1 +1,489 @@
+use crate::client::PolygonClient;
+use crate::data_types::{dividend::Dividend, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{
+        DividendType, Frequency, Order, Parameter, ParameterRequirment, Parameters, Sortv3,
+        TickerTypes,
+    },
+};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Dividends {
+    pub dividends: Option<Vec<Dividend>>,
+    pub next_url: Option<String>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl DividendsRequest for Dividends {}
+
+impl Parse for Dividends {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let dividends = Self::array_parse(map, vec!["results"]);
+        let next_url = Self::string_parse(map, vec!["next_url"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        Dividends {
+            dividends,
+            next_url,
+            request_id,
+            status,
+        }
+    }
+}
+
+impl Paginate for Dividends {
+    type Item = Dividend;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Dividend> {
+        self.dividends.unwrap_or_default()
+    }
+}
+
+pub trait DividendsRequest {
+    #[allow(clippy::too_many_arguments)]
+    fn get_dividends_async(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        ex_dividend_date: Option<String>,
+        ex_dividend_date_from: Option<String>,
+        ex_dividend_date_to: Option<String>,
+        record_date: Option<String>,
+        record_date_from: Option<String>,
+        record_date_to: Option<String>,
+        declaration_date: Option<String>,
+        declaration_date_from: Option<String>,
+        declaration_date_to: Option<String>,
+        pay_date: Option<String>,
+        pay_date_from: Option<String>,
+        pay_date_to: Option<String>,
+        frequency: Option<Frequency>,
+        cash_amount: Option<f64>,
+        cash_amount_from: Option<f64>,
+        cash_amount_to: Option<f64>,
+        dividend_type: Option<DividendType>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<Dividends, ErrorCode>> + Send {
+        async move {
+            let ticker = if ticker_from.is_some() || ticker_to.is_some() {
+                None
+            } else {
+                ticker
+            };
+            let ex_dividend_date =
+                if ex_dividend_date_from.is_some() || ex_dividend_date_to.is_some() {
+                    None
+                } else {
+                    ex_dividend_date
+                };
+            let record_date = if record_date_from.is_some() || record_date_to.is_some() {
+                None
+            } else {
+                record_date
+            };
+            let declaration_date =
+                if declaration_date_from.is_some() || declaration_date_to.is_some() {
+                    None
+                } else {
+                    declaration_date
+                };
+            let pay_date = if pay_date_from.is_some() || pay_date_to.is_some() {
+                None
+            } else {
+                pay_date
+            };
+            let cash_amount = if cash_amount_from.is_some() || cash_amount_to.is_some() {
+                None
+            } else {
+                cash_amount
+            };
+            let dividends_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker,
+                ticker_from,
+                ticker_to,
+                date: ex_dividend_date,
+                from: ex_dividend_date_from,
+                to: ex_dividend_date_to,
+                record_date,
+                record_date_from,
+                record_date_to,
+                declaration_date,
+                declaration_date_from,
+                declaration_date_to,
+                pay_date,
+                pay_date_from,
+                pay_date_to,
+                frequency,
+                cash_amount,
+                cash_amount_from,
+                cash_amount_to,
+                dividend_type,
+                order,
+                limit,
+                sortv3: sort,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(
+                &TickerTypes::stocks(),
+                PARAMETERS,
+                &dividends_parameters,
+            )?;
+            let url = url(&client.base_url, &dividends_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Dividends::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
+    fn get_dividends(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        ex_dividend_date: Option<String>,
+        ex_dividend_date_from: Option<String>,
+        ex_dividend_date_to: Option<String>,
+        record_date: Option<String>,
+        record_date_from: Option<String>,
+        record_date_to: Option<String>,
+        declaration_date: Option<String>,
+        declaration_date_from: Option<String>,
+        declaration_date_to: Option<String>,
+        pay_date: Option<String>,
+        pay_date_from: Option<String>,
+        pay_date_to: Option<String>,
+        frequency: Option<Frequency>,
+        cash_amount: Option<f64>,
+        cash_amount_from: Option<f64>,
+        cash_amount_to: Option<f64>,
+        dividend_type: Option<DividendType>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> Result<Dividends, ErrorCode> {
+        Request::blocking(Self::get_dividends_async(
+            client,
+            ticker,
+            ticker_from,
+            ticker_to,
+            ex_dividend_date,
+            ex_dividend_date_from,
+            ex_dividend_date_to,
+            record_date,
+            record_date_from,
+            record_date_to,
+            declaration_date,
+            declaration_date_from,
+            declaration_date_to,
+            pay_date,
+            pay_date_from,
+            pay_date_to,
+            frequency,
+            cash_amount,
+            cash_amount_from,
+            cash_amount_to,
+            dividend_type,
+            order,
+            limit,
+            sort,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Date,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::From,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::To,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::RecordDate,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::RecordDateFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::RecordDateTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::DeclarationDate,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::DeclarationDateFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::DeclarationDateTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PayDate,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PayDateFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PayDateTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Frequency,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::CashAmount,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::CashAmountFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::CashAmountTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::DividendType,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Order,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Limit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sortv3,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/dividends?{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
+        if let Some(ticker) = &parameters.ticker {
+            format!("ticker={}&", ticker)
+        } else {
+            "".to_string()
+        },
+        if let Some(ticker_from) = &parameters.ticker_from {
+            format!("ticker.gte={}&", ticker_from)
+        } else {
+            "".to_string()
+        },
+        if let Some(ticker_to) = &parameters.ticker_to {
+            format!("ticker.lte={}&", ticker_to)
+        } else {
+            "".to_string()
+        },
+        if let Some(date) = &parameters.date {
+            format!("ex_dividend_date={}&", date)
+        } else {
+            "".to_string()
+        },
+        if let Some(from) = &parameters.from {
+            format!("ex_dividend_date.gte={}&", from)
+        } else {
+            "".to_string()
+        },
+        if let Some(to) = &parameters.to {
+            format!("ex_dividend_date.lte={}&", to)
+        } else {
+            "".to_string()
+        },
+        if let Some(record_date) = &parameters.record_date {
+            format!("record_date={}&", record_date)
+        } else {
+            "".to_string()
+        },
+        if let Some(record_date_from) = &parameters.record_date_from {
+            format!("record_date.gte={}&", record_date_from)
+        } else {
+            "".to_string()
+        },
+        if let Some(record_date_to) = &parameters.record_date_to {
+            format!("record_date.lte={}&", record_date_to)
+        } else {
+            "".to_string()
+        },
+        if let Some(declaration_date) = &parameters.declaration_date {
+            format!("declaration_date={}&", declaration_date)
+        } else {
+            "".to_string()
+        },
+        if let Some(declaration_date_from) = &parameters.declaration_date_from {
+            format!("declaration_date.gte={}&", declaration_date_from)
+        } else {
+            "".to_string()
+        },
+        if let Some(declaration_date_to) = &parameters.declaration_date_to {
+            format!("declaration_date.lte={}&", declaration_date_to)
+        } else {
+            "".to_string()
+        },
+        if let Some(pay_date) = &parameters.pay_date {
+            format!("pay_date={}&", pay_date)
+        } else {
+            "".to_string()
+        },
+        if let Some(pay_date_from) = &parameters.pay_date_from {
+            format!("pay_date.gte={}&", pay_date_from)
+        } else {
+            "".to_string()
+        },
+        if let Some(pay_date_to) = &parameters.pay_date_to {
+            format!("pay_date.lte={}&", pay_date_to)
+        } else {
+            "".to_string()
+        },
+        if let Some(frequency) = &parameters.frequency {
+            format!("frequency={}&", *frequency as u8)
+        } else {
+            "".to_string()
+        },
+        if let Some(cash_amount) = &parameters.cash_amount {
+            format!("cash_amount={}&", cash_amount)
+        } else {
+            "".to_string()
+        },
+        if let Some(cash_amount_from) = &parameters.cash_amount_from {
+            format!("cash_amount.gte={}&", cash_amount_from)
+        } else {
+            "".to_string()
+        },
+        if let Some(cash_amount_to) = &parameters.cash_amount_to {
+            format!("cash_amount.lte={}&", cash_amount_to)
+        } else {
+            "".to_string()
+        },
+        if let Some(dividend_type) = &parameters.dividend_type {
+            format!("dividend_type={}&", dividend_type)
+        } else {
+            "".to_string()
+        },
+        if let Some(order) = &parameters.order {
+            format!("order={}&", order.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(limit) = &parameters.limit {
+            format!("limit={}&", limit)
+        } else {
+            "".to_string()
+        },
+        if let Some(sort) = &parameters.sortv3 {
+            match sort {
+                Sortv3::ExDividendDate => "sort=ex_dividend_date&".to_string(),
+                Sortv3::PayDate => "sort=pay_date&".to_string(),
+                Sortv3::DeclarationDate => "sort=declaration_date&".to_string(),
+                Sortv3::RecordDate => "sort=record_date&".to_string(),
+                Sortv3::CashAmount => "sort=cash_amount&".to_string(),
+                _ => format!("sort={}&", sort.to_string().to_lowercase()),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_dividends_parse() {
+    let data = serde_json::json!({
+        "next_url": "https://api.polygon.io/v3/reference/dividends/AAPL?cursor=YXA9MjUmYXM9JmxpbWl0PTEmc29ydD1leF9kaXZpZGVuZF9kYXRlJnNvcnRfZGlyPWRlc2M",
+        "request_id": "2e5f2fec6e3ebbd1ad1a24caa9f83db9",
+        "results": [
+            {
+                "cash_amount": 0.24,
+                "currency": "USD",
+                "declaration_date": "2023-05-04",
+                "dividend_type": "CD",
+                "ex_dividend_date": "2023-05-12",
+                "frequency": 4,
+                "id": "E8e3c4f794613e9205e2f178a36c53fcc57cdabb55e1988c87b33f9e52e221444",
+                "pay_date": "2023-05-18",
+                "record_date": "2023-05-15",
+                "ticker": "AAPL"
+            }
+        ],
+        "status": "OK"
+    });
+    let dividends = Dividends::parse(&data.as_object().unwrap());
+    assert_eq!(dividends.status.unwrap(), "OK");
+    assert_eq!(
+        dividends.request_id.unwrap(),
+        "2e5f2fec6e3ebbd1ad1a24caa9f83db9"
+    );
+    let dividends = dividends.dividends.unwrap();
+    assert_eq!(dividends[0].cash_amount.unwrap(), 0.24);
+    assert_eq!(dividends[0].currency.clone().unwrap(), "USD");
+    assert_eq!(dividends[0].declaration_date.clone().unwrap(), "2023-05-04");
+    assert_eq!(dividends[0].dividend_type.unwrap(), DividendType::CD);
+    assert_eq!(dividends[0].ex_dividend_date.clone().unwrap(), "2023-05-12");
+    assert_eq!(dividends[0].frequency.unwrap(), Frequency::Quarterly);
+    assert_eq!(dividends[0].pay_date.clone().unwrap(), "2023-05-18");
+    assert_eq!(dividends[0].record_date.clone().unwrap(), "2023-05-15");
+    assert_eq!(dividends[0].ticker.clone().unwrap(), "AAPL");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("AAPL"));
+    parameters.from = Some(String::from("2023-01-01"));
+    parameters.to = Some(String::from("2023-12-31"));
+    parameters.pay_date_from = Some(String::from("2023-02-01"));
+    parameters.frequency = Some(Frequency::Quarterly);
+    parameters.cash_amount_from = Some(0.1);
+    parameters.cash_amount_to = Some(0.5);
+    parameters.dividend_type = Some(DividendType::CD);
+    parameters.order = Some(Order::Desc);
+    parameters.limit = Some(10);
+    parameters.sortv3 = Some(Sortv3::ExDividendDate);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/v3/reference/dividends?ticker=AAPL&ex_dividend_date.gte=2023-01-01&ex_dividend_date.lte=2023-12-31&pay_date.gte=2023-02-01&frequency=4&cash_amount.gte=0.1&cash_amount.lte=0.5&dividend_type=CD&order=desc&limit=10&sort=ex_dividend_date&apiKey=apiKey");
+}
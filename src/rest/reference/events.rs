@@ -1 +1,163 @@
+use crate::client::PolygonClient;
+use crate::data_types::{ticker_events::TickerEvents, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{EventType, Parameter, ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Events {
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+    pub ticker_events: Option<TickerEvents>,
+}
+
+impl EventsRequest for Events {}
+
+impl Parse for Events {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        let ticker_events = Self::object_parse(map, vec!["results"]);
+        Events {
+            request_id,
+            status,
+            ticker_events,
+        }
+    }
+}
+
+pub trait EventsRequest {
+    //The identifier can be a ticker, a CUSIP or a composite FIGI
+    fn get_ticker_events_async(
+        client: &PolygonClient,
+        identifier: String,
+        event_type: Option<EventType>,
+    ) -> impl Future<Output = Result<Events, ErrorCode>> + Send {
+        async move {
+            let events_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                identifier: Some(identifier),
+                event_type,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::stocks(), PARAMETERS, &events_parameters)?;
+            let url = url(&client.base_url, &events_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Events::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_ticker_events(
+        client: &PolygonClient,
+        identifier: String,
+        event_type: Option<EventType>,
+    ) -> Result<Events, ErrorCode> {
+        Request::blocking(Self::get_ticker_events_async(
+            client, identifier, event_type,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: true,
+        parameter: Parameter::Identifier,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::EventType,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/vX/reference/tickers/{}/events?{}apiKey={}",
+        base_url,
+        match &parameters.identifier {
+            Some(identifier) => identifier,
+            None => return Err(ErrorCode::IdentifierNotSet),
+        },
+        if let Some(event_type) = &parameters.event_type {
+            match event_type {
+                EventType::TickerChange => "types=ticker_change&".to_string(),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_events_parse() {
+    let data = serde_json::json!({
+        "request_id": "31d59dda-80e5-4721-8496-d0d32a654afe",
+        "results": {
+            "cik": "0001326801",
+            "composite_figi": "BBG000MM2P62",
+            "events": [
+                {
+                    "date": "2022-06-09",
+                    "ticker_change": {
+                        "ticker": "META"
+                    },
+                    "type": "ticker_change"
+                },
+                {
+                    "date": "2012-05-18",
+                    "ticker_change": {
+                        "ticker": "FB"
+                    },
+                    "type": "ticker_change"
+                }
+            ],
+            "name": "Meta Platforms, Inc. Class A Common Stock"
+        },
+        "status": "OK"
+    });
+    let events = Events::parse(&data.as_object().unwrap());
+    assert_eq!(events.status.unwrap(), "OK");
+    assert_eq!(
+        events.request_id.unwrap(),
+        "31d59dda-80e5-4721-8496-d0d32a654afe"
+    );
+    let ticker_events = events.ticker_events.unwrap();
+    assert_eq!(ticker_events.cik.clone().unwrap(), "0001326801");
+    assert_eq!(
+        ticker_events.composite_figi.clone().unwrap(),
+        "BBG000MM2P62"
+    );
+    assert_eq!(
+        ticker_events.name.clone().unwrap(),
+        "Meta Platforms, Inc. Class A Common Stock"
+    );
+    let history = ticker_events.events.clone().unwrap();
+    assert_eq!(history[0].date.clone().unwrap(), "2022-06-09");
+    assert_eq!(history[0].event_type.unwrap(), EventType::TickerChange);
+    assert_eq!(
+        history[0].ticker_change.clone().unwrap().ticker.unwrap(),
+        "META"
+    );
+    assert_eq!(ticker_events.ticker_on("2020-01-02").unwrap(), "FB");
+    assert_eq!(ticker_events.ticker_on("2022-06-09").unwrap(), "META");
+    assert_eq!(ticker_events.ticker_on("2010-01-04"), None);
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.identifier = Some(String::from("META"));
+    parameters.event_type = Some(EventType::TickerChange);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/vX/reference/tickers/META/events?types=ticker_change&apiKey=apiKey"
+    );
+}
@@ -1 +1,151 @@
+use crate::client::PolygonClient;
+use crate::data_types::{exchange::Exchange, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{AssetClass, Locale, Parameter, ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Exchanges {
+    pub count: Option<i64>,
+    pub exchanges: Option<Vec<Exchange>>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl ExchangesRequest for Exchanges {}
+
+impl Parse for Exchanges {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let count = Self::i64_parse(map, vec!["count"]);
+        let exchanges = Self::array_parse(map, vec!["results"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        Exchanges {
+            count,
+            exchanges,
+            request_id,
+            status,
+        }
+    }
+}
+
+pub trait ExchangesRequest {
+    fn get_exchanges_async(
+        client: &PolygonClient,
+        asset_class: Option<AssetClass>,
+        locale: Option<Locale>,
+    ) -> impl Future<Output = Result<Exchanges, ErrorCode>> + Send {
+        async move {
+            let exchanges_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                asset_class,
+                locale,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &exchanges_parameters)?;
+            let url = url(&client.base_url, &exchanges_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Exchanges::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_exchanges(
+        client: &PolygonClient,
+        asset_class: Option<AssetClass>,
+        locale: Option<Locale>,
+    ) -> Result<Exchanges, ErrorCode> {
+        Request::blocking(Self::get_exchanges_async(client, asset_class, locale))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::AssetClass,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Locale,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/exchanges?{}{}apiKey={}",
+        base_url,
+        if let Some(asset_class) = &parameters.asset_class {
+            format!("asset_class={}&", asset_class.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(locale) = &parameters.locale {
+            format!("locale={}&", locale.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_exchanges_parse() {
+    let data = serde_json::json!({
+        "count": 1,
+        "request_id": "31d59dda-80e5-4721-8496-d0d32a654afe",
+        "results": [
+            {
+                "acronym": "AMEX",
+                "asset_class": "stocks",
+                "id": 1,
+                "locale": "us",
+                "mic": "XASE",
+                "name": "NYSE American, LLC",
+                "operating_mic": "XNYS",
+                "participant_id": "A",
+                "type": "exchange",
+                "url": "https://www.nyse.com/markets/nyse-american"
+            }
+        ],
+        "status": "OK"
+    });
+    let exchanges = Exchanges::parse(&data.as_object().unwrap());
+    assert_eq!(exchanges.count.unwrap(), 1);
+    assert_eq!(exchanges.status.unwrap(), "OK");
+    assert_eq!(
+        exchanges.request_id.unwrap(),
+        "31d59dda-80e5-4721-8496-d0d32a654afe"
+    );
+    let exchanges = exchanges.exchanges.unwrap();
+    assert_eq!(exchanges[0].acronym.clone().unwrap(), "AMEX");
+    assert_eq!(exchanges[0].asset_class.clone().unwrap(), "stocks");
+    assert_eq!(exchanges[0].id.unwrap(), 1);
+    assert_eq!(exchanges[0].locale.clone().unwrap(), "us");
+    assert_eq!(exchanges[0].mic.clone().unwrap(), "XASE");
+    assert_eq!(exchanges[0].name.clone().unwrap(), "NYSE American, LLC");
+    assert_eq!(exchanges[0].operating_mic.clone().unwrap(), "XNYS");
+    assert_eq!(exchanges[0].participant_id.clone().unwrap(), "A");
+    assert_eq!(exchanges[0].exchange_type.clone().unwrap(), "exchange");
+    assert_eq!(
+        exchanges[0].url.clone().unwrap(),
+        "https://www.nyse.com/markets/nyse-american"
+    );
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.asset_class = Some(AssetClass::Stocks);
+    parameters.locale = Some(Locale::Us);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/v3/reference/exchanges?asset_class=stocks&locale=us&apiKey=apiKey"
+    );
+}
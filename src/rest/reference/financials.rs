@@ -1 +1,479 @@
+use crate::client::PolygonClient;
+use crate::data_types::{financial::Financial, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{
+        FinancialTimeframe, Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes,
+    },
+};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Financials {
+    pub count: Option<i64>,
+    pub financials: Option<Vec<Financial>>,
+    pub next_url: Option<String>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl FinancialsRequest for Financials {}
+
+impl Parse for Financials {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let count = Self::i64_parse(map, vec!["count"]);
+        let financials = Self::array_parse(map, vec!["results"]);
+        let next_url = Self::string_parse(map, vec!["next_url"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        Financials {
+            count,
+            financials,
+            next_url,
+            request_id,
+            status,
+        }
+    }
+}
+
+impl Paginate for Financials {
+    type Item = Financial;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Financial> {
+        self.financials.unwrap_or_default()
+    }
+}
+
+pub trait FinancialsRequest {
+    #[allow(clippy::too_many_arguments)]
+    fn get_financials_async(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        cik: Option<String>,
+        company_name: Option<String>,
+        sic: Option<String>,
+        filing_date: Option<String>,
+        filing_date_from: Option<String>,
+        filing_date_to: Option<String>,
+        period_of_report_date: Option<String>,
+        period_of_report_date_from: Option<String>,
+        period_of_report_date_to: Option<String>,
+        timeframe: Option<FinancialTimeframe>,
+        include_sources: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<Financials, ErrorCode>> + Send {
+        async move {
+            let filing_date = if filing_date_from.is_some() || filing_date_to.is_some() {
+                None
+            } else {
+                filing_date
+            };
+            let period_of_report_date =
+                if period_of_report_date_from.is_some() || period_of_report_date_to.is_some() {
+                    None
+                } else {
+                    period_of_report_date
+                };
+            let financials_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker,
+                cik,
+                company_name,
+                sic,
+                date: filing_date,
+                from: filing_date_from,
+                to: filing_date_to,
+                period_of_report_date,
+                period_of_report_date_from,
+                period_of_report_date_to,
+                financial_timeframe: timeframe,
+                include_sources,
+                order,
+                limit,
+                sortv3: sort,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(
+                &TickerTypes::stocks(),
+                PARAMETERS,
+                &financials_parameters,
+            )?;
+            let url = url(&client.base_url, &financials_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Financials::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
+    fn get_financials(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        cik: Option<String>,
+        company_name: Option<String>,
+        sic: Option<String>,
+        filing_date: Option<String>,
+        filing_date_from: Option<String>,
+        filing_date_to: Option<String>,
+        period_of_report_date: Option<String>,
+        period_of_report_date_from: Option<String>,
+        period_of_report_date_to: Option<String>,
+        timeframe: Option<FinancialTimeframe>,
+        include_sources: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> Result<Financials, ErrorCode> {
+        Request::blocking(Self::get_financials_async(
+            client,
+            ticker,
+            cik,
+            company_name,
+            sic,
+            filing_date,
+            filing_date_from,
+            filing_date_to,
+            period_of_report_date,
+            period_of_report_date_from,
+            period_of_report_date_to,
+            timeframe,
+            include_sources,
+            order,
+            limit,
+            sort,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Cik,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::CompanyName,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sic,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Date,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::From,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::To,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PeriodOfReportDate,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PeriodOfReportDateFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PeriodOfReportDateTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::FinancialTimeframe,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::IncludeSources,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Order,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Limit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sortv3,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/vX/reference/financials?{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
+        if let Some(ticker) = &parameters.ticker {
+            format!("ticker={}&", Request::encode(ticker))
+        } else {
+            "".to_string()
+        },
+        if let Some(cik) = &parameters.cik {
+            format!("cik={}&", Request::encode(cik))
+        } else {
+            "".to_string()
+        },
+        if let Some(company_name) = &parameters.company_name {
+            format!("company_name={}&", Request::encode(company_name))
+        } else {
+            "".to_string()
+        },
+        if let Some(sic) = &parameters.sic {
+            format!("sic={}&", Request::encode(sic))
+        } else {
+            "".to_string()
+        },
+        if let Some(date) = &parameters.date {
+            format!("filing_date={}&", Request::encode(date))
+        } else {
+            "".to_string()
+        },
+        if let Some(from) = &parameters.from {
+            format!("filing_date.gte={}&", Request::encode(from))
+        } else {
+            "".to_string()
+        },
+        if let Some(to) = &parameters.to {
+            format!("filing_date.lte={}&", Request::encode(to))
+        } else {
+            "".to_string()
+        },
+        if let Some(period_of_report_date) = &parameters.period_of_report_date {
+            format!(
+                "period_of_report_date={}&",
+                Request::encode(period_of_report_date)
+            )
+        } else {
+            "".to_string()
+        },
+        if let Some(period_of_report_date_from) = &parameters.period_of_report_date_from {
+            format!(
+                "period_of_report_date.gte={}&",
+                Request::encode(period_of_report_date_from)
+            )
+        } else {
+            "".to_string()
+        },
+        if let Some(period_of_report_date_to) = &parameters.period_of_report_date_to {
+            format!(
+                "period_of_report_date.lte={}&",
+                Request::encode(period_of_report_date_to)
+            )
+        } else {
+            "".to_string()
+        },
+        if let Some(financial_timeframe) = &parameters.financial_timeframe {
+            format!(
+                "timeframe={}&",
+                financial_timeframe.to_string().to_lowercase()
+            )
+        } else {
+            "".to_string()
+        },
+        if let Some(include_sources) = &parameters.include_sources {
+            format!("include_sources={}&", include_sources)
+        } else {
+            "".to_string()
+        },
+        if let Some(order) = &parameters.order {
+            format!("order={}&", order.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(limit) = &parameters.limit {
+            format!("limit={}&", limit)
+        } else {
+            "".to_string()
+        },
+        if let Some(sort) = &parameters.sortv3 {
+            match sort {
+                Sortv3::FilingDate => "sort=filing_date&".to_string(),
+                Sortv3::PeriodOfReportDate => "sort=period_of_report_date&".to_string(),
+                _ => format!("sort={}&", sort.to_string().to_lowercase()),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_financials_parse() {
+    let data = serde_json::json!({
+        "count": 1,
+        "next_url": "https://api.polygon.io/vX/reference/financials?cursor=YXA9MjAyMy0wMS0wMSZhcz0mbGltaXQ9MSZzb3J0PWZpbGluZ19kYXRl",
+        "request_id": "55eb92ed43b25568ab0cce159830ea34",
+        "results": [
+            {
+                "cik": "0000320193",
+                "company_name": "Apple Inc.",
+                "end_date": "2023-07-01",
+                "filing_date": "2023-08-04",
+                "financials": {
+                    "balance_sheet": {
+                        "assets": {
+                            "label": "Assets",
+                            "order": 100,
+                            "unit": "USD",
+                            "value": 335038000000.0
+                        }
+                    },
+                    "cash_flow_statement": {
+                        "net_cash_flow": {
+                            "label": "Net Cash Flow",
+                            "order": 1100,
+                            "unit": "USD",
+                            "value": 2218000000.0
+                        }
+                    },
+                    "comprehensive_income": {
+                        "comprehensive_income_loss": {
+                            "label": "Comprehensive Income/Loss",
+                            "order": 100,
+                            "unit": "USD",
+                            "value": 18865000000.0
+                        }
+                    },
+                    "income_statement": {
+                        "basic_earnings_per_share": {
+                            "label": "Basic Earnings Per Share",
+                            "order": 4200,
+                            "unit": "USD / shares",
+                            "value": 1.27
+                        },
+                        "revenues": {
+                            "label": "Revenues",
+                            "order": 100,
+                            "unit": "USD",
+                            "value": 81797000000.0
+                        },
+                        "income_loss_before_equity_method_investments": {
+                            "label": "Income/Loss Before Equity Method Investments",
+                            "order": 1300,
+                            "unit": "USD",
+                            "value": 22733000000.0
+                        }
+                    }
+                },
+                "fiscal_period": "Q3",
+                "fiscal_year": "2023",
+                "sic": "3571",
+                "source_filing_file_url": "https://api.polygon.io/v1/reference/sec/filings/0000320193-23-000077/files/aapl-20230701_htm.xml",
+                "source_filing_url": "https://api.polygon.io/v1/reference/sec/filings/0000320193-23-000077",
+                "start_date": "2023-04-02",
+                "tickers": ["AAPL"],
+                "timeframe": "quarterly"
+            }
+        ],
+        "status": "OK"
+    });
+    let financials = Financials::parse(&data.as_object().unwrap());
+    assert_eq!(financials.count.unwrap(), 1);
+    assert_eq!(financials.status.unwrap(), "OK");
+    let financial = financials.financials.unwrap()[0].clone();
+    assert_eq!(financial.cik.unwrap(), "0000320193");
+    assert_eq!(financial.company_name.unwrap(), "Apple Inc.");
+    assert_eq!(financial.fiscal_period.unwrap(), "Q3");
+    assert_eq!(financial.fiscal_year.unwrap(), "2023");
+    assert_eq!(financial.tickers.unwrap(), vec![String::from("AAPL")]);
+    assert_eq!(financial.timeframe.unwrap(), FinancialTimeframe::Quarterly);
+    let revenues = financial
+        .income_statement
+        .clone()
+        .unwrap()
+        .revenues
+        .unwrap();
+    assert_eq!(revenues.label.unwrap(), "Revenues");
+    assert_eq!(revenues.order.unwrap(), 100);
+    assert_eq!(revenues.unit.unwrap(), "USD");
+    assert_eq!(revenues.value.unwrap(), 81797000000.0);
+    let additional = financial
+        .income_statement
+        .clone()
+        .unwrap()
+        .additional_line_items;
+    assert_eq!(additional.len(), 1);
+    assert_eq!(
+        additional["income_loss_before_equity_method_investments"]
+            .value
+            .unwrap(),
+        22733000000.0
+    );
+    let eps = financial
+        .income_statement
+        .unwrap()
+        .basic_earnings_per_share
+        .unwrap();
+    assert_eq!(eps.unit.unwrap(), "USD / shares");
+    assert_eq!(eps.value.unwrap(), 1.27);
+    assert_eq!(
+        financial
+            .balance_sheet
+            .unwrap()
+            .assets
+            .unwrap()
+            .value
+            .unwrap(),
+        335038000000.0
+    );
+    assert_eq!(
+        financial
+            .cash_flow_statement
+            .unwrap()
+            .net_cash_flow
+            .unwrap()
+            .order
+            .unwrap(),
+        1100
+    );
+    assert_eq!(
+        financial
+            .comprehensive_income
+            .unwrap()
+            .comprehensive_income_loss
+            .unwrap()
+            .label
+            .unwrap(),
+        "Comprehensive Income/Loss"
+    );
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("AAPL"));
+    parameters.company_name = Some(String::from("Apple Inc."));
+    parameters.sic = Some(String::from("3571"));
+    parameters.from = Some(String::from("2023-01-01"));
+    parameters.period_of_report_date_to = Some(String::from("2023-12-31"));
+    parameters.financial_timeframe = Some(FinancialTimeframe::Quarterly);
+    parameters.include_sources = Some(false);
+    parameters.order = Some(Order::Desc);
+    parameters.limit = Some(10);
+    parameters.sortv3 = Some(Sortv3::FilingDate);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/vX/reference/financials?ticker=AAPL&company_name=Apple%20Inc.&sic=3571&filing_date.gte=2023-01-01&period_of_report_date.lte=2023-12-31&timeframe=quarterly&include_sources=false&order=desc&limit=10&sort=filing_date&apiKey=apiKey");
+    parameters.company_name = Some(String::from("Procter & Gamble"));
+    let encoded = self::url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert!(encoded.contains("&company_name=Procter%20%26%20Gamble&sic=3571&"));
+}
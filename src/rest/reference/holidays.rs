@@ -1 +1,105 @@
+use crate::client::PolygonClient;
+use crate::data_types::{market_holiday::MarketHoliday, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Holidays {
+    pub holidays: Option<Vec<MarketHoliday>>,
+}
+
+impl HolidaysRequest for Holidays {}
+
+impl Parse for Holidays {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let holidays = Self::array_parse(map, vec!["results"]);
+        Holidays { holidays }
+    }
+}
+
+pub trait HolidaysRequest {
+    fn get_market_holidays_async(
+        client: &PolygonClient,
+    ) -> impl Future<Output = Result<Holidays, ErrorCode>> + Send {
+        async move {
+            let holidays_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &holidays_parameters)?;
+            let url = url(&client.base_url, &holidays_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Holidays::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_market_holidays(client: &PolygonClient) -> Result<Holidays, ErrorCode> {
+        Request::blocking(Self::get_market_holidays_async(client))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v1/marketstatus/upcoming?apiKey={}",
+        base_url, &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_holidays_parse() {
+    let data = serde_json::json!({
+        "results": [
+            {
+                "date": "2020-11-26",
+                "exchange": "NYSE",
+                "name": "Thanksgiving",
+                "status": "closed"
+            },
+            {
+                "close": "2020-11-27T18:00:00.000Z",
+                "date": "2020-11-27",
+                "exchange": "NASDAQ",
+                "name": "Thanksgiving",
+                "open": "2020-11-27T14:30:00.000Z",
+                "status": "early-close"
+            }
+        ]
+    });
+    let holidays = Holidays::parse(&data.as_object().unwrap());
+    let holidays = holidays.holidays.unwrap();
+    assert_eq!(holidays.len(), 2);
+    assert_eq!(holidays[0].date.clone().unwrap(), "2020-11-26");
+    assert_eq!(holidays[0].exchange.clone().unwrap(), "NYSE");
+    assert_eq!(holidays[0].name.clone().unwrap(), "Thanksgiving");
+    assert_eq!(holidays[0].status.clone().unwrap(), "closed");
+    assert_eq!(holidays[0].open, None);
+    assert_eq!(
+        holidays[1].close.clone().unwrap(),
+        "2020-11-27T18:00:00.000Z"
+    );
+    assert_eq!(
+        holidays[1].open.clone().unwrap(),
+        "2020-11-27T14:30:00.000Z"
+    );
+    assert_eq!(holidays[1].status.clone().unwrap(), "early-close");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/v1/marketstatus/upcoming?apiKey=apiKey"
+    );
+}
@@ -1 +1,304 @@
+use crate::client::PolygonClient;
+use crate::data_types::{news_article::NewsArticle, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes},
+};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct News {
+    pub count: Option<i64>,
+    pub news: Option<Vec<NewsArticle>>,
+    pub next_url: Option<String>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl NewsRequest for News {}
+
+impl Parse for News {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let count = Self::i64_parse(map, vec!["count"]);
+        let news = Self::array_parse(map, vec!["results"]);
+        let next_url = Self::string_parse(map, vec!["next_url"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        News {
+            count,
+            news,
+            next_url,
+            request_id,
+            status,
+        }
+    }
+}
+
+impl Paginate for News {
+    type Item = NewsArticle;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<NewsArticle> {
+        self.news.unwrap_or_default()
+    }
+}
+
+pub trait NewsRequest {
+    #[allow(clippy::too_many_arguments)]
+    fn get_news_async(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        published_utc: Option<String>,
+        published_utc_from: Option<String>,
+        published_utc_to: Option<String>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<News, ErrorCode>> + Send {
+        async move {
+            let news_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker,
+                ticker_from,
+                ticker_to,
+                published_utc,
+                published_utc_from,
+                published_utc_to,
+                order,
+                limit,
+                sortv3: sort,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::stocks(), PARAMETERS, &news_parameters)?;
+            let url = url(&client.base_url, &news_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(News::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
+    fn get_news(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        published_utc: Option<String>,
+        published_utc_from: Option<String>,
+        published_utc_to: Option<String>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> Result<News, ErrorCode> {
+        Request::blocking(Self::get_news_async(
+            client,
+            ticker,
+            ticker_from,
+            ticker_to,
+            published_utc,
+            published_utc_from,
+            published_utc_to,
+            order,
+            limit,
+            sort,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PublishedUtc,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PublishedUtcFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::PublishedUtcTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Order,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Limit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sortv3,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v2/reference/news?{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
+        if let Some(ticker) = &parameters.ticker {
+            format!("ticker={}&", ticker)
+        } else {
+            "".to_string()
+        },
+        if let Some(tf) = &parameters.ticker_from {
+            format!("ticker.gte={}&", tf)
+        } else {
+            "".to_string()
+        },
+        if let Some(tt) = &parameters.ticker_to {
+            format!("ticker.lte={}&", tt)
+        } else {
+            "".to_string()
+        },
+        if let Some(published_utc) = &parameters.published_utc {
+            format!("published_utc={}&", Request::encode(published_utc))
+        } else {
+            "".to_string()
+        },
+        if let Some(from) = &parameters.published_utc_from {
+            format!("published_utc.gte={}&", Request::encode(from))
+        } else {
+            "".to_string()
+        },
+        if let Some(to) = &parameters.published_utc_to {
+            format!("published_utc.lte={}&", Request::encode(to))
+        } else {
+            "".to_string()
+        },
+        if let Some(order) = &parameters.order {
+            format!("order={}&", order.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(limit) = &parameters.limit {
+            format!("limit={}&", limit)
+        } else {
+            "".to_string()
+        },
+        if let Some(sort) = &parameters.sortv3 {
+            match sort {
+                Sortv3::PublishedUtc => "sort=published_utc&".to_string(),
+                _ => format!("sort={}&", sort.to_string().to_lowercase()),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_news_parse() {
+    use crate::rest::parameters::Sentiment;
+
+    let data = serde_json::json!({
+        "count": 1,
+        "next_url": "https://api.polygon.io:443/v2/reference/news?cursor=eyJsaW1pdCI6MSwic29ydCI6InB1Ymxpc2hlZF91dGMiLCJvcmRlciI6ImFzY2VuZGluZyIsInRpY2tlciI6e30sInB1Ymxpc2hlZF91dGMiOnsiZ3RlIjoiMjAyMS0wNC0yNiJ9LCJzZWFyY2hfYWZ0ZXIiOlsxNjE5NDA0Mzk3MDAwLG51bGxdfQ",
+        "request_id": "831afdb0b8078549fed053476984947a",
+        "results": [
+            {
+                "amp_url": "https://m.uk.investing.com/news/stock-market-news/markets-are-underestimating-fed-cuts-ubs-3559968?ampMode=1",
+                "article_url": "https://uk.investing.com/news/stock-market-news/markets-are-underestimating-fed-cuts-ubs-3559968",
+                "author": "Sam Boughedda",
+                "description": "UBS analysts warn that markets are underestimating the extent of future interest rate cuts by the Federal Reserve.",
+                "id": "8ec638777ca03b553ae516761c2a22ba2fdd2f37befae3ab6fdab74e9e5193eb",
+                "image_url": "https://i-invdn-com.investing.com/news/LYNXNPEC4I0AL_L.jpg",
+                "insights": [
+                    {
+                        "sentiment": "positive",
+                        "sentiment_reasoning": "UBS analysts are providing a bullish outlook on the extent of future Federal Reserve rate cuts.",
+                        "ticker": "UBS"
+                    }
+                ],
+                "keywords": ["Federal Reserve", "interest rates", "economic data"],
+                "published_utc": "2024-06-24T18:33:53Z",
+                "publisher": {
+                    "favicon_url": "https://s3.polygon.io/public/assets/news/favicons/investing.ico",
+                    "homepage_url": "https://www.investing.com/",
+                    "logo_url": "https://s3.polygon.io/public/assets/news/logos/investing.png",
+                    "name": "Investing.com"
+                },
+                "tickers": ["UBS"],
+                "title": "Markets are underestimating Fed cuts: UBS By Investing.com - Investing.com UK"
+            }
+        ],
+        "status": "OK"
+    });
+    let news = News::parse(&data.as_object().unwrap());
+    assert_eq!(news.count.unwrap(), 1);
+    assert_eq!(news.status.unwrap(), "OK");
+    assert_eq!(news.request_id.unwrap(), "831afdb0b8078549fed053476984947a");
+    let article = news.news.unwrap()[0].clone();
+    assert_eq!(article.author.unwrap(), "Sam Boughedda");
+    assert_eq!(article.published_utc.unwrap(), "2024-06-24T18:33:53Z");
+    assert_eq!(article.tickers.unwrap(), vec![String::from("UBS")]);
+    assert_eq!(article.keywords.unwrap().len(), 3);
+    let publisher = article.publisher.unwrap();
+    assert_eq!(publisher.name.unwrap(), "Investing.com");
+    assert_eq!(
+        publisher.homepage_url.unwrap(),
+        "https://www.investing.com/"
+    );
+    let insights = article.insights.unwrap();
+    assert_eq!(insights[0].ticker.clone().unwrap(), "UBS");
+    assert_eq!(insights[0].sentiment.unwrap(), Sentiment::Positive);
+    assert_eq!(insights[0].sentiment_reasoning.clone().unwrap(), "UBS analysts are providing a bullish outlook on the extent of future Federal Reserve rate cuts.");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("AAPL"));
+    parameters.published_utc_from = Some(String::from("2024-06-01"));
+    parameters.published_utc_to = Some(String::from("2024-06-30"));
+    parameters.order = Some(Order::Desc);
+    parameters.limit = Some(10);
+    parameters.sortv3 = Some(Sortv3::PublishedUtc);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/v2/reference/news?ticker=AAPL&published_utc.gte=2024-06-01&published_utc.lte=2024-06-30&order=desc&limit=10&sort=published_utc&apiKey=apiKey");
+    parameters.published_utc_from = Some(String::from("2024-06-24T09:30:00-04:00"));
+    parameters.published_utc_to = Some(String::from("2024-06-24T13:45:00Z"));
+    let encoded = self::url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert!(encoded.contains("&published_utc.gte=2024-06-24T09%3A30%3A00-04%3A00&published_utc.lte=2024-06-24T13%3A45%3A00Z&"));
+}
+
+#[tokio::test]
+async fn test_get_news_async() {
+    let client = PolygonClient::new("ak123456789012345678901234567890").unwrap();
+    let news = News::get_news_async(
+        &client,
+        None,
+        None,
+        None,
+        Some(String::from("2024-06-24")),
+        Some(String::from("2024-06-01")),
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert!(matches!(news, Err(ErrorCode::PublishedUtcRangeError)));
+}
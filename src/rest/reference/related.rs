@@ -1 +1,123 @@
+use crate::client::PolygonClient;
+use crate::data_types::{related_company::RelatedCompany, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{Parameter, ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Related {
+    pub related_companies: Option<Vec<RelatedCompany>>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+    pub stock_symbol: Option<String>,
+}
+
+impl RelatedRequest for Related {}
+
+impl Parse for Related {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let related_companies = Self::array_parse(map, vec!["results"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        let stock_symbol = Self::string_parse(map, vec!["stock_symbol"]);
+        Related {
+            related_companies,
+            request_id,
+            status,
+            stock_symbol,
+        }
+    }
+}
+
+pub trait RelatedRequest {
+    fn get_related_companies_async(
+        client: &PolygonClient,
+        ticker: String,
+    ) -> impl Future<Output = Result<Related, ErrorCode>> + Send {
+        async move {
+            let related_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker: Some(ticker),
+                ..Parameters::default()
+            };
+            Verification::check_parameters(
+                &TickerTypes::stocks(),
+                PARAMETERS,
+                &related_parameters,
+            )?;
+            let url = url(&client.base_url, &related_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Related::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_related_companies(client: &PolygonClient, ticker: String) -> Result<Related, ErrorCode> {
+        Request::blocking(Self::get_related_companies_async(client, ticker))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[&ParameterRequirment {
+    required: true,
+    parameter: Parameter::Ticker,
+}];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v1/related-companies/{}?apiKey={}",
+        base_url,
+        match &parameters.ticker {
+            Some(ticker) => ticker,
+            None => return Err(ErrorCode::TickerNotSet),
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_related_parse() {
+    let data = serde_json::json!({
+        "request_id": "31d59dda-80e5-4721-8496-d0d32a654afe",
+        "results": [
+            {
+                "ticker": "MSFT"
+            },
+            {
+                "ticker": "GOOGL"
+            },
+            {
+                "ticker": "AMZN"
+            }
+        ],
+        "status": "OK",
+        "stock_symbol": "AAPL"
+    });
+    let related = Related::parse(&data.as_object().unwrap());
+    assert_eq!(related.status.unwrap(), "OK");
+    assert_eq!(
+        related.request_id.unwrap(),
+        "31d59dda-80e5-4721-8496-d0d32a654afe"
+    );
+    assert_eq!(related.stock_symbol.unwrap(), "AAPL");
+    let related_companies = related.related_companies.unwrap();
+    assert_eq!(related_companies.len(), 3);
+    assert_eq!(related_companies[0].ticker.clone().unwrap(), "MSFT");
+    assert_eq!(related_companies[2].ticker.clone().unwrap(), "AMZN");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("AAPL"));
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/v1/related-companies/AAPL?apiKey=apiKey"
+    );
+}
@@ -1 +1,273 @@
+use crate::client::PolygonClient;
+use crate::data_types::{split::Split, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes},
+};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Splits {
+    pub next_url: Option<String>,
+    pub request_id: Option<String>,
+    pub splits: Option<Vec<Split>>,
+    pub status: Option<String>,
+}
+
+impl SplitsRequest for Splits {}
+
+impl Parse for Splits {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let next_url = Self::string_parse(map, vec!["next_url"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let splits = Self::array_parse(map, vec!["results"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        Splits {
+            next_url,
+            request_id,
+            splits,
+            status,
+        }
+    }
+}
+
+impl Paginate for Splits {
+    type Item = Split;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<Split> {
+        self.splits.unwrap_or_default()
+    }
+}
+
+pub trait SplitsRequest {
+    #[allow(clippy::too_many_arguments)]
+    fn get_splits_async(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        execution_date: Option<String>,
+        execution_date_from: Option<String>,
+        execution_date_to: Option<String>,
+        reverse_split: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<Splits, ErrorCode>> + Send {
+        async move {
+            let ticker = if ticker_from.is_some() || ticker_to.is_some() {
+                None
+            } else {
+                ticker
+            };
+            let execution_date = if execution_date_from.is_some() || execution_date_to.is_some() {
+                None
+            } else {
+                execution_date
+            };
+            let splits_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker,
+                ticker_from,
+                ticker_to,
+                date: execution_date,
+                from: execution_date_from,
+                to: execution_date_to,
+                reverse_split,
+                order,
+                limit,
+                sortv3: sort,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::stocks(), PARAMETERS, &splits_parameters)?;
+            let url = url(&client.base_url, &splits_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Splits::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
+    fn get_splits(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        execution_date: Option<String>,
+        execution_date_from: Option<String>,
+        execution_date_to: Option<String>,
+        reverse_split: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> Result<Splits, ErrorCode> {
+        Request::blocking(Self::get_splits_async(
+            client,
+            ticker,
+            ticker_from,
+            ticker_to,
+            execution_date,
+            execution_date_from,
+            execution_date_to,
+            reverse_split,
+            order,
+            limit,
+            sort,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Date,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::From,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::To,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::ReverseSplit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Order,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Limit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sortv3,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/splits?{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
+        if let Some(ticker) = &parameters.ticker {
+            format!("ticker={}&", ticker)
+        } else {
+            "".to_string()
+        },
+        if let Some(tf) = &parameters.ticker_from {
+            format!("ticker.gte={}&", tf)
+        } else {
+            "".to_string()
+        },
+        if let Some(tt) = &parameters.ticker_to {
+            format!("ticker.lte={}&", tt)
+        } else {
+            "".to_string()
+        },
+        if let Some(date) = &parameters.date {
+            format!("execution_date={}&", date)
+        } else {
+            "".to_string()
+        },
+        if let Some(from) = &parameters.from {
+            format!("execution_date.gte={}&", from)
+        } else {
+            "".to_string()
+        },
+        if let Some(to) = &parameters.to {
+            format!("execution_date.lte={}&", to)
+        } else {
+            "".to_string()
+        },
+        if let Some(reverse_split) = &parameters.reverse_split {
+            format!("reverse_split={}&", reverse_split)
+        } else {
+            "".to_string()
+        },
+        if let Some(order) = &parameters.order {
+            format!("order={}&", order.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(limit) = &parameters.limit {
+            format!("limit={}&", limit)
+        } else {
+            "".to_string()
+        },
+        if let Some(sort) = &parameters.sortv3 {
+            match sort {
+                Sortv3::ExecutionDate => "sort=execution_date&".to_string(),
+                _ => format!("sort={}&", sort.to_string().to_lowercase()),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_splits_parse() {
+    let data = serde_json::json!({
+        "next_url": "https://api.polygon.io/v3/splits/AAPL?cursor=YWN0aXZlPXRydWUmZGF0ZT0yMDIxLTA0LTI1JmxpbWl0PTEmb3JkZXI9YXNjJnBhZ2VfbWFya2VyPUElN0M5YWRjMjY0ZTgyM2E1ZjBiOGUyNDc5YmZiOGE1YmYwNDVkYzU0YjgwMDcyMWE2YmI1ZjBjMjQwMjU4MjFmNGZiJnNvcnQ9dGlja2Vy",
+        "request_id": "6a7e466379af0a71039d60cc78e72282",
+        "results": [
+            {
+                "execution_date": "2020-08-31",
+                "id": "E36416cce743c3964c5da63e1ef1626c0aece30fb47302eea5a49c0055c04e8d0",
+                "split_from": 1,
+                "split_to": 4,
+                "ticker": "AAPL"
+            }
+        ],
+        "status": "OK"
+    });
+    let splits = Splits::parse(&data.as_object().unwrap());
+    assert_eq!(splits.status.unwrap(), "OK");
+    assert_eq!(
+        splits.request_id.unwrap(),
+        "6a7e466379af0a71039d60cc78e72282"
+    );
+    let splits = splits.splits.unwrap();
+    assert_eq!(splits[0].execution_date.clone().unwrap(), "2020-08-31");
+    assert_eq!(splits[0].split_from.unwrap(), 1.0);
+    assert_eq!(splits[0].split_to.unwrap(), 4.0);
+    assert_eq!(splits[0].ticker.clone().unwrap(), "AAPL");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker = Some(String::from("AAPL"));
+    parameters.from = Some(String::from("2020-01-01"));
+    parameters.to = Some(String::from("2020-12-31"));
+    parameters.reverse_split = Some(false);
+    parameters.order = Some(Order::Asc);
+    parameters.limit = Some(10);
+    parameters.sortv3 = Some(Sortv3::ExecutionDate);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/v3/reference/splits?ticker=AAPL&execution_date.gte=2020-01-01&execution_date.lte=2020-12-31&reverse_split=false&order=asc&limit=10&sort=execution_date&apiKey=apiKey");
+}
@@ -1 +1,131 @@
+use crate::client::PolygonClient;
+use crate::data_types::Parse;
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Status {
+    pub after_hours: Option<bool>,
+    pub currencies: Option<HashMap<String, String>>,
+    pub early_hours: Option<bool>,
+    pub exchanges: Option<HashMap<String, String>>,
+    pub indices_groups: Option<HashMap<String, String>>,
+    pub market: Option<String>,
+    pub server_time: Option<String>,
+}
+
+impl StatusRequest for Status {}
+
+impl Parse for Status {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let after_hours = Self::bool_parse(map, vec!["afterHours"]);
+        let currencies = Self::hashmap_string_parse(map, vec!["currencies"]);
+        let early_hours = Self::bool_parse(map, vec!["earlyHours"]);
+        let exchanges = Self::hashmap_string_parse(map, vec!["exchanges"]);
+        let indices_groups = Self::hashmap_string_parse(map, vec!["indicesGroups"]);
+        let market = Self::string_parse(map, vec!["market"]);
+        let server_time = Self::string_parse(map, vec!["serverTime"]);
+        Status {
+            after_hours,
+            currencies,
+            early_hours,
+            exchanges,
+            indices_groups,
+            market,
+            server_time,
+        }
+    }
+}
+
+pub trait StatusRequest {
+    fn get_market_status_async(
+        client: &PolygonClient,
+    ) -> impl Future<Output = Result<Status, ErrorCode>> + Send {
+        async move {
+            let status_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &status_parameters)?;
+            let url = url(&client.base_url, &status_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Status::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_market_status(client: &PolygonClient) -> Result<Status, ErrorCode> {
+        Request::blocking(Self::get_market_status_async(client))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v1/marketstatus/now?apiKey={}",
+        base_url, &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_status_parse() {
+    let data = serde_json::json!({
+        "afterHours": true,
+        "currencies": {
+            "crypto": "open",
+            "fx": "open"
+        },
+        "earlyHours": false,
+        "exchanges": {
+            "nasdaq": "extended-hours",
+            "nyse": "extended-hours",
+            "otc": "closed"
+        },
+        "indicesGroups": {
+            "s_and_p": "open",
+            "societe_generale": "open",
+            "msci": "open",
+            "ftse_russell": "open",
+            "mstar": "open",
+            "mstarc": "open",
+            "cccy": "open",
+            "nasdaq": "open",
+            "dow_jones": "open"
+        },
+        "market": "extended-hours",
+        "serverTime": "2020-11-10T17:37:37-05:00"
+    });
+    let status = Status::parse(&data.as_object().unwrap());
+    assert_eq!(status.after_hours.unwrap(), true);
+    assert_eq!(status.early_hours.unwrap(), false);
+    assert_eq!(status.currencies.unwrap().get("fx").unwrap(), "open");
+    assert_eq!(
+        status.exchanges.unwrap().get("nasdaq").unwrap(),
+        "extended-hours"
+    );
+    assert_eq!(
+        status.indices_groups.unwrap().get("s_and_p").unwrap(),
+        "open"
+    );
+    assert_eq!(status.market.unwrap(), "extended-hours");
+    assert_eq!(status.server_time.unwrap(), "2020-11-10T17:37:37-05:00");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/v1/marketstatus/now?apiKey=apiKey"
+    );
+}
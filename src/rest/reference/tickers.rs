@@ -0,0 +1,398 @@
+use crate::client::PolygonClient;
+use crate::data_types::{reference_ticker::ReferenceTicker, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{Market, Order, Parameter, ParameterRequirment, Parameters, Sortv3, TickerTypes},
+};
+use crate::tools::{pagination::Paginate, request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tickers {
+    pub count: Option<i64>,
+    pub next_url: Option<String>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+    pub tickers: Option<Vec<ReferenceTicker>>,
+}
+
+impl TickersRequest for Tickers {}
+
+impl Parse for Tickers {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let count = Self::i64_parse(map, vec!["count"]);
+        let next_url = Self::string_parse(map, vec!["next_url"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        let tickers = Self::array_parse(map, vec!["results"]);
+        Tickers {
+            count,
+            next_url,
+            request_id,
+            status,
+            tickers,
+        }
+    }
+}
+
+impl Paginate for Tickers {
+    type Item = ReferenceTicker;
+
+    fn next_url(&self) -> Option<String> {
+        self.next_url.clone()
+    }
+
+    fn items(self) -> Vec<ReferenceTicker> {
+        self.tickers.unwrap_or_default()
+    }
+}
+
+pub trait TickersRequest {
+    #[allow(clippy::too_many_arguments)]
+    fn get_tickers_async(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        ticker_type: Option<String>,
+        market: Option<Market>,
+        exchange: Option<String>,
+        cusip: Option<String>,
+        cik: Option<String>,
+        date: Option<String>,
+        search: Option<String>,
+        active: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> impl Future<Output = Result<Tickers, ErrorCode>> + Send {
+        async move {
+            let tickers_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                ticker,
+                ticker_from,
+                ticker_to,
+                type_code: ticker_type,
+                market,
+                exchange,
+                cusip,
+                cik,
+                date,
+                search,
+                active,
+                order,
+                limit,
+                sortv3: sort,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &tickers_parameters)?;
+            let url = url(&client.base_url, &tickers_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Tickers::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[allow(clippy::too_many_arguments)]
+    fn get_tickers(
+        client: &PolygonClient,
+        ticker: Option<String>,
+        ticker_from: Option<String>,
+        ticker_to: Option<String>,
+        ticker_type: Option<String>,
+        market: Option<Market>,
+        exchange: Option<String>,
+        cusip: Option<String>,
+        cik: Option<String>,
+        date: Option<String>,
+        search: Option<String>,
+        active: Option<bool>,
+        order: Option<Order>,
+        limit: Option<u16>,
+        sort: Option<Sortv3>,
+    ) -> Result<Tickers, ErrorCode> {
+        Request::blocking(Self::get_tickers_async(
+            client,
+            ticker,
+            ticker_from,
+            ticker_to,
+            ticker_type,
+            market,
+            exchange,
+            cusip,
+            cik,
+            date,
+            search,
+            active,
+            order,
+            limit,
+            sort,
+        ))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Ticker,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerFrom,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::TickerTo,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Type,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Market,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Exchange,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Cusip,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Cik,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Date,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Search,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Active,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Order,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Limit,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Sortv3,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/tickers?{}{}{}{}{}{}{}{}{}{}{}{}{}{}apiKey={}",
+        base_url,
+        if let Some(ticker) = &parameters.ticker {
+            format!("ticker={}&", Request::encode(ticker))
+        } else {
+            "".to_string()
+        },
+        if let Some(tf) = &parameters.ticker_from {
+            format!("ticker.gte={}&", Request::encode(tf))
+        } else {
+            "".to_string()
+        },
+        if let Some(tt) = &parameters.ticker_to {
+            format!("ticker.lte={}&", Request::encode(tt))
+        } else {
+            "".to_string()
+        },
+        if let Some(ticker_type) = &parameters.type_code {
+            format!("type={}&", Request::encode(ticker_type))
+        } else {
+            "".to_string()
+        },
+        if let Some(market) = &parameters.market {
+            format!("market={}&", market.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(exchange) = &parameters.exchange {
+            format!("exchange={}&", Request::encode(exchange))
+        } else {
+            "".to_string()
+        },
+        if let Some(cusip) = &parameters.cusip {
+            format!("cusip={}&", Request::encode(cusip))
+        } else {
+            "".to_string()
+        },
+        if let Some(cik) = &parameters.cik {
+            format!("cik={}&", Request::encode(cik))
+        } else {
+            "".to_string()
+        },
+        if let Some(date) = &parameters.date {
+            format!("date={}&", Request::encode(date))
+        } else {
+            "".to_string()
+        },
+        if let Some(search) = &parameters.search {
+            format!("search={}&", Request::encode(search))
+        } else {
+            "".to_string()
+        },
+        if let Some(active) = &parameters.active {
+            format!("active={}&", active)
+        } else {
+            "".to_string()
+        },
+        if let Some(order) = &parameters.order {
+            format!("order={}&", order.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(limit) = &parameters.limit {
+            format!("limit={}&", limit)
+        } else {
+            "".to_string()
+        },
+        if let Some(sort) = &parameters.sortv3 {
+            match sort {
+                Sortv3::PrimaryExchange => "sort=primary_exchange&".to_string(),
+                Sortv3::CurrencySymbol => "sort=currency_symbol&".to_string(),
+                Sortv3::CurrencyName => "sort=currency_name&".to_string(),
+                Sortv3::BaseCurrencySymbol => "sort=base_currency_symbol&".to_string(),
+                Sortv3::BaseCurrencyName => "sort=base_currency_name&".to_string(),
+                Sortv3::CompositeFigi => "sort=composite_figi&".to_string(),
+                Sortv3::ShareClassFigi => "sort=share_class_figi&".to_string(),
+                Sortv3::LastUpdatedUtc => "sort=last_updated_utc&".to_string(),
+                Sortv3::DelistedUtc => "sort=delisted_utc&".to_string(),
+                _ => format!("sort={}&", sort.to_string().to_lowercase()),
+            }
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_tickers_parse() {
+    let data = serde_json::json!({
+        "count": 1,
+        "next_url": "https://api.polygon.io/v3/reference/tickers?cursor=YWN0aXZlPXRydWUmZGF0ZT0yMDIxLTA0LTI1JmxpbWl0PTEmb3JkZXI9YXNjJnBhZ2VfbWFya2VyPUElN0M5YWRjMjY0ZTgyM2E1ZjBiOGUyNDc5YmZiOGE1YmYwNDVkYzU0YjgwMDcyMWE2YmI1ZjBjMjQwMjU4MjFmNGZiJnNvcnQ9dGlja2Vy",
+        "request_id": "e70013d92930de90e089dc8fa098888e",
+        "results": [
+            {
+                "active": true,
+                "cik": "0001090872",
+                "composite_figi": "BBG000BWQYZ5",
+                "currency_name": "usd",
+                "last_updated_utc": "2021-04-25T00:00:00Z",
+                "locale": "us",
+                "market": "stocks",
+                "name": "Agilent Technologies Inc.",
+                "primary_exchange": "XNYS",
+                "share_class_figi": "BBG001SCTQY4",
+                "ticker": "A",
+                "type": "CS"
+            }
+        ],
+        "status": "OK"
+    });
+    let tickers = Tickers::parse(&data.as_object().unwrap());
+    assert_eq!(tickers.count.unwrap(), 1);
+    assert_eq!(tickers.status.unwrap(), "OK");
+    assert_eq!(
+        tickers.request_id.unwrap(),
+        "e70013d92930de90e089dc8fa098888e"
+    );
+    let results = tickers.tickers.unwrap();
+    assert_eq!(results[0].active.unwrap(), true);
+    assert_eq!(results[0].cik.clone().unwrap(), "0001090872");
+    assert_eq!(results[0].composite_figi.clone().unwrap(), "BBG000BWQYZ5");
+    assert_eq!(results[0].currency_name.clone().unwrap(), "usd");
+    assert_eq!(results[0].locale.clone().unwrap(), "us");
+    assert_eq!(results[0].market.clone().unwrap(), "stocks");
+    assert_eq!(
+        results[0].name.clone().unwrap(),
+        "Agilent Technologies Inc."
+    );
+    assert_eq!(results[0].primary_exchange.clone().unwrap(), "XNYS");
+    assert_eq!(results[0].ticker.clone().unwrap(), "A");
+    assert_eq!(results[0].ticker_type.clone().unwrap(), "CS");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.ticker_from = Some(String::from("A"));
+    parameters.ticker_to = Some(String::from("B"));
+    parameters.type_code = Some(String::from("CS"));
+    parameters.market = Some(Market::Stocks);
+    parameters.exchange = Some(String::from("XNYS"));
+    parameters.cusip = Some(String::from("00846U101"));
+    parameters.cik = Some(String::from("0001090872"));
+    parameters.date = Some(String::from("2021-04-25"));
+    parameters.search = Some(String::from("Agilent Technologies"));
+    parameters.active = Some(true);
+    parameters.order = Some(Order::Asc);
+    parameters.limit = Some(100);
+    parameters.sortv3 = Some(Sortv3::PrimaryExchange);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(url, "https://api.polygon.io/v3/reference/tickers?ticker.gte=A&ticker.lte=B&type=CS&market=stocks&exchange=XNYS&cusip=00846U101&cik=0001090872&date=2021-04-25&search=Agilent%20Technologies&active=true&order=asc&limit=100&sort=primary_exchange&apiKey=apiKey");
+    parameters.search = Some(String::from("AT&T"));
+    parameters.cusip = Some(String::from("00206R#02"));
+    let encoded = self::url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert!(encoded.contains("&cusip=00206R%2302&"));
+    assert!(encoded.contains("&search=AT%26T&active=true&"));
+}
+
+#[tokio::test]
+async fn test_get_tickers_async() {
+    let client = PolygonClient::new("ak123456789012345678901234567890").unwrap();
+    let tickers = Tickers::get_tickers_async(
+        &client,
+        None,
+        None,
+        None,
+        None,
+        Some(Market::Stocks),
+        Some(String::from("nyse")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert!(matches!(tickers, Err(ErrorCode::ExchangeError)));
+    let tickers = Tickers::get_tickers_async(
+        &client,
+        Some(String::from("AAPL")),
+        Some(String::from("A")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert!(matches!(tickers, Err(ErrorCode::TickerRangeError)));
+}
@@ -1 +1,136 @@
+use crate::client::PolygonClient;
+use crate::data_types::{reference_ticker_type::ReferenceTickerType, Parse};
+use crate::rest::{
+    error::ErrorCode,
+    parameters::{AssetClass, Locale, Parameter, ParameterRequirment, Parameters, TickerTypes},
+};
+use crate::tools::{request::Request, verification::Verification};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Types {
+    pub count: Option<i64>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+    pub types: Option<Vec<ReferenceTickerType>>,
+}
+
+impl TypesRequest for Types {}
+
+impl Parse for Types {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let count = Self::i64_parse(map, vec!["count"]);
+        let request_id = Self::string_parse(map, vec!["request_id"]);
+        let status = Self::string_parse(map, vec!["status"]);
+        let types = Self::array_parse(map, vec!["results"]);
+        Types {
+            count,
+            request_id,
+            status,
+            types,
+        }
+    }
+}
+
+pub trait TypesRequest {
+    fn get_ticker_types_async(
+        client: &PolygonClient,
+        asset_class: Option<AssetClass>,
+        locale: Option<Locale>,
+    ) -> impl Future<Output = Result<Types, ErrorCode>> + Send {
+        async move {
+            let types_parameters = Parameters {
+                api_key: client.api_key.to_string(),
+                asset_class,
+                locale,
+                ..Parameters::default()
+            };
+            Verification::check_parameters(&TickerTypes::all(), PARAMETERS, &types_parameters)?;
+            let url = url(&client.base_url, &types_parameters)?;
+            let map = Request::request(client, url).await?;
+            Ok(Types::parse(&map))
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    fn get_ticker_types(
+        client: &PolygonClient,
+        asset_class: Option<AssetClass>,
+        locale: Option<Locale>,
+    ) -> Result<Types, ErrorCode> {
+        Request::blocking(Self::get_ticker_types_async(client, asset_class, locale))
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::AssetClass,
+    },
+    &ParameterRequirment {
+        required: false,
+        parameter: Parameter::Locale,
+    },
+];
+
+fn url(base_url: &str, parameters: &Parameters) -> Result<String, ErrorCode> {
+    let url = format!(
+        "{}/v3/reference/tickers/types?{}{}apiKey={}",
+        base_url,
+        if let Some(asset_class) = &parameters.asset_class {
+            format!("asset_class={}&", asset_class.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        if let Some(locale) = &parameters.locale {
+            format!("locale={}&", locale.to_string().to_lowercase())
+        } else {
+            "".to_string()
+        },
+        &parameters.api_key,
+    );
+    Ok(url)
+}
+
+#[test]
+fn test_types_parse() {
+    let data = serde_json::json!({
+        "count": 1,
+        "request_id": "31d59dda-80e5-4721-8496-d0d32a654afe",
+        "results": [
+            {
+                "asset_class": "stocks",
+                "code": "CS",
+                "description": "Common Stock",
+                "locale": "us"
+            }
+        ],
+        "status": "OK"
+    });
+    let types = Types::parse(&data.as_object().unwrap());
+    assert_eq!(types.count.unwrap(), 1);
+    assert_eq!(types.status.unwrap(), "OK");
+    assert_eq!(
+        types.request_id.unwrap(),
+        "31d59dda-80e5-4721-8496-d0d32a654afe"
+    );
+    let types = types.types.unwrap();
+    assert_eq!(types[0].asset_class.clone().unwrap(), "stocks");
+    assert_eq!(types[0].code.clone().unwrap(), "CS");
+    assert_eq!(types[0].description.clone().unwrap(), "Common Stock");
+    assert_eq!(types[0].locale.clone().unwrap(), "us");
+}
+
+#[test]
+fn test_url() {
+    let mut parameters = Parameters::default();
+    parameters.api_key = String::from("apiKey");
+    parameters.asset_class = Some(AssetClass::Options);
+    parameters.locale = Some(Locale::Us);
+    let url = url(PolygonClient::DEFAULT_BASE_URL, &parameters).unwrap();
+    assert_eq!(
+        url,
+        "https://api.polygon.io/v3/reference/tickers/types?asset_class=options&locale=us&apiKey=apiKey"
+    );
+}
@@ -1,3 +1,13 @@
+pub mod black_scholes;
+pub mod chain_analytics;
+pub mod indicators;
+pub mod option_symbol;
+pub mod order_book;
+pub mod pagination;
+pub mod rate_limit;
 pub mod regex_patterns;
+pub mod registry;
 pub mod request;
+pub mod retry;
+pub mod trading_calendar;
 pub mod verification;
@@ -0,0 +1,477 @@
+use crate::data_types::{contract::Contract, greeks::Greeks, option_contract::OptionContract};
+use crate::rest::parameters::ContractType;
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+//European pricing with a continuous dividend yield, American contracts are valued as if European
+//Time is in years, rate, dividend yield and volatility are annualised decimals
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+pub struct BlackScholes {
+    pub contract_type: ContractType,
+    pub spot: f64,
+    pub strike: f64,
+    pub time: f64,
+    pub rate: f64,
+    pub dividend_yield: f64,
+    pub volatility: f64,
+}
+
+//Greeks are scaled the way Polygon quotes them: theta and charm per calendar day,
+//vega, rho, epsilon and vanna per 1% move, vomma per 1% squared and veta per 1% per day
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Valuation {
+    pub price: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub rho: f64,
+    pub epsilon: f64,
+    pub vanna: f64,
+    pub charm: f64,
+    pub vomma: f64,
+    pub veta: f64,
+}
+
+impl BlackScholes {
+    const MIN_VOLATILITY: f64 = 1e-4;
+    const MAX_VOLATILITY: f64 = 5.0;
+    const TOLERANCE: f64 = 1e-10;
+    const MAX_ITERATIONS: usize = 100;
+
+    //Uses Polygon's implied volatility, or the one implied by the quote midpoint when the snapshot has none
+    pub fn from_contract(
+        contract: &Contract,
+        as_of: DateTime<Utc>,
+        rate: f64,
+        dividend_yield: f64,
+    ) -> Option<Self> {
+        let details = contract.details.as_ref()?;
+        let model = Self {
+            contract_type: details.contract_type?,
+            spot: contract.underlying_asset.as_ref()?.price?,
+            strike: details.strike_price?,
+            time: Self::time_to_expiration(details.expiration_date.as_deref()?, as_of)?,
+            rate,
+            dividend_yield,
+            volatility: 0.0,
+        };
+        match contract.implied_volatility {
+            Some(volatility) if volatility > 0.0 => Some(model.with_volatility(volatility)),
+            _ => {
+                let price = Self::market_price(contract)?;
+                model
+                    .implied_volatility(price)
+                    .map(|volatility| model.with_volatility(volatility))
+            }
+        }
+    }
+
+    //Reference contracts carry no market data, so the underlying price and volatility are supplied
+    pub fn from_option_contract(
+        contract: &OptionContract,
+        spot: f64,
+        volatility: f64,
+        as_of: DateTime<Utc>,
+        rate: f64,
+        dividend_yield: f64,
+    ) -> Option<Self> {
+        Some(Self {
+            contract_type: contract.contract_type?,
+            spot,
+            strike: contract.strike_price?,
+            time: Self::time_to_expiration(contract.expiration_date.as_deref()?, as_of)?,
+            rate,
+            dividend_yield,
+            volatility,
+        })
+    }
+
+    //Contracts stop trading at the 16:00 New York close on their expiration date
+    pub fn time_to_expiration(expiration_date: &str, as_of: DateTime<Utc>) -> Option<f64> {
+        let date = NaiveDate::parse_from_str(expiration_date, "%Y-%m-%d").ok()?;
+        let daylight_saving = match (
+            NaiveDate::from_weekday_of_month_opt(date.year(), 3, Weekday::Sun, 2),
+            NaiveDate::from_weekday_of_month_opt(date.year(), 11, Weekday::Sun, 1),
+        ) {
+            (Some(start), Some(end)) => date >= start && date < end,
+            _ => false,
+        };
+        let close = date
+            .and_hms_opt(if daylight_saving { 20 } else { 21 }, 0, 0)?
+            .and_utc();
+        let time = (close - as_of).num_milliseconds() as f64 / (365.0 * 86_400_000.0);
+        if time > 0.0 {
+            Some(time)
+        } else {
+            None
+        }
+    }
+
+    pub fn with_volatility(self, volatility: f64) -> Self {
+        Self { volatility, ..self }
+    }
+
+    pub fn price(&self) -> f64 {
+        let (d1, d2) = self.d();
+        let (spot, strike) = self.discounted();
+        match self.contract_type {
+            ContractType::Put => strike * cdf(-d2) - spot * cdf(-d1),
+            _ => spot * cdf(d1) - strike * cdf(d2),
+        }
+    }
+
+    pub fn valuation(&self) -> Valuation {
+        let (d1, d2) = self.d();
+        let (spot, strike) = self.discounted();
+        let root_time = self.time.sqrt();
+        let density = pdf(d1);
+        let put = matches!(self.contract_type, ContractType::Put);
+        let sign = if put { -1.0 } else { 1.0 };
+        let delta = sign * (-self.dividend_yield * self.time).exp() * cdf(sign * d1);
+        let vega = spot * density * root_time;
+        let theta = -spot * density * self.volatility / (2.0 * root_time)
+            - sign * self.rate * strike * cdf(sign * d2)
+            + sign * self.dividend_yield * spot * cdf(sign * d1);
+        let charm =
+            sign * self.dividend_yield * (-self.dividend_yield * self.time).exp() * cdf(sign * d1)
+                - (-self.dividend_yield * self.time).exp()
+                    * density
+                    * (2.0 * (self.rate - self.dividend_yield) * self.time
+                        - d2 * self.volatility * root_time)
+                    / (2.0 * self.time * self.volatility * root_time);
+        let veta = -vega
+            * (self.dividend_yield
+                + (self.rate - self.dividend_yield) * d1 / (self.volatility * root_time)
+                - (1.0 + d1 * d2) / (2.0 * self.time));
+        Valuation {
+            price: self.price(),
+            delta,
+            gamma: spot * density / (self.spot * self.spot * self.volatility * root_time),
+            theta: theta / 365.0,
+            vega: vega / 100.0,
+            rho: sign * strike * self.time * cdf(sign * d2) / 100.0,
+            epsilon: -sign * spot * self.time * cdf(sign * d1) / 100.0,
+            vanna: -spot / self.spot * density * d2 / self.volatility / 100.0,
+            charm: charm / 365.0,
+            vomma: vega * d1 * d2 / self.volatility / 10_000.0,
+            veta: veta / 36_500.0,
+        }
+    }
+
+    //Fills the snapshot Greeks for contracts Polygon returns none for
+    pub fn greeks(&self) -> Greeks {
+        let valuation = self.valuation();
+        Greeks {
+            delta: Some(valuation.delta),
+            gamma: Some(valuation.gamma),
+            theta: Some(valuation.theta),
+            vega: Some(valuation.vega),
+        }
+    }
+
+    //Newton's method from the current volatility, falling back to Brent's method when vega vanishes or a step leaves the bracket
+    pub fn implied_volatility(&self, price: f64) -> Option<f64> {
+        let (spot, strike) = self.discounted();
+        let (lower, upper) = match self.contract_type {
+            ContractType::Put => ((strike - spot).max(0.0), strike),
+            _ => ((spot - strike).max(0.0), spot),
+        };
+        if !(price > lower && price < upper) {
+            return None;
+        }
+        let error = |volatility: f64| self.with_volatility(volatility).price() - price;
+        let mut volatility = if self.volatility > 0.0 {
+            self.volatility
+        } else {
+            0.3
+        };
+        for _ in 0..Self::MAX_ITERATIONS {
+            let model = self.with_volatility(volatility);
+            let difference = model.price() - price;
+            if difference.abs() < Self::TOLERANCE {
+                return Some(volatility);
+            }
+            let vega = model.valuation().vega * 100.0;
+            if vega < 1e-8 {
+                break;
+            }
+            volatility -= difference / vega;
+            if !(Self::MIN_VOLATILITY..=Self::MAX_VOLATILITY).contains(&volatility) {
+                break;
+            }
+        }
+        brent(error, Self::MIN_VOLATILITY, Self::MAX_VOLATILITY)
+    }
+
+    pub(crate) fn market_price(contract: &Contract) -> Option<f64> {
+        let quote = contract.quote.as_ref();
+        match quote.and_then(|q| q.mid_point) {
+            Some(mid_point) => Some(mid_point),
+            None => match quote.and_then(|q| Some((q.bid?, q.ask?))) {
+                Some((bid, ask)) if bid > 0.0 && ask > 0.0 => Some((bid + ask) / 2.0),
+                _ => contract.trade.as_ref().and_then(|t| t.price),
+            },
+        }
+    }
+
+    fn d(&self) -> (f64, f64) {
+        let deviation = self.volatility * self.time.sqrt();
+        let d1 = ((self.spot / self.strike).ln()
+            + (self.rate - self.dividend_yield + self.volatility * self.volatility / 2.0)
+                * self.time)
+            / deviation;
+        (d1, d1 - deviation)
+    }
+
+    fn discounted(&self) -> (f64, f64) {
+        (
+            self.spot * (-self.dividend_yield * self.time).exp(),
+            self.strike * (-self.rate * self.time).exp(),
+        )
+    }
+}
+
+fn pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+//Hart's double precision approximation as given by West, accurate to about 1e-14
+fn cdf(x: f64) -> f64 {
+    let z = x.abs();
+    let tail = if z > 37.0 {
+        0.0
+    } else {
+        let e = (-z * z / 2.0).exp();
+        if z < 7.07106781186547 {
+            let n = [
+                0.700383064443688,
+                6.37396220353165,
+                33.912866078383,
+                112.079291497871,
+                221.213596169931,
+                220.206867912376,
+            ]
+            .iter()
+            .fold(0.0352624965998911, |n, c| n * z + c);
+            let d = [
+                1.75566716318264,
+                16.064177579207,
+                86.7807322029461,
+                296.564248779674,
+                637.333633378831,
+                793.826512519948,
+                440.413735824752,
+            ]
+            .iter()
+            .fold(0.0883883476483184, |d, c| d * z + c);
+            e * n / d
+        } else {
+            let b = z + 1.0 / (z + 2.0 / (z + 3.0 / (z + 4.0 / (z + 0.65))));
+            e / b / 2.506628274631
+        }
+    };
+    if x > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+//Brent's method on a bracketed root, None when the bracket holds no sign change
+fn brent(f: impl Fn(f64) -> f64, lower: f64, upper: f64) -> Option<f64> {
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa * fb > 0.0 {
+        return None;
+    }
+    let (mut c, mut fc) = (a, fa);
+    let (mut d, mut e) = (b - a, b - a);
+    for _ in 0..BlackScholes::MAX_ITERATIONS {
+        if fb * fc > 0.0 {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tolerance = 2.0 * f64::EPSILON * b.abs() + BlackScholes::TOLERANCE / 2.0;
+        let midpoint = (c - b) / 2.0;
+        if midpoint.abs() <= tolerance || fb == 0.0 {
+            return Some(b);
+        }
+        if e.abs() >= tolerance && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * midpoint * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * midpoint * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            if 2.0 * p < (3.0 * midpoint * q - (tolerance * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = midpoint;
+                e = d;
+            }
+        } else {
+            d = midpoint;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tolerance {
+            d
+        } else {
+            tolerance.copysign(midpoint)
+        };
+        fb = f(b);
+    }
+    Some(b)
+}
+
+#[test]
+fn test_black_scholes() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    let call = BlackScholes {
+        contract_type: ContractType::Call,
+        spot: 100.0,
+        strike: 100.0,
+        time: 1.0,
+        rate: 0.05,
+        dividend_yield: 0.0,
+        volatility: 0.2,
+    };
+    let valuation = call.valuation();
+    assert!(close(valuation.price, 10.450583572185565));
+    assert!(close(valuation.delta, 0.6368306511756191));
+    assert!(close(valuation.gamma, 0.018762017345846895));
+    assert!(close(valuation.theta, -0.01757267820941972));
+    assert!(close(valuation.vega, 0.3752403469169379));
+    assert!(close(valuation.rho, 0.5323248154537634));
+    assert!(close(valuation.epsilon, -0.6368306511756191));
+    assert!(close(valuation.vanna, -0.0028143026018770346));
+    assert!(close(valuation.charm, -0.0001799097553711346));
+    assert!(close(valuation.vomma, 0.0009850059106569623));
+    assert!(close(valuation.veta, 0.0004510594581090589));
+
+    let put = BlackScholes {
+        contract_type: ContractType::Put,
+        spot: 100.0,
+        strike: 110.0,
+        time: 0.5,
+        rate: 0.04,
+        dividend_yield: 0.02,
+        volatility: 0.3,
+    };
+    let valuation = put.valuation();
+    assert!(close(valuation.price, 13.837646611812737));
+    assert!(close(valuation.delta, -0.6102858601765202));
+    assert!(close(valuation.gamma, 0.017820657218606567));
+    assert!(close(valuation.theta, -0.01711018674944494));
+    assert!(close(valuation.vega, 0.2673098582790985));
+    assert!(close(valuation.rho, -0.3743311631473238));
+    assert!(close(valuation.epsilon, 0.3051429300882601));
+    assert!(close(valuation.vanna, 0.006404160863230714));
+    assert!(close(valuation.charm, -0.0006574571439388083));
+    assert!(close(valuation.vomma, 0.0013408216510511386));
+    assert!(close(valuation.veta, 0.0008483573567285755));
+    let greeks = put.greeks();
+    assert!(close(greeks.delta.unwrap(), -0.6102858601765202));
+
+    //Newton converges for near the money contracts, Brent picks up the deep out of the money ones
+    assert!((call.implied_volatility(10.450583572185565).unwrap() - 0.2).abs() < 1e-8);
+    assert!((put.implied_volatility(13.837646611812737).unwrap() - 0.3).abs() < 1e-8);
+    let wing = BlackScholes {
+        strike: 250.0,
+        volatility: 0.9,
+        ..call
+    };
+    let wing_price = wing.price();
+    assert!(
+        (wing
+            .with_volatility(0.1)
+            .implied_volatility(wing_price)
+            .unwrap()
+            - 0.9)
+            .abs()
+            < 1e-6
+    );
+    assert_eq!(call.implied_volatility(0.0), None);
+    assert_eq!(call.implied_volatility(150.0), None);
+}
+
+#[test]
+fn test_black_scholes_from_contract() {
+    use crate::data_types::Parse;
+    use chrono::TimeZone;
+
+    let contract = Contract::parse(
+        serde_json::json!({
+            "details": {
+                "contract_type": "call",
+                "exercise_style": "american",
+                "expiration_date": "2024-01-19",
+                "shares_per_contract": 100,
+                "strike_price": 150,
+                "ticker": "O:AAPL240119C00150000"
+            },
+            "last_quote": {"ask": 42.1, "bid": 41.9, "midpoint": 42.0},
+            "underlying_asset": {"price": 190.0, "ticker": "AAPL"}
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let as_of = Utc.with_ymd_and_hms(2023, 12, 19, 21, 0, 0).unwrap();
+    let time = BlackScholes::time_to_expiration("2024-01-19", as_of).unwrap();
+    assert!((time - 31.0 / 365.0).abs() < 1e-12);
+    assert_eq!(
+        BlackScholes::time_to_expiration(
+            "2024-07-19",
+            Utc.with_ymd_and_hms(2024, 7, 19, 20, 0, 0).unwrap()
+        ),
+        None
+    );
+    let model = BlackScholes::from_contract(&contract, as_of, 0.05, 0.005).unwrap();
+    assert_eq!(model.spot, 190.0);
+    assert_eq!(model.strike, 150.0);
+    assert!((model.price() - 42.0).abs() < 1e-8);
+
+    let with_volatility = Contract {
+        implied_volatility: Some(0.25),
+        ..contract.clone()
+    };
+    let model = BlackScholes::from_contract(&with_volatility, as_of, 0.05, 0.005).unwrap();
+    assert_eq!(model.volatility, 0.25);
+
+    let reference = OptionContract::parse(
+        serde_json::json!({
+            "contract_type": "put",
+            "expiration_date": "2024-01-19",
+            "strike_price": 150,
+            "ticker": "O:AAPL240119P00150000"
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let model =
+        BlackScholes::from_option_contract(&reference, 190.0, 0.25, as_of, 0.05, 0.0).unwrap();
+    assert_eq!(model.contract_type, ContractType::Put);
+    assert!(model.valuation().delta < 0.0);
+}
@@ -0,0 +1,637 @@
+use crate::data_types::contract::Contract;
+use crate::rest::market::snapshot::options_chain::OptionsChain;
+use crate::rest::parameters::{ContractStyle, ContractType};
+use crate::tools::black_scholes::BlackScholes;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+//A chain snapshot grouped by expiration then strike, pricing inputs fill any volatility or delta Polygon left out
+#[derive(Clone, Debug)]
+pub struct ChainAnalytics {
+    pub spot: Option<f64>,
+    pub as_of: DateTime<Utc>,
+    pub rate: f64,
+    pub dividend_yield: f64,
+    pub expirations: Vec<Expiration>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Expiration {
+    pub expiration_date: String,
+    pub time: Option<f64>,
+    pub pairs: Vec<ContractPair>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContractPair {
+    pub strike: f64,
+    pub call: Option<Contract>,
+    pub put: Option<Contract>,
+}
+
+//The volatility of each point is taken from the out of the money side where it has one
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SmilePoint {
+    pub strike: f64,
+    pub moneyness: Option<f64>,
+    pub call_volatility: Option<f64>,
+    pub put_volatility: Option<f64>,
+    pub volatility: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Smile {
+    pub expiration_date: String,
+    pub points: Vec<SmilePoint>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TermStructurePoint {
+    pub expiration_date: String,
+    pub time: f64,
+    pub strike: f64,
+    pub volatility: f64,
+}
+
+//Risk reversal is the 25 delta call less the 25 delta put, butterfly is their average less the ATM volatility
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Skew {
+    pub expiration_date: String,
+    pub atm_volatility: f64,
+    pub call_volatility: f64,
+    pub put_volatility: f64,
+    pub risk_reversal: f64,
+    pub butterfly: f64,
+}
+
+//For European pairs deviation is call less put less the discounted forward, it is zero when parity holds
+//For every other pair it is how far call less put falls outside the American bounds, negative below the lower one
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ParityViolation {
+    pub expiration_date: String,
+    pub strike: f64,
+    pub exercise_style: Option<ContractStyle>,
+    pub call_price: f64,
+    pub put_price: f64,
+    pub deviation: f64,
+}
+
+//Strike at which holders of every open contract would be paid the least at expiration
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MaxPain {
+    pub expiration_date: String,
+    pub strike: f64,
+    pub payout: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OpenInterestStrikes {
+    pub expiration_date: String,
+    pub call: Option<f64>,
+    pub put: Option<f64>,
+    pub total: Option<f64>,
+}
+
+impl ChainAnalytics {
+    const TARGET_DELTA: f64 = 0.25;
+
+    pub fn new(chain: &OptionsChain, as_of: DateTime<Utc>, rate: f64, dividend_yield: f64) -> Self {
+        Self::from_contracts(
+            chain.chain.as_deref().unwrap_or_default(),
+            as_of,
+            rate,
+            dividend_yield,
+        )
+    }
+
+    //Contracts without an expiration, strike or contract type are left out
+    pub fn from_contracts(
+        contracts: &[Contract],
+        as_of: DateTime<Utc>,
+        rate: f64,
+        dividend_yield: f64,
+    ) -> Self {
+        let mut expirations: Vec<Expiration> = Vec::new();
+        for contract in contracts {
+            let details = match contract.details.as_ref() {
+                Some(details) => details,
+                None => continue,
+            };
+            let (expiration_date, strike, contract_type) = match (
+                details.expiration_date.as_ref(),
+                details.strike_price,
+                details.contract_type,
+            ) {
+                (Some(expiration_date), Some(strike), Some(contract_type)) => {
+                    (expiration_date, strike, contract_type)
+                }
+                _ => continue,
+            };
+            let index = match expirations
+                .iter()
+                .position(|e| &e.expiration_date == expiration_date)
+            {
+                Some(index) => index,
+                None => {
+                    expirations.push(Expiration {
+                        expiration_date: expiration_date.clone(),
+                        time: BlackScholes::time_to_expiration(expiration_date, as_of),
+                        pairs: Vec::new(),
+                    });
+                    expirations.len() - 1
+                }
+            };
+            let pairs = &mut expirations[index].pairs;
+            let pair = match pairs.iter().position(|p| p.strike == strike) {
+                Some(index) => &mut pairs[index],
+                None => {
+                    pairs.push(ContractPair {
+                        strike,
+                        call: None,
+                        put: None,
+                    });
+                    pairs.last_mut().unwrap()
+                }
+            };
+            match contract_type {
+                ContractType::Call => pair.call = Some(contract.clone()),
+                ContractType::Put => pair.put = Some(contract.clone()),
+                ContractType::Other => (),
+            }
+        }
+        //ISO dates sort chronologically as strings
+        expirations.sort_by(|a, b| a.expiration_date.cmp(&b.expiration_date));
+        for expiration in expirations.iter_mut() {
+            expiration
+                .pairs
+                .sort_by(|a, b| a.strike.total_cmp(&b.strike));
+        }
+        Self {
+            spot: contracts
+                .iter()
+                .find_map(|c| c.underlying_asset.as_ref().and_then(|u| u.price)),
+            as_of,
+            rate,
+            dividend_yield,
+            expirations,
+        }
+    }
+
+    pub fn expiration(&self, expiration_date: &str) -> Option<&Expiration> {
+        self.expirations
+            .iter()
+            .find(|e| e.expiration_date == expiration_date)
+    }
+
+    pub fn smiles(&self) -> Vec<Smile> {
+        self.expirations.iter().map(|e| self.smile(e)).collect()
+    }
+
+    //ATM is the strike closest to the underlying, averaging the call and put volatility when both exist
+    pub fn term_structure(&self) -> Vec<TermStructurePoint> {
+        self.expirations
+            .iter()
+            .filter_map(|expiration| {
+                let (strike, volatility) = self.atm(expiration)?;
+                Some(TermStructurePoint {
+                    expiration_date: expiration.expiration_date.clone(),
+                    time: expiration.time?,
+                    strike,
+                    volatility,
+                })
+            })
+            .collect()
+    }
+
+    //The 25 delta volatilities are interpolated linearly in delta, expirations that do not bracket it are skipped
+    pub fn skews(&self) -> Vec<Skew> {
+        self.expirations
+            .iter()
+            .filter_map(|expiration| {
+                let (_, atm_volatility) = self.atm(expiration)?;
+                let call_volatility = self.delta_volatility(
+                    expiration.pairs.iter().filter_map(|p| p.call.as_ref()),
+                    Self::TARGET_DELTA,
+                )?;
+                let put_volatility = self.delta_volatility(
+                    expiration.pairs.iter().filter_map(|p| p.put.as_ref()),
+                    -Self::TARGET_DELTA,
+                )?;
+                Some(Skew {
+                    expiration_date: expiration.expiration_date.clone(),
+                    atm_volatility,
+                    call_volatility,
+                    put_volatility,
+                    risk_reversal: call_volatility - put_volatility,
+                    butterfly: (call_volatility + put_volatility) / 2.0 - atm_volatility,
+                })
+            })
+            .collect()
+    }
+
+    //Parity only binds European exercise, American pairs are held to S·e^(-qT) - K <= C - P <= S - K·e^(-rT)
+    //Pairs whose style is unknown or mixed get the bounds too, as a European pair always satisfies them
+    pub fn parity_violations(&self, threshold: f64) -> Vec<ParityViolation> {
+        let spot = match self.spot {
+            Some(spot) => spot,
+            None => return Vec::new(),
+        };
+        let mut violations = Vec::new();
+        for expiration in &self.expirations {
+            let time = match expiration.time {
+                Some(time) => time,
+                None => continue,
+            };
+            for pair in &expiration.pairs {
+                let prices = (
+                    pair.call.as_ref().and_then(BlackScholes::market_price),
+                    pair.put.as_ref().and_then(BlackScholes::market_price),
+                );
+                let (call_price, put_price) = match prices {
+                    (Some(call_price), Some(put_price)) => (call_price, put_price),
+                    _ => continue,
+                };
+                let difference = call_price - put_price;
+                let spot_value = spot * (-self.dividend_yield * time).exp();
+                let strike_value = pair.strike * (-self.rate * time).exp();
+                let style = match (Self::style(&pair.call), Self::style(&pair.put)) {
+                    (Some(call), Some(put)) if call == put => Some(call),
+                    _ => None,
+                };
+                let deviation = match style {
+                    Some(ContractStyle::European) => difference - (spot_value - strike_value),
+                    _ => {
+                        let lower = spot_value - pair.strike;
+                        let upper = spot - strike_value;
+                        if difference < lower {
+                            difference - lower
+                        } else if difference > upper {
+                            difference - upper
+                        } else {
+                            0.0
+                        }
+                    }
+                };
+                if deviation.abs() > threshold {
+                    violations.push(ParityViolation {
+                        expiration_date: expiration.expiration_date.clone(),
+                        strike: pair.strike,
+                        exercise_style: style,
+                        call_price,
+                        put_price,
+                        deviation,
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    fn style(contract: &Option<Contract>) -> Option<ContractStyle> {
+        contract
+            .as_ref()
+            .and_then(|contract| contract.details.as_ref())
+            .and_then(|details| details.contract_style)
+    }
+
+    //Payout is in underlying points per share, every listed strike is a candidate settlement price
+    pub fn max_pain(&self) -> Vec<MaxPain> {
+        self.expirations
+            .iter()
+            .filter_map(|expiration| {
+                expiration
+                    .pairs
+                    .iter()
+                    .map(|candidate| MaxPain {
+                        expiration_date: expiration.expiration_date.clone(),
+                        strike: candidate.strike,
+                        payout: Self::payout(expiration, candidate.strike),
+                    })
+                    .min_by(|a, b| a.payout.total_cmp(&b.payout))
+            })
+            .filter(|max_pain| max_pain.payout.is_finite())
+            .collect()
+    }
+
+    pub fn open_interest_strikes(&self) -> Vec<OpenInterestStrikes> {
+        self.expirations
+            .iter()
+            .map(|expiration| {
+                let calls: Vec<(f64, f64)> = expiration
+                    .pairs
+                    .iter()
+                    .filter_map(|p| Some((p.strike, Self::open_interest(p.call.as_ref()?)?)))
+                    .collect();
+                let puts: Vec<(f64, f64)> = expiration
+                    .pairs
+                    .iter()
+                    .filter_map(|p| Some((p.strike, Self::open_interest(p.put.as_ref()?)?)))
+                    .collect();
+                let total: Vec<(f64, f64)> = calls.iter().chain(puts.iter()).copied().collect();
+                OpenInterestStrikes {
+                    expiration_date: expiration.expiration_date.clone(),
+                    call: weighted(&calls),
+                    put: weighted(&puts),
+                    total: weighted(&total),
+                }
+            })
+            .collect()
+    }
+
+    fn smile(&self, expiration: &Expiration) -> Smile {
+        let points = expiration
+            .pairs
+            .iter()
+            .map(|pair| {
+                let call_volatility = pair.call.as_ref().and_then(|c| self.volatility(c));
+                let put_volatility = pair.put.as_ref().and_then(|p| self.volatility(p));
+                let volatility = match self.spot {
+                    Some(spot) if pair.strike < spot => put_volatility.or(call_volatility),
+                    _ => call_volatility.or(put_volatility),
+                };
+                SmilePoint {
+                    strike: pair.strike,
+                    moneyness: self.spot.map(|spot| pair.strike / spot),
+                    call_volatility,
+                    put_volatility,
+                    volatility,
+                }
+            })
+            .collect();
+        Smile {
+            expiration_date: expiration.expiration_date.clone(),
+            points,
+        }
+    }
+
+    fn atm(&self, expiration: &Expiration) -> Option<(f64, f64)> {
+        let spot = self.spot?;
+        expiration
+            .pairs
+            .iter()
+            .filter_map(|pair| {
+                let volatilities: Vec<f64> = [pair.call.as_ref(), pair.put.as_ref()]
+                    .iter()
+                    .flatten()
+                    .filter_map(|c| self.volatility(c))
+                    .collect();
+                if volatilities.is_empty() {
+                    return None;
+                }
+                let volatility = volatilities.iter().sum::<f64>() / volatilities.len() as f64;
+                Some((pair.strike, volatility))
+            })
+            .min_by(|a, b| (a.0 - spot).abs().total_cmp(&(b.0 - spot).abs()))
+    }
+
+    fn delta_volatility<'a>(
+        &self,
+        contracts: impl Iterator<Item = &'a Contract>,
+        target: f64,
+    ) -> Option<f64> {
+        let mut points: Vec<(f64, f64)> = contracts
+            .filter_map(|c| Some((self.delta(c)?, self.volatility(c)?)))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points.windows(2).find_map(|window| {
+            let ((d0, v0), (d1, v1)) = (window[0], window[1]);
+            if d0 <= target && target <= d1 {
+                if d1 == d0 {
+                    return Some(v0);
+                }
+                Some(v0 + (v1 - v0) * (target - d0) / (d1 - d0))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn volatility(&self, contract: &Contract) -> Option<f64> {
+        match contract.implied_volatility {
+            Some(volatility) if volatility > 0.0 => Some(volatility),
+            _ => self.model(contract).map(|model| model.volatility),
+        }
+    }
+
+    fn delta(&self, contract: &Contract) -> Option<f64> {
+        match contract.greeks.as_ref().and_then(|g| g.delta) {
+            Some(delta) => Some(delta),
+            None => self.model(contract).map(|model| model.valuation().delta),
+        }
+    }
+
+    fn model(&self, contract: &Contract) -> Option<BlackScholes> {
+        BlackScholes::from_contract(contract, self.as_of, self.rate, self.dividend_yield)
+    }
+
+    fn open_interest(contract: &Contract) -> Option<f64> {
+        contract
+            .open_interest
+            .filter(|open_interest| *open_interest > 0)
+            .map(|open_interest| open_interest as f64)
+    }
+
+    fn payout(expiration: &Expiration, settlement: f64) -> f64 {
+        expiration
+            .pairs
+            .iter()
+            .map(|pair| {
+                let call = pair.call.as_ref().and_then(Self::open_interest);
+                let put = pair.put.as_ref().and_then(Self::open_interest);
+                call.unwrap_or_default() * (settlement - pair.strike).max(0.0)
+                    + put.unwrap_or_default() * (pair.strike - settlement).max(0.0)
+            })
+            .sum()
+    }
+}
+
+fn weighted(values: &[(f64, f64)]) -> Option<f64> {
+    let weight: f64 = values.iter().map(|(_, weight)| weight).sum();
+    if weight <= 0.0 {
+        return None;
+    }
+    Some(
+        values
+            .iter()
+            .map(|(value, weight)| value * weight)
+            .sum::<f64>()
+            / weight,
+    )
+}
+
+#[test]
+fn test_chain_analytics() {
+    use crate::data_types::Parse;
+    use chrono::TimeZone;
+
+    let as_of = Utc.with_ymd_and_hms(2024, 1, 2, 16, 0, 0).unwrap();
+    let (spot, rate) = (100.0, 0.05);
+    //Volatility rises away from the money and more steeply on the put side
+    let volatility =
+        |strike: f64| 0.2 + 0.004 * (strike - spot).abs() + 0.006 * (spot - strike).max(0.0);
+    let mut contracts = Vec::new();
+    for (expiration_date, strikes) in [
+        (
+            "2024-03-15",
+            vec![80.0, 85.0, 90.0, 95.0, 100.0, 105.0, 110.0, 115.0, 120.0],
+        ),
+        ("2024-02-16", vec![90.0, 95.0, 100.0, 105.0, 110.0]),
+    ] {
+        let time = BlackScholes::time_to_expiration(expiration_date, as_of).unwrap();
+        for (i, strike) in strikes.into_iter().enumerate() {
+            for contract_type in [ContractType::Call, ContractType::Put] {
+                let model = BlackScholes {
+                    contract_type,
+                    spot,
+                    strike,
+                    time,
+                    rate,
+                    dividend_yield: 0.0,
+                    volatility: volatility(strike),
+                };
+                let valuation = model.valuation();
+                let mut price = valuation.price;
+                if expiration_date == "2024-02-16"
+                    && strike == 105.0
+                    && contract_type == ContractType::Put
+                {
+                    price += 0.5;
+                }
+                let open_interest = match contract_type {
+                    ContractType::Call => 100 + i as i64 * 50,
+                    _ => 500 - i as i64 * 50,
+                };
+                let mut contract = serde_json::json!({
+                    "details": {
+                        "contract_type": contract_type.to_string().to_lowercase(),
+                        "exercise_style": "european",
+                        "expiration_date": expiration_date,
+                        "shares_per_contract": 100,
+                        "strike_price": strike
+                    },
+                    "greeks": {"delta": valuation.delta},
+                    "implied_volatility": volatility(strike),
+                    "last_quote": {"midpoint": price},
+                    "open_interest": open_interest,
+                    "underlying_asset": {"price": spot, "ticker": "SPY"}
+                });
+                //Missing volatility and delta are recovered from the quote
+                if strike == 95.0 {
+                    contract
+                        .as_object_mut()
+                        .unwrap()
+                        .remove("implied_volatility");
+                    contract.as_object_mut().unwrap().remove("greeks");
+                }
+                contracts.push(contract);
+            }
+        }
+    }
+    contracts.push(serde_json::json!({"details": {"contract_type": "call"}}));
+    let chain = OptionsChain::parse(
+        serde_json::json!({"status": "OK", "results": contracts})
+            .as_object()
+            .unwrap(),
+    );
+    let analytics = ChainAnalytics::new(&chain, as_of, rate, 0.0);
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+    assert_eq!(analytics.spot, Some(spot));
+    let dates: Vec<&str> = analytics
+        .expirations
+        .iter()
+        .map(|e| e.expiration_date.as_str())
+        .collect();
+    assert_eq!(dates, vec!["2024-02-16", "2024-03-15"]);
+    let expiration = analytics.expiration("2024-02-16").unwrap();
+    assert_eq!(expiration.pairs.len(), 5);
+    assert_eq!(expiration.pairs[0].strike, 90.0);
+    assert!(expiration
+        .pairs
+        .iter()
+        .all(|p| p.call.is_some() && p.put.is_some()));
+
+    let smiles = analytics.smiles();
+    let points = &smiles[1].points;
+    assert_eq!(points.len(), 9);
+    for point in points {
+        assert!(close(point.volatility.unwrap(), volatility(point.strike)));
+        assert!(close(point.moneyness.unwrap(), point.strike / spot));
+    }
+    assert!(close(points[3].call_volatility.unwrap(), volatility(95.0)));
+    assert!(points[0].volatility > points[8].volatility);
+
+    let term_structure = analytics.term_structure();
+    assert_eq!(term_structure.len(), 2);
+    assert!(term_structure[0].time < term_structure[1].time);
+    assert!(term_structure
+        .iter()
+        .all(|p| p.strike == 100.0 && close(p.volatility, 0.2)));
+
+    let skews = analytics.skews();
+    assert_eq!(skews.len(), 2);
+    for skew in &skews {
+        assert!(skew.risk_reversal < 0.0);
+        assert!(skew.butterfly > 0.0);
+        assert!(close(skew.atm_volatility, 0.2));
+        assert!(close(
+            skew.risk_reversal,
+            skew.call_volatility - skew.put_volatility
+        ));
+    }
+
+    let violations = analytics.parity_violations(0.01);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].strike, 105.0);
+    assert!(close(violations[0].deviation, -0.5));
+    assert_eq!(violations[0].exercise_style, Some(ContractStyle::European));
+
+    //The same chain as American exercise, where the 105 put's premium sits inside the bounds
+    let mut american = analytics.clone();
+    for expiration in american.expirations.iter_mut() {
+        for pair in expiration.pairs.iter_mut() {
+            for contract in pair.call.iter_mut().chain(pair.put.iter_mut()) {
+                contract.details.as_mut().unwrap().contract_style = Some(ContractStyle::American);
+            }
+        }
+    }
+    assert!(american.parity_violations(0.01).is_empty());
+    //A call worth more than S - K·e^(-rT) over its put breaks the upper bound, one worth less than S - K the lower
+    let mid_point = |contract: &mut Option<Contract>, shift: f64| {
+        let quote = contract.as_mut().unwrap().quote.as_mut().unwrap();
+        quote.mid_point = Some(quote.mid_point.unwrap() + shift);
+    };
+    mid_point(&mut american.expirations[1].pairs[2].call, 1.0);
+    mid_point(&mut american.expirations[1].pairs[6].put, 1.5);
+    let violations = american.parity_violations(0.01);
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0].expiration_date, "2024-03-15");
+    assert_eq!(violations[0].strike, 90.0);
+    assert_eq!(violations[0].exercise_style, Some(ContractStyle::American));
+    assert!(violations[0].deviation > 0.0);
+    assert_eq!(violations[1].strike, 110.0);
+    assert!(violations[1].deviation < 0.0);
+    for contract in american.expirations[1].pairs[2].put.iter_mut() {
+        contract.details.as_mut().unwrap().contract_style = None;
+    }
+    let violations = american.parity_violations(0.01);
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0].exercise_style, None);
+
+    let max_pain = analytics.max_pain();
+    assert_eq!(max_pain.len(), 2);
+    assert_eq!(max_pain[0].strike, 105.0);
+    assert!(close(max_pain[0].payout, 5500.0));
+
+    let strikes = analytics.open_interest_strikes();
+    assert!(close(strikes[0].call.unwrap(), 102.5));
+    assert!(close(strikes[0].put.unwrap(), 98.75));
+    assert!(close(strikes[0].total.unwrap(), 100.0));
+    let serialized = serde_json::to_string(&analytics.skews()).unwrap();
+    assert!(serialized.contains("risk_reversal"));
+
+    let empty = ChainAnalytics::from_contracts(&[], as_of, rate, 0.0);
+    assert!(empty.expirations.is_empty());
+    assert!(empty.term_structure().is_empty());
+    assert!(empty.parity_violations(0.0).is_empty());
+}
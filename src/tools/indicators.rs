@@ -0,0 +1,669 @@
+use crate::data_types::{
+    bar::Bar, bollinger_band::BollingerBand, macd::MACD, moving_average::MovingAverage,
+    relative_strength::RelativeStrength, stochastic::Stochastic,
+};
+use crate::rest::parameters::SeriesType;
+
+pub mod incremental;
+
+//Computes the /v1/indicators values locally from get_aggregates bars, results are oldest first
+//Bars are sorted by timestamp and any bar missing the fields an indicator needs is skipped
+//Exponential and Wilder averages are seeded with the simple average of their first window
+pub struct Indicators {}
+
+fn series_value(bar: &Bar, series_type: SeriesType) -> Option<f64> {
+    match series_type {
+        SeriesType::Open => bar.open,
+        SeriesType::High => bar.high,
+        SeriesType::Low => bar.low,
+        SeriesType::Close => bar.close,
+    }
+}
+
+struct Candle {
+    timestamp: i64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: Option<f64>,
+    volume_weighted: Option<f64>,
+}
+
+impl Indicators {
+    pub fn simple_moving_average(
+        bars: &[Bar],
+        window: usize,
+        series_type: SeriesType,
+    ) -> Vec<MovingAverage> {
+        let (timestamps, values) = Self::series(bars, series_type);
+        Self::moving_averages(&timestamps, Self::sma(&values, window))
+    }
+
+    pub fn exponential_moving_average(
+        bars: &[Bar],
+        window: usize,
+        series_type: SeriesType,
+    ) -> Vec<MovingAverage> {
+        let (timestamps, values) = Self::series(bars, series_type);
+        Self::moving_averages(&timestamps, Self::ema(&values, window))
+    }
+
+    pub fn relative_strength_index(
+        bars: &[Bar],
+        window: usize,
+        series_type: SeriesType,
+    ) -> Vec<RelativeStrength> {
+        let (timestamps, values) = Self::series(bars, series_type);
+        timestamps
+            .iter()
+            .zip(Self::rsi(&values, window))
+            .filter_map(|(timestamp, value)| {
+                value.map(|value| RelativeStrength {
+                    timestamp: Some(*timestamp),
+                    value: Some(value),
+                })
+            })
+            .collect()
+    }
+
+    pub fn moving_average_convergence_divergence(
+        bars: &[Bar],
+        short_window: usize,
+        long_window: usize,
+        signal_window: usize,
+        series_type: SeriesType,
+    ) -> Vec<MACD> {
+        let (timestamps, values) = Self::series(bars, series_type);
+        let short = Self::ema(&values, short_window);
+        let long = Self::ema(&values, long_window);
+        let line: Vec<(i64, f64)> = timestamps
+            .iter()
+            .zip(short.iter().zip(long.iter()))
+            .filter_map(|(timestamp, averages)| match averages {
+                (Some(short), Some(long)) => Some((*timestamp, short - long)),
+                _ => None,
+            })
+            .collect();
+        let macd: Vec<f64> = line.iter().map(|(_, value)| *value).collect();
+        line.iter()
+            .zip(Self::ema(&macd, signal_window))
+            .filter_map(|((timestamp, value), signal)| {
+                signal.map(|signal| MACD {
+                    histogram: Some(value - signal),
+                    signal: Some(signal),
+                    timestamp: Some(*timestamp),
+                    value: Some(*value),
+                })
+            })
+            .collect()
+    }
+
+    //Bands sit multiplier population standard deviations either side of the simple moving average
+    pub fn bollinger_bands(
+        bars: &[Bar],
+        window: usize,
+        multiplier: f64,
+        series_type: SeriesType,
+    ) -> Vec<BollingerBand> {
+        let (timestamps, values) = Self::series(bars, series_type);
+        Self::sma(&values, window)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, middle)| {
+                let middle = middle?;
+                let variance = values[i + 1 - window..=i]
+                    .iter()
+                    .map(|value| (value - middle).powi(2))
+                    .sum::<f64>()
+                    / window as f64;
+                let deviation = multiplier * variance.sqrt();
+                Some(BollingerBand {
+                    lower: Some(middle - deviation),
+                    middle: Some(middle),
+                    timestamp: Some(timestamps[i]),
+                    upper: Some(middle + deviation),
+                })
+            })
+            .collect()
+    }
+
+    //Wilder smoothed true range, the first bar only provides the previous close
+    pub fn average_true_range(bars: &[Bar], window: usize) -> Vec<MovingAverage> {
+        let candles = Self::candles(bars);
+        let (timestamps, ranges): (Vec<i64>, Vec<f64>) = candles
+            .windows(2)
+            .map(|pair| {
+                let (previous, candle) = (&pair[0], &pair[1]);
+                let range = (candle.high - candle.low)
+                    .max((candle.high - previous.close).abs())
+                    .max((candle.low - previous.close).abs());
+                (candle.timestamp, range)
+            })
+            .unzip();
+        Self::moving_averages(&timestamps, Self::wilder(&ranges, window))
+    }
+
+    //%K is where the close sits in the k_window high-low range, %D is its d_window simple average
+    pub fn stochastic(bars: &[Bar], k_window: usize, d_window: usize) -> Vec<Stochastic> {
+        let candles = Self::candles(bars);
+        if k_window == 0 {
+            return Vec::new();
+        }
+        let k: Vec<(i64, f64)> = candles
+            .windows(k_window)
+            .map(|window| {
+                let candle = &window[window.len() - 1];
+                let high = window.iter().map(|c| c.high).fold(f64::MIN, f64::max);
+                let low = window.iter().map(|c| c.low).fold(f64::MAX, f64::min);
+                let k = if high > low {
+                    100.0 * (candle.close - low) / (high - low)
+                } else {
+                    0.0
+                };
+                (candle.timestamp, k)
+            })
+            .collect();
+        let values: Vec<f64> = k.iter().map(|(_, k)| *k).collect();
+        k.iter()
+            .zip(Self::sma(&values, d_window))
+            .filter_map(|((timestamp, k), d)| {
+                d.map(|d| Stochastic {
+                    d: Some(d),
+                    k: Some(*k),
+                    timestamp: Some(*timestamp),
+                })
+            })
+            .collect()
+    }
+
+    //Cumulative over every bar given, pass a single session for a daily VWAP
+    //Each bar is priced at its own VWAP where Polygon provides one, otherwise at its typical price
+    pub fn volume_weighted_average_price(bars: &[Bar]) -> Vec<MovingAverage> {
+        let mut value = 0.0;
+        let mut volume = 0.0;
+        Self::candles(bars)
+            .iter()
+            .filter_map(|candle| {
+                let price = candle
+                    .volume_weighted
+                    .unwrap_or((candle.high + candle.low + candle.close) / 3.0);
+                value += price * candle.volume.unwrap_or_default();
+                volume += candle.volume.unwrap_or_default();
+                if volume > 0.0 {
+                    Some(MovingAverage {
+                        timestamp: Some(candle.timestamp),
+                        value: Some(value / volume),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn series(bars: &[Bar], series_type: SeriesType) -> (Vec<i64>, Vec<f64>) {
+        let mut series: Vec<(i64, f64)> = bars
+            .iter()
+            .filter_map(|bar| Some((bar.timestamp?, series_value(bar, series_type)?)))
+            .collect();
+        series.sort_by_key(|(timestamp, _)| *timestamp);
+        series.into_iter().unzip()
+    }
+
+    fn candles(bars: &[Bar]) -> Vec<Candle> {
+        let mut candles: Vec<Candle> = bars
+            .iter()
+            .filter_map(|bar| {
+                Some(Candle {
+                    timestamp: bar.timestamp?,
+                    high: bar.high?,
+                    low: bar.low?,
+                    close: bar.close?,
+                    volume: bar.volume,
+                    volume_weighted: bar.volume_weighted,
+                })
+            })
+            .collect();
+        candles.sort_by_key(|candle| candle.timestamp);
+        candles
+    }
+
+    fn moving_averages(timestamps: &[i64], values: Vec<Option<f64>>) -> Vec<MovingAverage> {
+        timestamps
+            .iter()
+            .zip(values)
+            .filter_map(|(timestamp, value)| {
+                value.map(|value| MovingAverage {
+                    timestamp: Some(*timestamp),
+                    value: Some(value),
+                })
+            })
+            .collect()
+    }
+
+    //Each helper returns one entry per value, None until the window has filled
+    fn sma(values: &[f64], window: usize) -> Vec<Option<f64>> {
+        let mut sum = 0.0;
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                sum += value;
+                if i >= window {
+                    sum -= values[i - window];
+                }
+                if window > 0 && i + 1 >= window {
+                    Some(sum / window as f64)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn ema(values: &[f64], window: usize) -> Vec<Option<f64>> {
+        Self::smooth(values, window, 2.0 / (window as f64 + 1.0))
+    }
+
+    fn wilder(values: &[f64], window: usize) -> Vec<Option<f64>> {
+        Self::smooth(values, window, 1.0 / window as f64)
+    }
+
+    fn smooth(values: &[f64], window: usize, alpha: f64) -> Vec<Option<f64>> {
+        let mut average: Option<f64> = None;
+        Self::sma(values, window)
+            .into_iter()
+            .zip(values)
+            .map(|(seed, value)| {
+                average = match average {
+                    Some(previous) => Some(previous + alpha * (value - previous)),
+                    None => seed,
+                };
+                average
+            })
+            .collect()
+    }
+
+    fn rsi(values: &[f64], window: usize) -> Vec<Option<f64>> {
+        let changes: Vec<f64> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let gains: Vec<f64> = changes.iter().map(|change| change.max(0.0)).collect();
+        let losses: Vec<f64> = changes.iter().map(|change| (-change).max(0.0)).collect();
+        let mut rsi = vec![None];
+        for (gain, loss) in Self::wilder(&gains, window)
+            .into_iter()
+            .zip(Self::wilder(&losses, window))
+        {
+            //A flat series has neither gains nor losses and sits at the midpoint
+            rsi.push(match (gain, loss) {
+                (Some(0.0), Some(0.0)) => Some(50.0),
+                (Some(_), Some(0.0)) => Some(100.0),
+                (Some(gain), Some(loss)) => Some(100.0 - 100.0 / (1.0 + gain / loss)),
+                _ => None,
+            });
+        }
+        rsi.truncate(values.len());
+        rsi
+    }
+}
+
+#[test]
+fn test_indicators() {
+    use crate::data_types::Parse;
+
+    let bars = serde_json::json!([
+        {"c": 150.0, "h": 150.9, "l": 148.9, "o": 149.7, "t": 1672722000000i64, "v": 1000, "vw": 149.9, "n": 100},
+        {"c": 151.2, "h": 152.1, "l": 150.1, "o": 150.9, "t": 1672808400000i64, "v": 1010, "vw": 151.1, "n": 100},
+        {"c": 149.8, "h": 150.7, "l": 148.7, "o": 149.5, "t": 1672894800000i64, "v": 1020, "vw": 149.7, "n": 100},
+        {"c": 152.4, "h": 153.3, "l": 151.3, "o": 152.1, "t": 1672981200000i64, "v": 1030, "vw": 152.3, "n": 100},
+        {"c": 153.1, "h": 154.0, "l": 152.0, "o": 152.8, "t": 1673067600000i64, "v": 1040, "vw": 153.0, "n": 100},
+        {"c": 152.0, "h": 152.9, "l": 150.9, "o": 151.7, "t": 1673154000000i64, "v": 1050, "vw": 151.9, "n": 100},
+        {"c": 154.3, "h": 155.2, "l": 153.2, "o": 154.0, "t": 1673240400000i64, "v": 1060, "vw": 154.2, "n": 100},
+        {"c": 155.0, "h": 155.9, "l": 153.9, "o": 154.7, "t": 1673326800000i64, "v": 1070, "vw": 154.9, "n": 100},
+        {"c": 153.6, "h": 154.5, "l": 152.5, "o": 153.3, "t": 1673413200000i64, "v": 1080, "vw": 153.5, "n": 100},
+        {"c": 156.2, "h": 157.1, "l": 155.1, "o": 155.9, "t": 1673499600000i64, "v": 1090, "vw": 156.1, "n": 100},
+        {"c": 157.1, "h": 158.0, "l": 156.0, "o": 156.8, "t": 1673586000000i64, "v": 1100, "vw": 157.0, "n": 100},
+        {"c": 156.4, "h": 157.3, "l": 155.3, "o": 156.1, "t": 1673672400000i64, "v": 1110, "vw": 156.3, "n": 100}
+    ]);
+    //Served newest first, the indicators sort them back into order
+    let bars: Vec<Bar> = bars
+        .as_array()
+        .unwrap()
+        .iter()
+        .rev()
+        .map(|bar| Bar::parse(bar.as_object().unwrap()))
+        .collect();
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-8;
+
+    //Reference values below were worked out separately from the closes above, window 3 and a 3/5/2 MACD
+    let sma = Indicators::simple_moving_average(&bars, 3, SeriesType::Close);
+    let expected = [
+        150.3333333333,
+        151.1333333333,
+        151.7666666667,
+        152.5,
+        153.1333333333,
+        153.7666666667,
+        154.3,
+        154.9333333333,
+        155.6333333333,
+        156.5666666667,
+    ];
+    assert_eq!(sma.len(), expected.len());
+    assert_eq!(sma[0].timestamp.unwrap(), 1672894800000);
+    for (actual, expected) in sma.iter().zip(expected) {
+        assert!(close(actual.value.unwrap(), expected));
+    }
+
+    let ema = Indicators::exponential_moving_average(&bars, 3, SeriesType::Close);
+    let expected = [
+        150.3333333333,
+        151.3666666667,
+        152.2333333333,
+        152.1166666667,
+        153.2083333333,
+        154.1041666667,
+        153.8520833333,
+        155.0260416667,
+        156.0630208333,
+        156.2315104167,
+    ];
+    assert_eq!(ema.len(), expected.len());
+    for (actual, expected) in ema.iter().zip(expected) {
+        assert!(close(actual.value.unwrap(), expected));
+    }
+
+    let rsi = Indicators::relative_strength_index(&bars, 3, SeriesType::Close);
+    let expected = [
+        73.0769230769,
+        77.6,
+        55.5873925501,
+        76.4973464746,
+        80.655226209,
+        52.6905829596,
+        75.9344704236,
+        80.8259069177,
+        65.3333611569,
+    ];
+    assert_eq!(rsi.len(), expected.len());
+    assert_eq!(rsi[0].timestamp.unwrap(), 1672981200000);
+    for (actual, expected) in rsi.iter().zip(expected) {
+        assert!(close(actual.value.unwrap(), expected));
+    }
+
+    let macd = Indicators::moving_average_convergence_divergence(&bars, 3, 5, 2, SeriesType::Close);
+    let expected = [
+        (0.5833333333, 0.7583333333, -0.175),
+        (0.7527777778, 0.7546296296, -0.0018518519),
+        (0.800462963, 0.7851851852, 0.0152777778),
+        (0.4496141975, 0.5614711934, -0.1118569959),
+        (0.6910622428, 0.6478652263, 0.0431970165),
+        (0.8063678841, 0.7535336648, 0.0528342193),
+        (0.5937417838, 0.6470057442, -0.0532639603),
+    ];
+    assert_eq!(macd.len(), expected.len());
+    assert_eq!(macd[0].timestamp.unwrap(), 1673154000000);
+    for (actual, (value, signal, histogram)) in macd.iter().zip(expected) {
+        assert!(close(actual.value.unwrap(), value));
+        assert!(close(actual.signal.unwrap(), signal));
+        assert!(close(actual.histogram.unwrap(), histogram));
+    }
+
+    let bands = Indicators::bollinger_bands(&bars, 3, 2.0, SeriesType::Close);
+    assert_eq!(bands.len(), 10);
+    let last = bands.last().unwrap();
+    assert!(close(last.lower.unwrap(), 155.7949442064806));
+    assert!(close(last.middle.unwrap(), 156.56666666666663));
+    assert!(close(last.upper.unwrap(), 157.33838912685266));
+
+    let atr = Indicators::average_true_range(&bars, 3);
+    assert_eq!(atr.len(), 9);
+    assert!(close(
+        atr.last().unwrap().value.unwrap(),
+        2.3574455113549764
+    ));
+
+    let stochastic = Indicators::stochastic(&bars, 3, 3);
+    assert_eq!(stochastic.len(), 8);
+    assert!(close(
+        stochastic.last().unwrap().k.unwrap(),
+        44.827586206896854
+    ));
+    assert!(close(
+        stochastic.last().unwrap().d.unwrap(),
+        69.63291081731863
+    ));
+
+    let vwap = Indicators::volume_weighted_average_price(&bars);
+    assert_eq!(vwap.len(), 12);
+    assert!(close(
+        vwap.last().unwrap().value.unwrap(),
+        153.39699842022117
+    ));
+
+    assert!(Indicators::simple_moving_average(&bars, 0, SeriesType::Close).is_empty());
+    assert!(Indicators::relative_strength_index(&bars[..2], 3, SeriesType::Close).is_empty());
+
+    let flat: Vec<Bar> = bars
+        .iter()
+        .map(|bar| Bar {
+            close: Some(150.0),
+            ..bar.clone()
+        })
+        .collect();
+    let rsi = Indicators::relative_strength_index(&flat, 3, SeriesType::Close);
+    assert_eq!(rsi.len(), 9);
+    assert!(rsi.iter().all(|rsi| rsi.value == Some(50.0)));
+    let mut rising = flat.clone();
+    //The bars are held newest first, so this is a single gain on the last day
+    rising[0].close = Some(151.0);
+    let rsi = Indicators::relative_strength_index(&rising, 3, SeriesType::Close);
+    assert_eq!(rsi.last().unwrap().value, Some(100.0));
+}
+
+//Raw responses from record_indicator_fixtures, all five cover the same bars
+#[cfg(test)]
+const PARITY_FIXTURES: &str = "tests/fixtures/indicators";
+
+//Polygon's values are checked at every timestamp both sides share, exponential ones only once twenty of their
+//longest window have passed, by then any difference in how the averages were seeded has decayed below 1e-8
+#[test]
+#[ignore = "needs the responses recorded by record_indicator_fixtures in tests/fixtures/indicators"]
+fn test_indicators_parity() {
+    use crate::data_types::Parse;
+    use crate::rest::market::{
+        aggregates::Aggregates,
+        technical_indicators::{
+            exponential_moving_average::ExponentialMovingAverage,
+            moving_average_converge_divergence::MovingAverageConvergenceDivergence,
+            relative_strength_index::RelativeStrengthIndex,
+            simple_moving_average::SimpleMovingAverage,
+        },
+    };
+    use std::collections::HashMap;
+
+    let fixture = |name: &str| -> serde_json::Map<String, serde_json::Value> {
+        let path = format!(
+            "{}/{}/{}.json",
+            env!("CARGO_MANIFEST_DIR"),
+            PARITY_FIXTURES,
+            name
+        );
+        let text = std::fs::read_to_string(&path).expect(&path);
+        serde_json::from_str(&text).unwrap()
+    };
+    let bars = Aggregates::parse(&fixture("aggregates")).results.unwrap();
+    let warm = |window: usize| bars[20 * window].timestamp.unwrap();
+    let compare = |name: &str,
+                   request_id: Option<String>,
+                   expected: Vec<(Option<i64>, Option<f64>)>,
+                   actual: Vec<(Option<i64>, Option<f64>)>,
+                   cutoff: i64| {
+        let actual: HashMap<i64, f64> = actual
+            .into_iter()
+            .filter_map(|(timestamp, value)| Some((timestamp?, value?)))
+            .collect();
+        let mut compared = 0;
+        for (timestamp, value) in expected {
+            let (timestamp, value) = match (timestamp, value) {
+                (Some(timestamp), Some(value)) if timestamp >= cutoff => (timestamp, value),
+                _ => continue,
+            };
+            let local = match actual.get(&timestamp) {
+                Some(local) => local,
+                None => continue,
+            };
+            assert!(
+                (local - value).abs() < 1e-6,
+                "{} at {} is {} locally and {} from Polygon, request {:?}",
+                name,
+                timestamp,
+                local,
+                value,
+                request_id
+            );
+            compared += 1;
+        }
+        assert!(
+            compared >= 100,
+            "{} only compared {} values",
+            name,
+            compared
+        );
+    };
+    let values = |averages: Vec<MovingAverage>| {
+        averages
+            .into_iter()
+            .map(|average| (average.timestamp, average.value))
+            .collect::<Vec<_>>()
+    };
+
+    let sma = SimpleMovingAverage::parse(&fixture("sma"));
+    compare(
+        "SMA",
+        sma.request_id,
+        values(sma.moving_average.unwrap()),
+        values(Indicators::simple_moving_average(
+            &bars,
+            20,
+            SeriesType::Close,
+        )),
+        i64::MIN,
+    );
+
+    let ema = ExponentialMovingAverage::parse(&fixture("ema"));
+    compare(
+        "EMA",
+        ema.request_id,
+        values(ema.moving_average.unwrap()),
+        values(Indicators::exponential_moving_average(
+            &bars,
+            20,
+            SeriesType::Close,
+        )),
+        warm(20),
+    );
+
+    let rsi = RelativeStrengthIndex::parse(&fixture("rsi"));
+    compare(
+        "RSI",
+        rsi.request_id,
+        rsi.relative_strength
+            .unwrap()
+            .into_iter()
+            .map(|rsi| (rsi.timestamp, rsi.value))
+            .collect(),
+        Indicators::relative_strength_index(&bars, 14, SeriesType::Close)
+            .into_iter()
+            .map(|rsi| (rsi.timestamp, rsi.value))
+            .collect(),
+        warm(14),
+    );
+
+    let macd = MovingAverageConvergenceDivergence::parse(&fixture("macd"));
+    let local =
+        Indicators::moving_average_convergence_divergence(&bars, 12, 26, 9, SeriesType::Close);
+    let expected = macd.macd.unwrap();
+    for (name, field) in [
+        (
+            "MACD",
+            (|macd: &MACD| macd.value) as fn(&MACD) -> Option<f64>,
+        ),
+        ("MACD signal", |macd: &MACD| macd.signal),
+        ("MACD histogram", |macd: &MACD| macd.histogram),
+    ] {
+        compare(
+            name,
+            macd.request_id.clone(),
+            expected.iter().map(|m| (m.timestamp, field(m))).collect(),
+            local.iter().map(|m| (m.timestamp, field(m))).collect(),
+            warm(26),
+        );
+    }
+}
+
+//Captures the fixtures for test_indicators_parity, run with POLYGON_API_KEY set and --ignored then check them in
+#[tokio::test]
+#[ignore = "calls the live API and needs POLYGON_API_KEY"]
+async fn record_indicator_fixtures() {
+    use crate::client::PolygonClient;
+
+    let api_key = std::env::var("POLYGON_API_KEY").expect("POLYGON_API_KEY is not set");
+    let directory = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), PARITY_FIXTURES);
+    std::fs::create_dir_all(&directory).unwrap();
+    //Three years of daily bars give MACD its twenty long windows with a few months left to compare
+    let (ticker, from, to) = ("AAPL", "2021-01-01", "2023-12-31");
+    let range = format!(
+        "timestamp.gte={}&timestamp.lte={}&timespan=day&adjusted=true&series_type=close&order=asc&limit=5000",
+        from, to
+    );
+    for (name, path) in [
+        (
+            "aggregates",
+            format!(
+                "/v2/aggs/ticker/{}/range/1/day/{}/{}?adjusted=true&sort=asc&limit=50000",
+                ticker, from, to
+            ),
+        ),
+        (
+            "sma",
+            format!("/v1/indicators/sma/{}?{}&window=20", ticker, range),
+        ),
+        (
+            "ema",
+            format!("/v1/indicators/ema/{}?{}&window=20", ticker, range),
+        ),
+        (
+            "rsi",
+            format!("/v1/indicators/rsi/{}?{}&window=14", ticker, range),
+        ),
+        (
+            "macd",
+            format!(
+                "/v1/indicators/macd/{}?{}&short_window=12&long_window=26&signal_window=9",
+                ticker, range
+            ),
+        ),
+    ] {
+        let url = format!(
+            "{}{}&apiKey={}",
+            PolygonClient::DEFAULT_BASE_URL,
+            path,
+            api_key
+        );
+        let body = reqwest::get(url)
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| e.without_url())
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        let response: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(
+            response["next_url"].is_null(),
+            "{} did not fit one page",
+            name
+        );
+        std::fs::write(format!("{}/{}.json", directory, name), body).unwrap();
+    }
+}
@@ -0,0 +1,468 @@
+use super::series_value;
+use crate::data_types::{
+    bar::Bar, macd::MACD, moving_average::MovingAverage, relative_strength::RelativeStrength,
+};
+use crate::rest::parameters::SeriesType;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+//Streaming counterparts of Indicators, each update matches the batch value for the same bars
+//Bars must arrive oldest first, a bar missing the value an indicator needs leaves it unchanged
+pub trait Indicator {
+    type Output;
+
+    fn update(&mut self, bar: &Bar) -> Option<Self::Output>;
+
+    fn value(&self) -> Option<Self::Output>;
+}
+
+//Priced the same way as Indicators::volume_weighted_average_price, bars without a full candle are skipped
+fn bar_price(bar: &Bar) -> Option<f64> {
+    let typical = (bar.high? + bar.low? + bar.close?) / 3.0;
+    Some(bar.volume_weighted.unwrap_or(typical))
+}
+
+//Seeded with the simple average of the first window, then smoothed by alpha
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct Smoothed {
+    window: usize,
+    alpha: f64,
+    count: usize,
+    sum: f64,
+    average: Option<f64>,
+}
+
+impl Smoothed {
+    fn new(window: usize, alpha: f64) -> Self {
+        Self {
+            window,
+            alpha,
+            count: 0,
+            sum: 0.0,
+            average: None,
+        }
+    }
+
+    fn exponential(window: usize) -> Self {
+        Self::new(window, 2.0 / (window as f64 + 1.0))
+    }
+
+    fn wilder(window: usize) -> Self {
+        Self::new(window, 1.0 / window as f64)
+    }
+
+    fn update(&mut self, value: f64) -> Option<f64> {
+        match self.average {
+            Some(previous) => self.average = Some(previous + self.alpha * (value - previous)),
+            None if self.window > 0 => {
+                self.count += 1;
+                self.sum += value;
+                if self.count == self.window {
+                    self.average = Some(self.sum / self.window as f64);
+                }
+            }
+            None => (),
+        }
+        self.average
+    }
+}
+
+//The running sum is rebuilt from the window each time it turns over, so rounding from removals cannot build up
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SmaIndicator {
+    window: usize,
+    series_type: SeriesType,
+    values: VecDeque<f64>,
+    sum: f64,
+    removed: usize,
+    timestamp: Option<i64>,
+}
+
+impl SmaIndicator {
+    pub fn new(window: usize, series_type: SeriesType) -> Self {
+        Self {
+            window,
+            series_type,
+            values: VecDeque::new(),
+            sum: 0.0,
+            removed: 0,
+            timestamp: None,
+        }
+    }
+}
+
+impl Indicator for SmaIndicator {
+    type Output = MovingAverage;
+
+    fn update(&mut self, bar: &Bar) -> Option<MovingAverage> {
+        if let Some(value) = series_value(bar, self.series_type) {
+            self.values.push_back(value);
+            self.sum += value;
+            if self.values.len() > self.window {
+                self.sum -= self.values.pop_front().unwrap_or_default();
+                self.removed += 1;
+                if self.removed >= self.window {
+                    self.sum = self.values.iter().sum();
+                    self.removed = 0;
+                }
+            }
+            self.timestamp = bar.timestamp;
+        }
+        self.value()
+    }
+
+    fn value(&self) -> Option<MovingAverage> {
+        if self.window == 0 || self.values.len() < self.window {
+            return None;
+        }
+        Some(MovingAverage {
+            timestamp: self.timestamp,
+            value: Some(self.sum / self.window as f64),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EmaIndicator {
+    series_type: SeriesType,
+    average: Smoothed,
+    timestamp: Option<i64>,
+}
+
+impl EmaIndicator {
+    pub fn new(window: usize, series_type: SeriesType) -> Self {
+        Self {
+            series_type,
+            average: Smoothed::exponential(window),
+            timestamp: None,
+        }
+    }
+}
+
+impl Indicator for EmaIndicator {
+    type Output = MovingAverage;
+
+    fn update(&mut self, bar: &Bar) -> Option<MovingAverage> {
+        if let Some(value) = series_value(bar, self.series_type) {
+            self.average.update(value);
+            self.timestamp = bar.timestamp;
+        }
+        self.value()
+    }
+
+    fn value(&self) -> Option<MovingAverage> {
+        self.average.average.map(|value| MovingAverage {
+            timestamp: self.timestamp,
+            value: Some(value),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RsiIndicator {
+    series_type: SeriesType,
+    previous: Option<f64>,
+    gains: Smoothed,
+    losses: Smoothed,
+    timestamp: Option<i64>,
+}
+
+impl RsiIndicator {
+    pub fn new(window: usize, series_type: SeriesType) -> Self {
+        Self {
+            series_type,
+            previous: None,
+            gains: Smoothed::wilder(window),
+            losses: Smoothed::wilder(window),
+            timestamp: None,
+        }
+    }
+}
+
+impl Indicator for RsiIndicator {
+    type Output = RelativeStrength;
+
+    fn update(&mut self, bar: &Bar) -> Option<RelativeStrength> {
+        if let Some(value) = series_value(bar, self.series_type) {
+            if let Some(previous) = self.previous {
+                let change = value - previous;
+                self.gains.update(change.max(0.0));
+                self.losses.update((-change).max(0.0));
+            }
+            self.previous = Some(value);
+            self.timestamp = bar.timestamp;
+        }
+        self.value()
+    }
+
+    fn value(&self) -> Option<RelativeStrength> {
+        let value = match (self.gains.average, self.losses.average) {
+            (Some(0.0), Some(0.0)) => 50.0,
+            (Some(_), Some(0.0)) => 100.0,
+            (Some(gain), Some(loss)) => 100.0 - 100.0 / (1.0 + gain / loss),
+            _ => return None,
+        };
+        Some(RelativeStrength {
+            timestamp: self.timestamp,
+            value: Some(value),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MacdIndicator {
+    series_type: SeriesType,
+    short: Smoothed,
+    long: Smoothed,
+    signal: Smoothed,
+    macd: Option<MACD>,
+}
+
+impl MacdIndicator {
+    pub fn new(
+        short_window: usize,
+        long_window: usize,
+        signal_window: usize,
+        series_type: SeriesType,
+    ) -> Self {
+        Self {
+            series_type,
+            short: Smoothed::exponential(short_window),
+            long: Smoothed::exponential(long_window),
+            signal: Smoothed::exponential(signal_window),
+            macd: None,
+        }
+    }
+}
+
+impl Indicator for MacdIndicator {
+    type Output = MACD;
+
+    fn update(&mut self, bar: &Bar) -> Option<MACD> {
+        if let Some(value) = series_value(bar, self.series_type) {
+            let short = self.short.update(value);
+            let long = self.long.update(value);
+            if let (Some(short), Some(long)) = (short, long) {
+                let value = short - long;
+                if let Some(signal) = self.signal.update(value) {
+                    self.macd = Some(MACD {
+                        histogram: Some(value - signal),
+                        signal: Some(signal),
+                        timestamp: bar.timestamp,
+                        value: Some(value),
+                    });
+                }
+            }
+        }
+        self.value()
+    }
+
+    fn value(&self) -> Option<MACD> {
+        self.macd.clone()
+    }
+}
+
+//Rolling over the last window bars rather than the whole session, the sums are rebuilt the same way as SmaIndicator's
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VwapIndicator {
+    window: usize,
+    bars: VecDeque<(f64, f64)>,
+    value: f64,
+    volume: f64,
+    removed: usize,
+    timestamp: Option<i64>,
+}
+
+impl VwapIndicator {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            bars: VecDeque::new(),
+            value: 0.0,
+            volume: 0.0,
+            removed: 0,
+            timestamp: None,
+        }
+    }
+}
+
+impl Indicator for VwapIndicator {
+    type Output = MovingAverage;
+
+    fn update(&mut self, bar: &Bar) -> Option<MovingAverage> {
+        if let (Some(price), Some(volume)) = (bar_price(bar), bar.volume) {
+            self.bars.push_back((price * volume, volume));
+            self.value += price * volume;
+            self.volume += volume;
+            if self.bars.len() > self.window {
+                let (value, volume) = self.bars.pop_front().unwrap_or_default();
+                self.value -= value;
+                self.volume -= volume;
+                self.removed += 1;
+                if self.removed >= self.window {
+                    self.value = self.bars.iter().map(|(value, _)| value).sum();
+                    self.volume = self.bars.iter().map(|(_, volume)| volume).sum();
+                    self.removed = 0;
+                }
+            }
+            self.timestamp = bar.timestamp;
+        }
+        self.value()
+    }
+
+    fn value(&self) -> Option<MovingAverage> {
+        if self.bars.is_empty() || self.volume <= 0.0 {
+            return None;
+        }
+        Some(MovingAverage {
+            timestamp: self.timestamp,
+            value: Some(self.value / self.volume),
+        })
+    }
+}
+
+#[test]
+fn test_incremental_indicators() {
+    use crate::tools::indicators::Indicators;
+
+    let closes = [
+        150.0, 151.2, 149.8, 152.4, 153.1, 152.0, 154.3, 155.0, 153.6, 156.2, 157.1, 156.4,
+    ];
+    let bars: Vec<Bar> = closes
+        .iter()
+        .enumerate()
+        .map(|(i, close)| Bar {
+            excahnge: None,
+            close: Some(*close),
+            high: Some(close + 0.9),
+            low: Some(close - 1.1),
+            transactions: None,
+            open: Some(close - 0.3),
+            timestamp: Some(1672722000000 + i as i64 * 86400000),
+            volume: Some(1000.0 + i as f64 * 10.0),
+            volume_weighted: Some(close - 0.1),
+            otc: None,
+        })
+        .collect();
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+    let mut sma = SmaIndicator::new(3, SeriesType::Close);
+    let mut ema = EmaIndicator::new(3, SeriesType::Close);
+    let mut rsi = RsiIndicator::new(3, SeriesType::Close);
+    let mut macd = MacdIndicator::new(3, 5, 2, SeriesType::Close);
+    let mut smas = Vec::new();
+    let mut emas = Vec::new();
+    let mut rsis = Vec::new();
+    let mut macds = Vec::new();
+    for (i, bar) in bars.iter().enumerate() {
+        //Checkpoint and restore halfway through, the restored state must carry on unchanged
+        if i == 6 {
+            sma = serde_json::from_str(&serde_json::to_string(&sma).unwrap()).unwrap();
+            ema = serde_json::from_str(&serde_json::to_string(&ema).unwrap()).unwrap();
+            rsi = serde_json::from_str(&serde_json::to_string(&rsi).unwrap()).unwrap();
+            macd = serde_json::from_str(&serde_json::to_string(&macd).unwrap()).unwrap();
+        }
+        smas.extend(sma.update(bar));
+        emas.extend(ema.update(bar));
+        rsis.extend(rsi.update(bar));
+        macds.extend(macd.update(bar));
+    }
+    let expected = Indicators::simple_moving_average(&bars, 3, SeriesType::Close);
+    assert_eq!(smas.len(), expected.len());
+    for (actual, expected) in smas.iter().zip(expected.iter()) {
+        assert_eq!(actual.timestamp, expected.timestamp);
+        assert!(close(actual.value.unwrap(), expected.value.unwrap()));
+    }
+    let expected = Indicators::exponential_moving_average(&bars, 3, SeriesType::Close);
+    assert_eq!(emas.len(), expected.len());
+    for (actual, expected) in emas.iter().zip(expected.iter()) {
+        assert_eq!(actual.timestamp, expected.timestamp);
+        assert!(close(actual.value.unwrap(), expected.value.unwrap()));
+    }
+    let expected = Indicators::relative_strength_index(&bars, 3, SeriesType::Close);
+    assert_eq!(rsis.len(), expected.len());
+    for (actual, expected) in rsis.iter().zip(expected.iter()) {
+        assert_eq!(actual.timestamp, expected.timestamp);
+        assert!(close(actual.value.unwrap(), expected.value.unwrap()));
+    }
+    let expected =
+        Indicators::moving_average_convergence_divergence(&bars, 3, 5, 2, SeriesType::Close);
+    assert_eq!(macds.len(), expected.len());
+    for (actual, expected) in macds.iter().zip(expected.iter()) {
+        assert_eq!(actual.timestamp, expected.timestamp);
+        assert!(close(actual.value.unwrap(), expected.value.unwrap()));
+        assert!(close(actual.signal.unwrap(), expected.signal.unwrap()));
+    }
+    assert_eq!(
+        macd.value().unwrap().timestamp,
+        bars.last().unwrap().timestamp
+    );
+
+    let mut vwap = VwapIndicator::new(3);
+    assert!(vwap.value().is_none());
+    for bar in &bars {
+        vwap.update(bar);
+    }
+    let expected = Indicators::volume_weighted_average_price(&bars[bars.len() - 3..]);
+    assert!(close(
+        vwap.value().unwrap().value.unwrap(),
+        expected.last().unwrap().value.unwrap()
+    ));
+
+    //Bars with only a VWAP are skipped by the batch VWAP, so they must leave the rolling one unchanged
+    let partial = Bar {
+        high: None,
+        low: None,
+        close: None,
+        ..bars[0].clone()
+    };
+    let before = vwap.value().unwrap().value;
+    assert_eq!(vwap.update(&partial).unwrap().value, before);
+    let mut with_partial = bars.clone();
+    with_partial.push(Bar {
+        timestamp: Some(1673758800000),
+        ..partial
+    });
+    assert_eq!(
+        Indicators::volume_weighted_average_price(&with_partial).len(),
+        bars.len()
+    );
+
+    //A price far larger than the rest loses the small ones to rounding until the sum is rebuilt
+    let spike = |close: f64| Bar {
+        close: Some(close),
+        high: Some(close),
+        low: Some(close),
+        volume_weighted: None,
+        ..bars[0].clone()
+    };
+    let mut sma = SmaIndicator::new(3, SeriesType::Close);
+    let mut vwap = VwapIndicator::new(3);
+    let mut spikes = vec![spike(1e17)];
+    spikes.extend((0..6).map(|_| spike(1.0)));
+    for bar in &spikes {
+        sma.update(bar);
+        vwap.update(bar);
+    }
+    assert_eq!(sma.value().unwrap().value.unwrap(), 1.0);
+    assert_eq!(vwap.value().unwrap().value.unwrap(), 1.0);
+
+    let mut flat = RsiIndicator::new(3, SeriesType::Close);
+    for bar in &bars {
+        flat.update(&Bar {
+            close: Some(150.0),
+            ..bar.clone()
+        });
+    }
+    assert_eq!(flat.value().unwrap().value, Some(50.0));
+
+    let mut empty = SmaIndicator::new(3, SeriesType::Close);
+    let missing = Bar {
+        close: None,
+        ..bars[0].clone()
+    };
+    assert!(empty.update(&missing).is_none());
+    assert!(SmaIndicator::new(0, SeriesType::Close)
+        .update(&bars[0])
+        .is_none());
+}
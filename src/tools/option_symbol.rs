@@ -0,0 +1,354 @@
+use crate::data_types::{details::Details, option_contract::OptionContract};
+use crate::rest::error::ErrorCode;
+use crate::rest::parameters::ContractType;
+use crate::tools::regex_patterns::RegexPatterns;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+//OCC symbology behind Polygon's O: tickers: root, yymmdd expiration, C or P, then the strike in thousandths over 8 digits
+//Roots of adjusted contracts carry a trailing digit after a corporate action, such as AAPL1
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OptionSymbol {
+    pub root: String,
+    pub expiration_date: String,
+    pub contract_type: ContractType,
+    pub strike_price: f64,
+}
+
+impl OptionSymbol {
+    const MAX_STRIKE: f64 = 99999.999;
+
+    pub fn new(
+        root: &str,
+        expiration_date: &str,
+        contract_type: ContractType,
+        strike_price: f64,
+    ) -> Result<Self, ErrorCode> {
+        if !RegexPatterns::option_root().is_match(root) {
+            return Err(ErrorCode::TickerError);
+        }
+        let date = Self::date(expiration_date)?;
+        if contract_type == ContractType::Other {
+            return Err(ErrorCode::OptionsTickerError);
+        }
+        let thousandths = (strike_price * 1000.0).round();
+        if !(0.0..=Self::MAX_STRIKE).contains(&strike_price)
+            || (strike_price * 1000.0 - thousandths).abs() > 1e-6
+        {
+            return Err(ErrorCode::OptionsTickerError);
+        }
+        Ok(Self {
+            root: root.to_string(),
+            expiration_date: date.format("%Y-%m-%d").to_string(),
+            contract_type,
+            strike_price: thousandths / 1000.0,
+        })
+    }
+
+    pub fn parse(ticker: &str) -> Result<Self, ErrorCode> {
+        let captures = match RegexPatterns::options_ticker().captures(ticker) {
+            Some(captures) => captures,
+            None => return Err(ErrorCode::OptionsTickerError),
+        };
+        let date = NaiveDate::parse_from_str(&format!("20{}", &captures[2]), "%Y%m%d")
+            .map_err(|_| ErrorCode::OptionsTickerError)?;
+        let contract_type = match &captures[3] {
+            "C" => ContractType::Call,
+            _ => ContractType::Put,
+        };
+        let thousandths: i64 = captures[4]
+            .parse()
+            .map_err(|_| ErrorCode::OptionsTickerError)?;
+        Ok(Self {
+            root: captures[1].to_string(),
+            expiration_date: date.format("%Y-%m-%d").to_string(),
+            contract_type,
+            strike_price: thousandths as f64 / 1000.0,
+        })
+    }
+
+    //Reference details carry no underlying, so the contract's own ticker is the only source
+    pub fn from_details(details: &Details) -> Result<Self, ErrorCode> {
+        match &details.ticker {
+            Some(ticker) => Self::parse(ticker),
+            None => Err(ErrorCode::TickerNotSet),
+        }
+    }
+
+    //The underlying does not determine the root, adjusted contracts such as AAPL1 and weeklies such as SPXW differ from it
+    pub fn from_option_contract(contract: &OptionContract) -> Result<Self, ErrorCode> {
+        match &contract.ticker {
+            Some(ticker) => Self::parse(ticker),
+            None => Err(ErrorCode::TickerNotSet),
+        }
+    }
+
+    pub fn ticker(&self) -> String {
+        self.to_string()
+    }
+
+    //The root without the digit an adjusted contract appends
+    pub fn underlying(&self) -> &str {
+        self.root.trim_end_matches(|c: char| c.is_ascii_digit())
+    }
+
+    pub fn is_adjusted(&self) -> bool {
+        self.underlying() != self.root
+    }
+
+    pub fn to_details(&self) -> Details {
+        Details {
+            contract_type: Some(self.contract_type),
+            contract_style: None,
+            expiration_date: Some(self.expiration_date.clone()),
+            shares_per_contract: None,
+            strike_price: Some(self.strike_price),
+            ticker: Some(self.ticker()),
+        }
+    }
+
+    //The underlying is left unset, a root such as SPXW does not name its underlying ticker
+    pub fn to_option_contract(&self) -> OptionContract {
+        OptionContract {
+            additional_underlyings: None,
+            cfi: None,
+            contract: None,
+            contract_type: Some(self.contract_type),
+            correction: None,
+            exercise_style: None,
+            expiration_date: Some(self.expiration_date.clone()),
+            primary_exchange: None,
+            shares_per_contract: None,
+            strike_price: Some(self.strike_price),
+            ticker: Some(self.ticker()),
+            underlying_ticker: None,
+        }
+    }
+
+    fn date(expiration_date: &str) -> Result<NaiveDate, ErrorCode> {
+        //The two digit year in the symbol can only express 2000 to 2099
+        if !RegexPatterns::string_date().is_match(expiration_date) {
+            return Err(ErrorCode::DateError);
+        }
+        match NaiveDate::parse_from_str(expiration_date, "%Y-%m-%d") {
+            Ok(date) if date.year() >= 2000 => Ok(date),
+            _ => Err(ErrorCode::DateError),
+        }
+    }
+}
+
+impl fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let contract_type = match self.contract_type {
+            ContractType::Put => "P",
+            _ => "C",
+        };
+        let date = NaiveDate::parse_from_str(&self.expiration_date, "%Y-%m-%d")
+            .map(|date| date.format("%y%m%d").to_string())
+            .unwrap_or_default();
+        write!(
+            f,
+            "O:{}{}{}{:08}",
+            self.root,
+            date,
+            contract_type,
+            (self.strike_price * 1000.0).round() as i64
+        )
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = ErrorCode;
+
+    fn from_str(ticker: &str) -> Result<Self, ErrorCode> {
+        Self::parse(ticker)
+    }
+}
+
+#[test]
+fn test_option_symbol_parse() {
+    let symbol = OptionSymbol::parse("O:AAPL250117C00150000").unwrap();
+    assert_eq!(symbol.root, "AAPL");
+    assert_eq!(symbol.underlying(), "AAPL");
+    assert_eq!(symbol.expiration_date, "2025-01-17");
+    assert_eq!(symbol.contract_type, ContractType::Call);
+    assert_eq!(symbol.strike_price, 150.0);
+    assert!(!symbol.is_adjusted());
+
+    let symbol: OptionSymbol = "O:AAPL1250117P00152500".parse().unwrap();
+    assert_eq!(symbol.root, "AAPL1");
+    assert_eq!(symbol.underlying(), "AAPL");
+    assert!(symbol.is_adjusted());
+    assert_eq!(symbol.contract_type, ContractType::Put);
+    assert_eq!(symbol.strike_price, 152.5);
+
+    let symbol = OptionSymbol::parse("O:F240621C00012500").unwrap();
+    assert_eq!(symbol.root, "F");
+    assert_eq!(symbol.strike_price, 12.5);
+    let symbol = OptionSymbol::parse("O:GOOGL240621P00000500").unwrap();
+    assert_eq!(symbol.root, "GOOGL");
+    assert_eq!(symbol.strike_price, 0.5);
+    let symbol = OptionSymbol::parse("O:SPXW241220C05900000").unwrap();
+    assert_eq!(symbol.strike_price, 5900.0);
+    assert_eq!(symbol.root.len(), 4);
+    assert_eq!(
+        OptionSymbol::parse("O:BRKB12240621C00400000").unwrap().root,
+        "BRKB12"
+    );
+
+    assert_eq!(
+        OptionSymbol::parse("AAPL250117C00150000"),
+        Err(ErrorCode::OptionsTickerError)
+    );
+    assert_eq!(
+        OptionSymbol::parse("O:AAPLXYZ1250117C00150000"),
+        Err(ErrorCode::OptionsTickerError)
+    );
+    assert_eq!(
+        OptionSymbol::parse("O:AAPL250230C00150000"),
+        Err(ErrorCode::OptionsTickerError)
+    );
+    assert_eq!(
+        OptionSymbol::parse("O:AAPL250117X00150000"),
+        Err(ErrorCode::OptionsTickerError)
+    );
+}
+
+#[test]
+fn test_option_symbol_build() {
+    let symbol = OptionSymbol::new("AAPL", "2025-01-17", ContractType::Call, 150.0).unwrap();
+    assert_eq!(symbol.ticker(), "O:AAPL250117C00150000");
+    let symbol = OptionSymbol::new("AAPL1", "2025-01-17", ContractType::Put, 152.5).unwrap();
+    assert_eq!(symbol.to_string(), "O:AAPL1250117P00152500");
+    assert_eq!(OptionSymbol::parse(&symbol.ticker()).unwrap(), symbol);
+    let symbol = OptionSymbol::new("SPY", "2024-06-21", ContractType::Call, 545.123).unwrap();
+    assert_eq!(symbol.ticker(), "O:SPY240621C00545123");
+
+    assert_eq!(
+        OptionSymbol::new("aapl", "2025-01-17", ContractType::Call, 150.0),
+        Err(ErrorCode::TickerError)
+    );
+    assert_eq!(
+        OptionSymbol::new("AAPL", "2025-02-30", ContractType::Call, 150.0),
+        Err(ErrorCode::DateError)
+    );
+    assert_eq!(
+        OptionSymbol::new("AAPL", "1999-01-15", ContractType::Call, 150.0),
+        Err(ErrorCode::DateError)
+    );
+    assert_eq!(
+        OptionSymbol::new("AAPL", "2025-01-17", ContractType::Other, 150.0),
+        Err(ErrorCode::OptionsTickerError)
+    );
+    assert_eq!(
+        OptionSymbol::new("AAPL", "2025-01-17", ContractType::Call, 150.0001),
+        Err(ErrorCode::OptionsTickerError)
+    );
+    assert_eq!(
+        OptionSymbol::new("AAPL", "2025-01-17", ContractType::Call, 100000.0),
+        Err(ErrorCode::OptionsTickerError)
+    );
+}
+
+#[test]
+fn test_option_symbol_round_trip() {
+    use crate::data_types::Parse;
+
+    let details = Details::parse(
+        serde_json::json!({
+            "contract_type": "put",
+            "exercise_style": "american",
+            "expiration_date": "2024-01-19",
+            "shares_per_contract": 100,
+            "strike_price": 185,
+            "ticker": "O:AAPL240119P00185000"
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let symbol = OptionSymbol::from_details(&details).unwrap();
+    assert_eq!(
+        symbol.expiration_date,
+        details.expiration_date.clone().unwrap()
+    );
+    assert_eq!(symbol.contract_type, details.contract_type.unwrap());
+    assert_eq!(symbol.strike_price, details.strike_price.unwrap());
+    let built = symbol.to_details();
+    assert_eq!(built.ticker, details.ticker);
+    assert_eq!(OptionSymbol::from_details(&built).unwrap(), symbol);
+    assert_eq!(
+        OptionSymbol::from_details(&Details {
+            ticker: None,
+            ..built
+        }),
+        Err(ErrorCode::TickerNotSet)
+    );
+
+    let contract = OptionContract::parse(
+        serde_json::json!({
+            "cfi": "OCASPS",
+            "contract_type": "call",
+            "exercise_style": "american",
+            "expiration_date": "2024-06-21",
+            "primary_exchange": "BATO",
+            "shares_per_contract": 100,
+            "strike_price": 12.5,
+            "ticker": "O:F240621C00012500",
+            "underlying_ticker": "F"
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let symbol = OptionSymbol::from_option_contract(&contract).unwrap();
+    let built = symbol.to_option_contract();
+    assert_eq!(built.ticker, contract.ticker);
+    assert_eq!(built.underlying_ticker, None);
+    assert_eq!(built.expiration_date, contract.expiration_date);
+    assert_eq!(built.strike_price, contract.strike_price);
+    assert_eq!(built.contract_type, contract.contract_type);
+    assert_eq!(OptionSymbol::from_option_contract(&built).unwrap(), symbol);
+    assert_eq!(
+        OptionSymbol::from_option_contract(&OptionContract {
+            ticker: None,
+            ..contract
+        }),
+        Err(ErrorCode::TickerNotSet)
+    );
+
+    //After a corporate action the root carries a digit and the deliverable moves to additional_underlyings
+    let contract = OptionContract::parse(
+        serde_json::json!({
+            "additional_underlyings": [{"amount": 44, "type": "equity", "underlying": "AAPL"}],
+            "cfi": "OCASPS",
+            "contract_type": "call",
+            "exercise_style": "american",
+            "expiration_date": "2025-01-17",
+            "primary_exchange": "BATO",
+            "shares_per_contract": 100,
+            "strike_price": 150,
+            "ticker": "O:AAPL1250117C00150000",
+            "underlying_ticker": "AAPL"
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let symbol = OptionSymbol::from_option_contract(&contract).unwrap();
+    assert_eq!(symbol.root, "AAPL1");
+    assert!(symbol.is_adjusted());
+    let built = symbol.to_option_contract();
+    assert_eq!(built.ticker, contract.ticker);
+    assert_eq!(built.underlying_ticker, None);
+    assert_eq!(OptionSymbol::from_option_contract(&built).unwrap(), symbol);
+    assert_eq!(OptionSymbol::parse(&symbol.ticker()).unwrap(), symbol);
+    assert_eq!(
+        OptionSymbol::from_option_contract(&OptionContract {
+            ticker: None,
+            ..contract
+        }),
+        Err(ErrorCode::TickerNotSet)
+    );
+
+    let symbol = OptionSymbol::parse("O:SPXW241220C05900000").unwrap();
+    assert_eq!(symbol.to_option_contract().underlying_ticker, None);
+}
@@ -0,0 +1,276 @@
+use crate::data_types::l2::L2;
+use crate::rest::market::snapshot::l2_snapshot::L2Snapshot;
+use std::collections::{BTreeMap, HashMap};
+
+//Prices are positive so their bit patterns sort in the same order as the prices themselves
+#[derive(Clone, Debug, Default)]
+pub struct OrderBook {
+    ticker: Option<String>,
+    timestamp: Option<i64>,
+    snapshot: Option<i64>,
+    bids: BTreeMap<u64, Level>,
+    asks: BTreeMap<u64, Level>,
+}
+
+//Size resting at a price, per exchange id
+#[derive(Clone, Debug, Default)]
+struct Level {
+    price: f64,
+    sizes: HashMap<String, f64>,
+}
+
+impl Level {
+    fn size(&self) -> f64 {
+        self.sizes.values().sum()
+    }
+}
+
+impl OrderBook {
+    pub fn new(snapshot: &L2Snapshot) -> Self {
+        match snapshot.l2.as_ref().and_then(|l2| l2.first()) {
+            Some(l2) => Self::from_l2(l2),
+            None => Self::default(),
+        }
+    }
+
+    pub fn from_l2(l2: &L2) -> Self {
+        let mut book = Self::default();
+        book.apply(l2);
+        book.snapshot = l2.timestamp;
+        book
+    }
+
+    //A zero size removes the exchange from the level, the level is dropped once no exchange quotes it
+    //Deltas from before the snapshot are already reflected and are skipped, returns whether it was applied
+    //XL2 sends several updates within a millisecond, so any at or after the snapshot apply in arrival order
+    pub fn apply(&mut self, l2: &L2) -> bool {
+        if let (Some(timestamp), Some(snapshot)) = (l2.timestamp, self.snapshot) {
+            if timestamp < snapshot {
+                return false;
+            }
+        }
+        if l2.ticker.is_some() {
+            self.ticker = l2.ticker.clone();
+        }
+        if l2.timestamp.is_some() {
+            self.timestamp = l2.timestamp;
+        }
+        for bid in l2.bids.iter().flatten() {
+            if let (Some(price), Some(sizes)) = (bid.price, &bid.size) {
+                Self::update(&mut self.bids, price, sizes);
+            }
+        }
+        for ask in l2.asks.iter().flatten() {
+            if let (Some(price), Some(sizes)) = (ask.price, &ask.size) {
+                Self::update(&mut self.asks, price, sizes);
+            }
+        }
+        true
+    }
+
+    pub fn ticker(&self) -> Option<&str> {
+        self.ticker.as_deref()
+    }
+
+    pub fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+
+    pub fn best_bid(&self) -> Option<(f64, f64)> {
+        self.bids.values().next_back().map(|l| (l.price, l.size()))
+    }
+
+    pub fn best_ask(&self) -> Option<(f64, f64)> {
+        self.asks.values().next().map(|l| (l.price, l.size()))
+    }
+
+    pub fn spread(&self) -> Option<f64> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some((bid, _)), Some((ask, _))) => Some(ask - bid),
+            _ => None,
+        }
+    }
+
+    //Best first, as (price, total size) pairs
+    pub fn bids(&self, depth: usize) -> Vec<(f64, f64)> {
+        self.bids
+            .values()
+            .rev()
+            .take(depth)
+            .map(|l| (l.price, l.size()))
+            .collect()
+    }
+
+    pub fn asks(&self, depth: usize) -> Vec<(f64, f64)> {
+        self.asks
+            .values()
+            .take(depth)
+            .map(|l| (l.price, l.size()))
+            .collect()
+    }
+
+    pub fn bid_depth(&self, depth: usize) -> f64 {
+        self.bids(depth).iter().map(|(_, size)| size).sum()
+    }
+
+    pub fn ask_depth(&self, depth: usize) -> f64 {
+        self.asks(depth).iter().map(|(_, size)| size).sum()
+    }
+
+    //CRC32 of the top levels formatted as price:size, asks then bids, for comparing two books
+    pub fn checksum(&self, depth: usize) -> u32 {
+        let levels: Vec<String> = self
+            .asks(depth)
+            .iter()
+            .chain(self.bids(depth).iter())
+            .map(|(price, size)| format!("{}:{}", price, size))
+            .collect();
+        crc32(levels.join("|").as_bytes())
+    }
+
+    fn update(side: &mut BTreeMap<u64, Level>, price: f64, sizes: &HashMap<String, f64>) {
+        let key = price.to_bits();
+        let level = side.entry(key).or_insert_with(|| Level {
+            price,
+            ..Level::default()
+        });
+        for (exchange, size) in sizes {
+            if *size > 0.0 {
+                level.sizes.insert(exchange.clone(), *size);
+            } else {
+                level.sizes.remove(exchange);
+            }
+        }
+        if level.sizes.is_empty() {
+            side.remove(&key);
+        }
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFFu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[test]
+fn test_order_book() {
+    use crate::data_types::Parse;
+
+    let snapshot = L2Snapshot::parse(
+        serde_json::json!({
+            "status": "OK",
+            "data": {
+                "asks": [{"p": 101.0, "x": {"1": 2.0}}, {"p": 102.0, "x": {"1": 1.0, "2": 3.0}}],
+                "bids": [{"p": 100.0, "x": {"1": 1.5, "2": 0.5}}, {"p": 99.0, "x": {"2": 4.0}}],
+                "ticker": "X:BTCUSD",
+                "updated": 1605295074162365000i64
+            }
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let mut book = OrderBook::new(&snapshot);
+    assert_eq!(book.ticker().unwrap(), "X:BTCUSD");
+    assert_eq!(book.timestamp().unwrap(), 1605295074162);
+    assert_eq!(book.best_bid().unwrap(), (100.0, 2.0));
+    assert_eq!(book.best_ask().unwrap(), (101.0, 2.0));
+    assert_eq!(book.spread().unwrap(), 1.0);
+    assert_eq!(book.bids(5), vec![(100.0, 2.0), (99.0, 4.0)]);
+    assert_eq!(book.asks(1), vec![(101.0, 2.0)]);
+    assert_eq!(book.ask_depth(2), 6.0);
+    let checksum = book.checksum(10);
+    assert_eq!(checksum, book.clone().checksum(10));
+
+    assert!(book.apply(&L2::parse(
+        serde_json::json!({
+            "ev": "XL2",
+            "pair": "BTC-USD",
+            "t": 1605295075000i64,
+            "x": 1,
+            "b": [[100.0, 0], [100.5, 0.25]],
+            "a": [[101.0, 0]]
+        })
+        .as_object()
+        .unwrap(),
+    )));
+    assert_eq!(book.timestamp().unwrap(), 1605295075000);
+    assert_eq!(book.best_bid().unwrap(), (100.5, 0.25));
+    assert_eq!(book.bids(3), vec![(100.5, 0.25), (100.0, 0.5), (99.0, 4.0)]);
+    assert_eq!(book.best_ask().unwrap(), (102.0, 4.0));
+    assert_eq!(book.spread().unwrap(), 1.5);
+    assert_eq!(book.bid_depth(2), 0.75);
+    assert_ne!(book.checksum(10), checksum);
+}
+
+#[test]
+fn test_order_book_stale_delta() {
+    use crate::data_types::Parse;
+
+    let snapshot = L2Snapshot::parse(
+        serde_json::json!({
+            "status": "OK",
+            "data": {
+                "asks": [{"p": 101.0, "x": {"1": 2.0}}],
+                "bids": [{"p": 100.0, "x": {"1": 1.5}}],
+                "ticker": "X:BTCUSD",
+                "updated": 1605295074162365000i64
+            }
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let mut book = OrderBook::new(&snapshot);
+    let checksum = book.checksum(10);
+    let delta = |t: i64, x: i64, bids: serde_json::Value| {
+        L2::parse(
+            serde_json::json!({"ev": "XL2", "pair": "BTC-USD", "t": t, "x": x, "b": bids, "a": []})
+                .as_object()
+                .unwrap(),
+        )
+    };
+    //Sent before the snapshot was taken, so the removal must not be replayed
+    assert!(!book.apply(&delta(1605295074000, 1, serde_json::json!([[100.0, 0]]))));
+    assert!(!book.apply(&delta(1605295074161, 1, serde_json::json!([[100.0, 0]]))));
+    assert_eq!(book.timestamp().unwrap(), 1605295074162);
+    assert_eq!(book.best_bid().unwrap(), (100.0, 1.5));
+    assert_eq!(book.checksum(10), checksum);
+
+    //Updates from two exchanges in the same millisecond as each other and the snapshot
+    assert!(book.apply(&delta(1605295074162, 1, serde_json::json!([[100.5, 0.25]]))));
+    assert!(book.apply(&delta(1605295074162, 2, serde_json::json!([[99.5, 3.0]]))));
+    assert!(book.apply(&delta(1605295074163, 1, serde_json::json!([[100.0, 0]]))));
+    assert!(book.apply(&delta(1605295074163, 2, serde_json::json!([[100.0, 0.75]]))));
+    assert_eq!(
+        book.bids(5),
+        vec![(100.5, 0.25), (100.0, 0.75), (99.5, 3.0)]
+    );
+    assert_eq!(book.timestamp().unwrap(), 1605295074163);
+    let expected = OrderBook::from_l2(&L2::parse(
+        serde_json::json!({
+            "asks": [{"p": 101.0, "x": {"1": 2.0}}],
+            "bids": [
+                {"p": 100.5, "x": {"1": 0.25}},
+                {"p": 100.0, "x": {"2": 0.75}},
+                {"p": 99.5, "x": {"2": 3.0}}
+            ]
+        })
+        .as_object()
+        .unwrap(),
+    ));
+    assert_eq!(book.checksum(10), expected.checksum(10));
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(crc32(b"123456789"), 0xCBF43926);
+}
@@ -0,0 +1,155 @@
+use crate::{
+    client::PolygonClient, data_types::Parse, rest::error::ErrorCode, tools::request::Request,
+};
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+
+pub trait Paginate: Parse {
+    type Item;
+
+    fn next_url(&self) -> Option<String>;
+
+    fn items(self) -> Vec<Self::Item>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PageLimit {
+    pub max_items: Option<usize>,
+    pub max_pages: Option<usize>, //Includes the first page passed to the Paginator
+}
+
+pub struct Paginator<T: Paginate> {
+    client: PolygonClient,
+    next_url: Option<String>,
+    buffer: VecDeque<T::Item>,
+    limit: PageLimit,
+    pages: usize,
+    items: usize,
+}
+
+impl<T: Paginate> Paginator<T> {
+    pub fn new(client: &PolygonClient, first_page: T, limit: PageLimit) -> Self {
+        let next_url = first_page.next_url();
+        Self {
+            client: client.clone(),
+            next_url,
+            buffer: first_page.items().into(),
+            limit,
+            pages: 1,
+            items: 0,
+        }
+    }
+
+    pub async fn next_item(&mut self) -> Option<Result<T::Item, ErrorCode>> {
+        loop {
+            if let Some(max_items) = self.limit.max_items {
+                if self.items >= max_items {
+                    return None;
+                }
+            }
+            if let Some(item) = self.buffer.pop_front() {
+                self.items += 1;
+                return Some(Ok(item));
+            }
+            if let Some(max_pages) = self.limit.max_pages {
+                if self.pages >= max_pages {
+                    return None;
+                }
+            }
+            let next_url = self.next_url.take()?;
+            match Request::next_async::<T>(&self.client, Some(next_url)).await {
+                Ok(page) => {
+                    self.pages += 1;
+                    self.next_url = page.next_url();
+                    self.buffer = page.items().into();
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = Result<T::Item, ErrorCode>> {
+        stream::unfold(self, |mut paginator| async move {
+            paginator.next_item().await.map(|item| (item, paginator))
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T: Paginate> Iterator for Paginator<T> {
+    type Item = Result<T::Item, ErrorCode>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Request::blocking(self.next_item())
+    }
+}
+
+#[tokio::test]
+async fn test_paginator_stream() {
+    use crate::rest::market::trades::{Trades, TradesRequest};
+    use futures::StreamExt;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v3/trades/AAPL"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "OK",
+            "results": [{"p": 3.0}]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v3/trades/AAPL"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "OK",
+            "next_url": format!("{}/v3/trades/AAPL?cursor=page2", server.uri()),
+            "results": [{"p": 1.0}, {"p": 2.0}]
+        })))
+        .mount(&server)
+        .await;
+    let client = PolygonClient::with_config(crate::client::ClientConfig {
+        api_key: String::from("ak123456789012345678901234567890"),
+        base_url: server.uri(),
+        ..crate::client::ClientConfig::default()
+    })
+    .unwrap();
+    let first_page = Trades::get_trades_async(
+        &client,
+        String::from("AAPL"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let prices: Vec<f64> = Paginator::new(&client, first_page.clone(), PageLimit::default())
+        .into_stream()
+        .map(|trade| trade.unwrap().price.unwrap())
+        .collect()
+        .await;
+    assert_eq!(prices, vec![1.0, 2.0, 3.0]);
+    let limit = PageLimit {
+        max_items: Some(2),
+        ..PageLimit::default()
+    };
+    let capped: Vec<_> = Paginator::new(&client, first_page.clone(), limit)
+        .into_stream()
+        .collect()
+        .await;
+    assert_eq!(capped.len(), 2);
+    let limit = PageLimit {
+        max_pages: Some(1),
+        ..PageLimit::default()
+    };
+    let first_only: Vec<_> = Paginator::new(&client, first_page, limit)
+        .into_stream()
+        .collect()
+        .await;
+    assert_eq!(first_only.len(), 2);
+}
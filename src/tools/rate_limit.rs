@@ -0,0 +1,64 @@
+use std::time::Duration;
+use tokio::{sync::Mutex, time::Instant};
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_minute: u32, burst: u32) -> Self {
+        let capacity = burst.max(1) as f64;
+        Self {
+            capacity,
+            refill_per_second: requests_per_minute.max(1) as f64 / 60.0,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn per_minute(requests_per_minute: u32) -> Self {
+        Self::new(requests_per_minute, requests_per_minute)
+    }
+
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens =
+                    (bucket.tokens + elapsed * self.refill_per_second).min(self.capacity);
+                bucket.last_refill = now;
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_second)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limiter() {
+    let limiter = RateLimiter::new(600, 2);
+    let start = Instant::now();
+    for _ in 0..4 {
+        limiter.acquire().await;
+    }
+    //Two requests come out of the burst, the other two wait 100ms each
+    assert!(start.elapsed() >= Duration::from_millis(190));
+    assert!(start.elapsed() < Duration::from_millis(1000));
+}
@@ -9,7 +9,8 @@ impl RegexPatterns {
     const STOCK_TICKER: &'static str = r"^[A-Z]{1,6}$";
     const OPTION_CHECK: &'static str = r"^O:";
     const OPTION_TICKER: &'static str =
-        r"(O:)([A-Z]){1,4}([0-9]{2})(1[0-2]|0[1-9])(3[01]|[12][0-9]|0[1-9])([CP]){1}([0-9]){8}$";
+        r"^O:([A-Z][A-Z0-9]{0,5})([0-9]{2}(?:1[0-2]|0[1-9])(?:3[01]|[12][0-9]|0[1-9]))([CP])([0-9]{8})$";
+    const OPTION_ROOT: &'static str = r"^[A-Z][A-Z0-9]{0,5}$";
     const INDICIE_CHECK: &'static str = r"^I:";
     const INDICIE_TICKER: &'static str = r"^I:[A-Z0-9]+$";
     const FOREX_CHECK: &'static str = r"^C:";
@@ -19,6 +20,13 @@ impl RegexPatterns {
     const STRING_DATE: &'static str =
         r"^(19|20)([0-9]{2})-(1[0-2]|0[1-9])-(3[01]|[12][0-9]|0[1-9])$";
     const EPOCH_NANO_DATE: &'static str = r"^\d{19}$";
+    const EXCHANGE_MIC: &'static str = r"^[A-Z]{4}$";
+    const CIK: &'static str = r"^\d{1,10}$";
+    const CUSIP: &'static str = r"^[0-9A-Z*@#]{9}$";
+    const SIC: &'static str = r"^\d{4}$";
+    const FIGI: &'static str = r"^BBG[0-9A-Z]{9}$";
+    const FOREX_PAIR: &'static str = r"^C:[A-Z]{3}-[A-Z]{3}$";
+    const CRYPTO_PAIR: &'static str = r"^X:[A-Z0-9]+-[A-Z0-9]+$";
 
     pub fn api_key() -> Regex {
         match Regex::new(Self::API_KEY) {
@@ -108,6 +116,17 @@ impl RegexPatterns {
         }
     }
 
+    pub fn option_root() -> Regex {
+        match Regex::new(Self::OPTION_ROOT) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
     pub fn indicies_ticker() -> Regex {
         match Regex::new(Self::INDICIE_TICKER) {
             Ok(regex) => regex,
@@ -162,6 +181,83 @@ impl RegexPatterns {
             ),
         }
     }
+
+    pub fn exchange_mic() -> Regex {
+        match Regex::new(Self::EXCHANGE_MIC) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
+    pub fn cik() -> Regex {
+        match Regex::new(Self::CIK) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
+    pub fn cusip() -> Regex {
+        match Regex::new(Self::CUSIP) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
+    pub fn sic() -> Regex {
+        match Regex::new(Self::SIC) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
+    pub fn figi() -> Regex {
+        match Regex::new(Self::FIGI) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
+    pub fn forex_pair() -> Regex {
+        match Regex::new(Self::FOREX_PAIR) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
+
+    pub fn crypto_pair() -> Regex {
+        match Regex::new(Self::CRYPTO_PAIR) {
+            Ok(regex) => regex,
+            Err(e) => panic!(
+                "The follow error code: {} occurred due to {}",
+                ErrorCode::RegexError,
+                e
+            ),
+        }
+    }
 }
 
 #[test]
@@ -209,6 +305,25 @@ fn test_options_ticker() {
         RegexPatterns::options_ticker().is_match("O:AAPL230421C00200000"),
         true
     );
+    assert_eq!(
+        RegexPatterns::options_ticker().is_match("O:GOOGL230421C00200000"),
+        true
+    );
+    assert_eq!(
+        RegexPatterns::options_ticker().is_match("O:AAPL1230421C00200000"),
+        true
+    );
+    assert_eq!(
+        RegexPatterns::options_ticker().is_match("O:AAPLXYZ230421C00200000"),
+        false
+    );
+}
+
+#[test]
+fn test_option_root() {
+    assert_eq!(RegexPatterns::option_root().is_match("AAPL1"), true);
+    assert_eq!(RegexPatterns::option_root().is_match("1AAPL"), false);
+    assert_eq!(RegexPatterns::option_root().is_match("AAPLXYZ"), false);
 }
 
 #[test]
@@ -264,3 +379,46 @@ fn test_epoch_nano_date() {
         false
     );
 }
+
+#[test]
+fn test_exchange_mic() {
+    assert_eq!(RegexPatterns::exchange_mic().is_match("XNAS"), true);
+    assert_eq!(RegexPatterns::exchange_mic().is_match("XNASD"), false);
+}
+
+#[test]
+fn test_cik() {
+    assert_eq!(RegexPatterns::cik().is_match("0000320193"), true);
+    assert_eq!(RegexPatterns::cik().is_match("CIK320193"), false);
+}
+
+#[test]
+fn test_cusip() {
+    assert_eq!(RegexPatterns::cusip().is_match("037833100"), true);
+    assert_eq!(RegexPatterns::cusip().is_match("03783310"), false);
+}
+
+#[test]
+fn test_sic() {
+    assert_eq!(RegexPatterns::sic().is_match("3571"), true);
+    assert_eq!(RegexPatterns::sic().is_match("357"), false);
+}
+
+#[test]
+fn test_figi() {
+    assert_eq!(RegexPatterns::figi().is_match("BBG000MM2P62"), true);
+    assert_eq!(RegexPatterns::figi().is_match("BBG000MM2P6"), false);
+    assert_eq!(RegexPatterns::figi().is_match("META"), false);
+}
+
+#[test]
+fn test_forex_pair() {
+    assert_eq!(RegexPatterns::forex_pair().is_match("C:EUR-USD"), true);
+    assert_eq!(RegexPatterns::forex_pair().is_match("C:EURUSD"), false);
+}
+
+#[test]
+fn test_crypto_pair() {
+    assert_eq!(RegexPatterns::crypto_pair().is_match("X:BTC-USD"), true);
+    assert_eq!(RegexPatterns::crypto_pair().is_match("X:BTCUSD"), false);
+}
@@ -0,0 +1,234 @@
+use crate::data_types::{
+    condition::Condition, exchange::Exchange, quote::Quote, trade::Trade, update_rule::UpdateRule,
+};
+use crate::rest::reference::{conditions::Conditions, exchanges::Exchanges};
+use std::collections::HashMap;
+
+//Exchange ids and condition codes are only unique within an asset class, build one registry per asset class
+#[derive(Clone, Debug, Default)]
+pub struct ReferenceRegistry {
+    exchanges: HashMap<i64, Exchange>,
+    trade_conditions: HashMap<i64, Condition>,
+    quote_conditions: HashMap<i64, Condition>,
+}
+
+impl ReferenceRegistry {
+    pub fn new(exchanges: &Exchanges, conditions: &Conditions) -> Self {
+        Self::from_parts(
+            exchanges.exchanges.as_deref().unwrap_or_default(),
+            conditions.conditions.as_deref().unwrap_or_default(),
+        )
+    }
+
+    pub fn from_parts(exchanges: &[Exchange], conditions: &[Condition]) -> Self {
+        let mut registry = Self::default();
+        for exchange in exchanges {
+            if let Some(id) = exchange.id {
+                registry.exchanges.insert(id, exchange.clone());
+            }
+        }
+        for condition in conditions {
+            let id = match condition.id {
+                Some(id) => id,
+                None => continue,
+            };
+            for data_type in condition.data_types.iter().flatten() {
+                match data_type.as_str() {
+                    "trade" => {
+                        registry.trade_conditions.insert(id, condition.clone());
+                    }
+                    "bbo" | "nbbo" => {
+                        registry.quote_conditions.insert(id, condition.clone());
+                    }
+                    _ => (),
+                }
+            }
+        }
+        registry
+    }
+
+    pub fn exchange(&self, id: i64) -> Option<&Exchange> {
+        self.exchanges.get(&id)
+    }
+
+    pub fn exchange_name(&self, id: i64) -> Option<&str> {
+        self.exchange(id).and_then(|e| e.name.as_deref())
+    }
+
+    pub fn exchange_mic(&self, id: i64) -> Option<&str> {
+        self.exchange(id).and_then(|e| e.mic.as_deref())
+    }
+
+    pub fn trade_condition(&self, id: i64) -> Option<&Condition> {
+        self.trade_conditions.get(&id)
+    }
+
+    pub fn quote_condition(&self, id: i64) -> Option<&Condition> {
+        self.quote_conditions.get(&id)
+    }
+
+    pub fn trade_exchange(&self, trade: &Trade) -> Option<&Exchange> {
+        trade.exchange_id.and_then(|id| self.exchange(id))
+    }
+
+    pub fn quote_exchange(&self, quote: &Quote) -> Option<&Exchange> {
+        quote.exchange_id.and_then(|id| self.exchange(id))
+    }
+
+    pub fn bid_exchange(&self, quote: &Quote) -> Option<&Exchange> {
+        quote.bid_exchange_id.and_then(|id| self.exchange(id))
+    }
+
+    pub fn ask_exchange(&self, quote: &Quote) -> Option<&Exchange> {
+        quote.ask_exchange_id.and_then(|id| self.exchange(id))
+    }
+
+    pub fn trade_conditions(&self, trade: &Trade) -> Vec<&Condition> {
+        trade
+            .conditions
+            .iter()
+            .flatten()
+            .filter_map(|id| self.trade_condition(*id))
+            .collect()
+    }
+
+    pub fn quote_conditions(&self, quote: &Quote) -> Vec<&Condition> {
+        quote
+            .conditions
+            .iter()
+            .flatten()
+            .filter_map(|id| self.quote_condition(*id))
+            .collect()
+    }
+
+    pub fn updates_high_low(&self, trade: &Trade) -> bool {
+        self.updates(trade, |rule| rule.updates_high_low)
+    }
+
+    pub fn updates_open_close(&self, trade: &Trade) -> bool {
+        self.updates(trade, |rule| rule.updates_open_close)
+    }
+
+    pub fn updates_volume(&self, trade: &Trade) -> bool {
+        self.updates(trade, |rule| rule.updates_volume)
+    }
+
+    //A trade only updates the consolidated bar if none of its conditions forbid it, unknown codes are treated as regular sales
+    fn updates(&self, trade: &Trade, rule: fn(&UpdateRule) -> Option<bool>) -> bool {
+        self.trade_conditions(trade).iter().all(|condition| {
+            condition
+                .update_rules
+                .as_ref()
+                .and_then(|rules| rules.consolidated.as_ref())
+                .and_then(rule)
+                .unwrap_or(true)
+        })
+    }
+}
+
+#[test]
+fn test_reference_registry() {
+    use crate::data_types::Parse;
+
+    let exchanges = Exchanges::parse(
+        serde_json::json!({
+            "results": [
+                {
+                    "acronym": "AMEX",
+                    "asset_class": "stocks",
+                    "id": 1,
+                    "mic": "XASE",
+                    "name": "NYSE American, LLC"
+                },
+                {
+                    "asset_class": "stocks",
+                    "id": 12,
+                    "mic": "XNAS",
+                    "name": "Nasdaq"
+                }
+            ]
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let conditions = Conditions::parse(
+        serde_json::json!({
+            "results": [
+                {
+                    "data_types": ["trade"],
+                    "id": 2,
+                    "name": "Average Price Trade",
+                    "update_rules": {
+                        "consolidated": {
+                            "updates_high_low": false,
+                            "updates_open_close": false,
+                            "updates_volume": true
+                        }
+                    }
+                },
+                {
+                    "data_types": ["trade"],
+                    "id": 37,
+                    "name": "Odd Lot Trade",
+                    "update_rules": {
+                        "consolidated": {
+                            "updates_high_low": false,
+                            "updates_open_close": false,
+                            "updates_volume": true
+                        }
+                    }
+                },
+                {
+                    "data_types": ["bbo", "nbbo"],
+                    "id": 1,
+                    "name": "Regular, Two-Sided Open"
+                }
+            ]
+        })
+        .as_object()
+        .unwrap(),
+    );
+    let registry = ReferenceRegistry::new(&exchanges, &conditions);
+    assert_eq!(registry.exchange_name(1).unwrap(), "NYSE American, LLC");
+    assert_eq!(registry.exchange_mic(12).unwrap(), "XNAS");
+    assert!(registry.exchange(4).is_none());
+    assert!(registry.trade_condition(1).is_none());
+    assert_eq!(
+        registry.quote_condition(1).unwrap().name.clone().unwrap(),
+        "Regular, Two-Sided Open"
+    );
+
+    let trade = Trade::parse(
+        serde_json::json!({"c": [37], "x": 12, "p": 10.0, "s": 5})
+            .as_object()
+            .unwrap(),
+    );
+    assert_eq!(
+        registry
+            .trade_exchange(&trade)
+            .unwrap()
+            .mic
+            .clone()
+            .unwrap(),
+        "XNAS"
+    );
+    assert_eq!(
+        registry.trade_conditions(&trade)[0].name.clone().unwrap(),
+        "Odd Lot Trade"
+    );
+    assert_eq!(registry.updates_high_low(&trade), false);
+    assert_eq!(registry.updates_open_close(&trade), false);
+    assert_eq!(registry.updates_volume(&trade), true);
+
+    let regular = Trade::parse(serde_json::json!({"x": 1, "p": 10.0}).as_object().unwrap());
+    assert_eq!(registry.updates_high_low(&regular), true);
+
+    let quote = Quote::parse(
+        serde_json::json!({"bid_exchange": 1, "ask_exchange": 12, "c": [1]})
+            .as_object()
+            .unwrap(),
+    );
+    assert_eq!(registry.bid_exchange(&quote).unwrap().id.unwrap(), 1);
+    assert_eq!(registry.ask_exchange(&quote).unwrap().id.unwrap(), 12);
+    assert_eq!(registry.quote_conditions(&quote).len(), 1);
+}
@@ -1,51 +1,309 @@
-use crate::{data_types, rest::error::ErrorCode};
+use crate::{
+    client::PolygonClient,
+    data_types,
+    rest::error::{ErrorCode, ErrorDetails},
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde_json::Value;
 
+//Everything but the RFC 3986 unreserved characters, so & # + and spaces cannot break the query
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+#[cfg(feature = "blocking")]
+static RUNTIME: std::sync::OnceLock<tokio::runtime::Runtime> = std::sync::OnceLock::new();
+
 pub struct Request {}
 
 impl Request {
-    #[tokio::main]
-    pub async fn request(url: String) -> Result<serde_json::Map<String, Value>, ErrorCode> {
-        let request = reqwest::get(url).await;
-        let r = match request {
-            Ok(response) => {
-                let response_text = response.text().await;
-                match response_text {
-                    Ok(text) => text,
-                    Err(e) => {
-                        println!("{}", e);
-                        return Err(ErrorCode::RequestError);
+    pub fn encode(value: &str) -> String {
+        utf8_percent_encode(value, QUERY_VALUE).to_string()
+    }
+
+    pub async fn request(
+        client: &PolygonClient,
+        url: String,
+    ) -> Result<serde_json::Map<String, Value>, ErrorCode> {
+        let mut attempt = 0;
+        loop {
+            if let Some(rate_limiter) = &client.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+            match Self::send(client, &url).await {
+                Ok(map) => return Ok(map),
+                Err(e) => {
+                    if !client.retry.should_retry(attempt, &e) {
+                        return Err(e);
                     }
+                    tokio::time::sleep(client.retry.backoff(attempt, &e)).await;
+                    attempt += 1;
                 }
             }
-            Err(e) => {
-                println!("{}", e);
-                return Err(ErrorCode::RequestError);
-            }
+        }
+    }
+
+    async fn send(
+        client: &PolygonClient,
+        url: &str,
+    ) -> Result<serde_json::Map<String, Value>, ErrorCode> {
+        let response = match client.http.get(url).send().await {
+            Ok(response) => response,
+            Err(e) => return Err(ErrorCode::from(e)),
+        };
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        let text = match response.text().await {
+            Ok(text) => text,
+            Err(e) => return Err(ErrorCode::from(e)),
         };
-        match serde_json::from_str(r.as_str()) {
-            Ok(map) => Ok(map),
-            Err(err) => {
-                println!("{}", err);
-                return Err(ErrorCode::JSONParseError);
+        if !status.is_success() {
+            return Err(ErrorCode::from_status(ErrorDetails::from_response(
+                status.as_u16(),
+                retry_after,
+                text.as_str(),
+            )));
+        }
+        match serde_json::from_str(text.as_str()) {
+            Ok(Value::Object(map)) => Ok(map),
+            //Some endpoints (market holidays) return a bare array, expose it under results
+            Ok(Value::Array(array)) => {
+                let mut map = serde_json::Map::new();
+                map.insert(String::from("results"), Value::Array(array));
+                Ok(map)
             }
+            Ok(_) => Err(ErrorCode::JSONParseError(ErrorDetails {
+                status: Some(status.as_u16()),
+                message: Some(text),
+                ..ErrorDetails::default()
+            })),
+            Err(e) => Err(ErrorCode::from(e)),
         }
     }
-    pub fn next<T: data_types::Parse>(
+
+    pub async fn next_async<T: data_types::Parse>(
+        client: &PolygonClient,
         url: Option<String>,
-        api_key: String,
     ) -> Result<T, ErrorCode> {
-        if url.is_none() {
-            return Err(ErrorCode::NoNextURL);
-        }
-        let next_url = if let Some(next_url) = url {
-            format!("{}&apiKey={}", next_url, api_key)
-        } else {
-            return Err(ErrorCode::NoNextURL);
+        let next_url = match url {
+            Some(next_url) => format!("{}&apiKey={}", client.rebase_url(&next_url), client.api_key),
+            None => return Err(ErrorCode::NoNextURL),
         };
-        match Self::request(next_url) {
-            Ok(mut map) => Ok(T::parse(&mut map)),
-            Err(e) => return Err(e),
+        match Self::request(client, next_url).await {
+            Ok(map) => Ok(T::parse(&map)),
+            Err(e) => Err(e),
         }
     }
+
+    #[cfg(feature = "blocking")]
+    pub fn next<T: data_types::Parse>(
+        client: &PolygonClient,
+        url: Option<String>,
+    ) -> Result<T, ErrorCode> {
+        Self::blocking(Self::next_async(client, url))
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn blocking<F: std::future::Future>(future: F) -> F::Output {
+        RUNTIME
+            .get_or_init(|| match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(e) => panic!(
+                    "The follow error code: {} occurred due to {}",
+                    ErrorCode::ClientError(ErrorDetails::default()),
+                    e
+                ),
+            })
+            .block_on(future)
+    }
+}
+
+#[tokio::test]
+async fn test_request_errors() {
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    for (status, route) in [(401, "/401"), (403, "/403"), (404, "/404"), (500, "/500")] {
+        Mock::given(path(route))
+            .respond_with(
+                ResponseTemplate::new(status).set_body_json(serde_json::json!({
+                    "status": "ERROR",
+                    "request_id": "req12345",
+                    "error": "error",
+                    "message": "message"
+                })),
+            )
+            .mount(&server)
+            .await;
+    }
+    Mock::given(path("/429"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "7"))
+        .mount(&server)
+        .await;
+    Mock::given(path("/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+        .mount(&server)
+        .await;
+    let client = PolygonClient::with_config(crate::client::ClientConfig {
+        api_key: String::from("apiKey"),
+        retry: crate::tools::retry::RetryPolicy::none(),
+        ..crate::client::ClientConfig::default()
+    })
+    .unwrap();
+    let get = |route: &str| Request::request(&client, format!("{}{}", server.uri(), route));
+    let unauthorized = get("/401").await.unwrap_err();
+    assert!(matches!(unauthorized, ErrorCode::Unauthorized(_)));
+    assert_eq!(unauthorized.status(), Some(401));
+    assert_eq!(unauthorized.request_id(), Some("req12345"));
+    assert_eq!(
+        unauthorized.details().unwrap().message,
+        Some(String::from("message"))
+    );
+    assert!(matches!(get("/403").await, Err(ErrorCode::Forbidden(_))));
+    assert!(matches!(get("/404").await, Err(ErrorCode::NotFound(_))));
+    assert!(matches!(get("/500").await, Err(ErrorCode::ServerError(_))));
+    let rate_limited = get("/429").await.unwrap_err();
+    assert!(matches!(rate_limited, ErrorCode::RateLimited(_)));
+    assert_eq!(rate_limited.details().unwrap().retry_after, Some(7));
+    let json = get("/json").await.unwrap_err();
+    assert!(matches!(json, ErrorCode::JSONParseError(_)));
+    assert!(std::error::Error::source(&json).is_some());
+}
+
+#[tokio::test]
+async fn test_request_array() {
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(path("/array"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"p": 1.0}])))
+        .mount(&server)
+        .await;
+    Mock::given(path("/string"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!("OK")))
+        .mount(&server)
+        .await;
+    let client = PolygonClient::new("apiKey").unwrap();
+    let map = Request::request(&client, format!("{}/array", server.uri()))
+        .await
+        .unwrap();
+    assert_eq!(
+        map.get("results")
+            .and_then(|v| v.as_array())
+            .map(|v| v.len()),
+        Some(1)
+    );
+    let string = Request::request(&client, format!("{}/string", server.uri())).await;
+    assert!(matches!(string, Err(ErrorCode::JSONParseError(_))));
+}
+
+#[tokio::test]
+async fn test_request_retry() {
+    use crate::{client::ClientConfig, tools::retry::RetryPolicy};
+    use std::time::Duration;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(path("/flaky"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(path("/flaky"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "OK"})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = PolygonClient::with_config(ClientConfig {
+        api_key: String::from("apiKey"),
+        retry: RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            ..RetryPolicy::default()
+        },
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    let map = Request::request(&client, format!("{}/flaky", server.uri()))
+        .await
+        .unwrap();
+    assert_eq!(map.get("status").and_then(|v| v.as_str()), Some("OK"));
+    let missing = Request::request(&client, format!("{}/missing", server.uri())).await;
+    assert!(matches!(missing, Err(ErrorCode::NotFound(_))));
+}
+
+#[test]
+fn test_encode() {
+    assert_eq!(Request::encode("AT&T"), "AT%26T");
+    assert_eq!(Request::encode("S&P 500"), "S%26P%20500");
+    assert_eq!(Request::encode("a+b#c"), "a%2Bb%23c");
+    assert_eq!(Request::encode("Apple Inc."), "Apple%20Inc.");
+    assert_eq!(Request::encode("2021-04-25"), "2021-04-25");
+}
+
+#[tokio::test]
+async fn test_request_hides_api_key() {
+    use crate::{client::ClientConfig, tools::retry::RetryPolicy};
+    use std::time::Duration;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const API_KEY: &str = "ak123456789012345678901234567890";
+    let formatted = |error: &ErrorCode| {
+        let mut chain = vec![error.to_string(), format!("{:?}", error)];
+        let mut source = std::error::Error::source(error);
+        while let Some(e) = source {
+            chain.push(e.to_string());
+            chain.push(format!("{:?}", e));
+            source = e.source();
+        }
+        chain.join("\n")
+    };
+    let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", closed.local_addr().unwrap());
+    drop(closed);
+    let client = PolygonClient::with_config(ClientConfig {
+        api_key: String::from(API_KEY),
+        base_url: base_url.clone(),
+        timeout: Some(Duration::from_millis(100)),
+        retry: RetryPolicy::none(),
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    let refused = Request::next_async::<crate::rest::market::aggregates::Aggregates>(
+        &client,
+        Some(format!(
+            "{}/v2/aggs/ticker/AAPL/range/1/day/2023-01-09/2023-01-09?cursor=abc",
+            PolygonClient::DEFAULT_BASE_URL
+        )),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(refused, ErrorCode::RequestError(_)));
+    assert!(!formatted(&refused).contains(API_KEY));
+
+    let server = MockServer::start().await;
+    Mock::given(path("/slow"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(1)))
+        .mount(&server)
+        .await;
+    let timeout = Request::request(&client, format!("{}/slow?apiKey={}", server.uri(), API_KEY))
+        .await
+        .unwrap_err();
+    assert!(matches!(timeout, ErrorCode::Timeout(_)));
+    assert!(!formatted(&timeout).contains(API_KEY));
 }
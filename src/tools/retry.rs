@@ -0,0 +1,76 @@
+use crate::rest::error::ErrorCode;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    pub fn should_retry(&self, attempt: u32, error: &ErrorCode) -> bool {
+        attempt < self.max_retries && error.is_retryable()
+    }
+
+    pub fn backoff(&self, attempt: u32, error: &ErrorCode) -> Duration {
+        if let Some(retry_after) = error.details().and_then(|details| details.retry_after) {
+            return Duration::from_secs(retry_after).min(self.max_backoff);
+        }
+        let exponential = self.initial_backoff.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = exponential.min(self.max_backoff.as_secs_f64());
+        //Full jitter spreads retries from many workers sharing a key across the whole window
+        let backoff = if self.jitter {
+            capped * fastrand::f64()
+        } else {
+            capped
+        };
+        Duration::from_secs_f64(backoff)
+    }
+}
+
+#[test]
+fn test_backoff() {
+    use crate::rest::error::ErrorDetails;
+
+    let policy = RetryPolicy {
+        jitter: false,
+        ..RetryPolicy::default()
+    };
+    let error = ErrorCode::ServerError(ErrorDetails::default());
+    assert_eq!(policy.backoff(0, &error), Duration::from_millis(500));
+    assert_eq!(policy.backoff(1, &error), Duration::from_millis(1000));
+    assert_eq!(policy.backoff(2, &error), Duration::from_millis(2000));
+    assert_eq!(policy.backoff(10, &error), Duration::from_secs(30));
+    let rate_limited = ErrorCode::RateLimited(ErrorDetails {
+        retry_after: Some(7),
+        ..ErrorDetails::default()
+    });
+    assert_eq!(policy.backoff(0, &rate_limited), Duration::from_secs(7));
+    let jittered = RetryPolicy::default().backoff(1, &error);
+    assert!(jittered <= Duration::from_millis(1000));
+    assert!(policy.should_retry(0, &error));
+    assert!(!policy.should_retry(3, &error));
+    assert!(!policy.should_retry(0, &ErrorCode::NotFound(ErrorDetails::default())));
+}
@@ -0,0 +1,217 @@
+use crate::{data_types::market_holiday::MarketHoliday, rest::reference::holidays::Holidays};
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc, Weekday};
+use std::collections::{HashMap, HashSet};
+
+//Only the holidays Polygon returns as upcoming are known, so the calendar answers from the earliest of them to the latest
+//Dates outside that range return None rather than treating unknown holidays as full sessions
+#[derive(Clone, Debug, Default)]
+pub struct TradingCalendar {
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    closed: HashSet<NaiveDate>,
+    early_closes: HashMap<NaiveDate, Option<DateTime<Utc>>>,
+}
+
+impl TradingCalendar {
+    pub fn new(holidays: &Holidays, exchange: &str) -> Self {
+        match &holidays.holidays {
+            Some(holidays) => Self::from_holidays(holidays, exchange),
+            None => Self::default(),
+        }
+    }
+
+    pub fn from_holidays(holidays: &[MarketHoliday], exchange: &str) -> Self {
+        let mut calendar = Self::default();
+        for holiday in holidays {
+            match &holiday.exchange {
+                Some(e) if e.eq_ignore_ascii_case(exchange) => {}
+                _ => continue,
+            }
+            let date = match holiday
+                .date
+                .as_deref()
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            {
+                Some(date) => date,
+                None => continue,
+            };
+            if calendar.start.is_none_or(|start| date < start) {
+                calendar.start = Some(date);
+            }
+            if calendar.end.is_none_or(|end| date > end) {
+                calendar.end = Some(date);
+            }
+            match holiday.status.as_deref() {
+                Some("closed") => {
+                    calendar.closed.insert(date);
+                }
+                Some("early-close") => {
+                    let close = holiday
+                        .close
+                        .as_deref()
+                        .and_then(|c| DateTime::parse_from_rfc3339(c).ok())
+                        .map(|c| c.with_timezone(&Utc));
+                    calendar.early_closes.insert(date, close);
+                }
+                _ => (),
+            }
+        }
+        calendar
+    }
+
+    pub fn start(&self) -> Option<NaiveDate> {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<NaiveDate> {
+        self.end
+    }
+
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.start.is_some_and(|start| date >= start) && self.end.is_some_and(|end| date <= end)
+    }
+
+    pub fn is_session(&self, date: NaiveDate) -> Option<bool> {
+        if !self.covers(date) {
+            return None;
+        }
+        Some(!matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.closed.contains(&date))
+    }
+
+    pub fn is_early_close(&self, date: NaiveDate) -> Option<bool> {
+        if !self.covers(date) {
+            return None;
+        }
+        Some(self.early_closes.contains_key(&date))
+    }
+
+    pub fn early_close(&self, date: NaiveDate) -> Option<DateTime<Utc>> {
+        self.early_closes.get(&date).copied().flatten()
+    }
+
+    //None once the walk forward leaves the known range
+    pub fn next_trading_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut next = date;
+        loop {
+            next = next + Days::new(1);
+            if self.is_session(next)? {
+                return Some(next);
+            }
+        }
+    }
+
+    //None once the walk back leaves the known range
+    pub fn previous_trading_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut previous = date;
+        loop {
+            previous = previous - Days::new(1);
+            if self.is_session(previous)? {
+                return Some(previous);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_trading_calendar() {
+    use crate::data_types::Parse;
+
+    let data = serde_json::json!({
+        "results": [
+            {
+                "date": "2020-09-07",
+                "exchange": "NYSE",
+                "name": "Labor Day",
+                "status": "closed"
+            },
+            {
+                "date": "2020-11-26",
+                "exchange": "NYSE",
+                "name": "Thanksgiving",
+                "status": "closed"
+            },
+            {
+                "close": "2020-11-27T18:00:00.000Z",
+                "date": "2020-11-27",
+                "exchange": "NYSE",
+                "name": "Thanksgiving",
+                "open": "2020-11-27T14:30:00.000Z",
+                "status": "early-close"
+            },
+            {
+                "date": "2020-12-25",
+                "exchange": "NASDAQ",
+                "name": "Christmas",
+                "status": "closed"
+            },
+            {
+                "date": "2021-01-01",
+                "exchange": "NYSE",
+                "name": "New Years Day",
+                "status": "closed"
+            }
+        ]
+    });
+    let holidays = Holidays::parse(&data.as_object().unwrap());
+    let calendar = TradingCalendar::new(&holidays, "nyse");
+    let date = |d: &str| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap();
+    assert_eq!(calendar.is_session(date("2020-11-25")), Some(true));
+    assert_eq!(calendar.is_session(date("2020-11-26")), Some(false));
+    assert_eq!(calendar.is_session(date("2020-11-27")), Some(true));
+    assert_eq!(calendar.is_session(date("2020-11-28")), Some(false));
+    assert_eq!(calendar.is_session(date("2020-12-25")), Some(true));
+    assert_eq!(
+        calendar.next_trading_day(date("2020-11-25")),
+        Some(date("2020-11-27"))
+    );
+    assert_eq!(
+        calendar.next_trading_day(date("2020-11-27")),
+        Some(date("2020-11-30"))
+    );
+    assert_eq!(
+        calendar.previous_trading_day(date("2020-11-30")),
+        Some(date("2020-11-27"))
+    );
+    assert_eq!(
+        calendar.previous_trading_day(date("2020-11-27")),
+        Some(date("2020-11-25"))
+    );
+    assert_eq!(calendar.is_early_close(date("2020-11-27")), Some(true));
+    assert_eq!(
+        calendar
+            .early_close(date("2020-11-27"))
+            .unwrap()
+            .to_rfc3339(),
+        "2020-11-27T18:00:00+00:00"
+    );
+    assert_eq!(calendar.early_close(date("2020-11-25")), None);
+
+    //Holidays before the earliest one Polygon returned are unknown
+    assert_eq!(calendar.start(), Some(date("2020-09-07")));
+    assert_eq!(calendar.is_session(date("2020-07-03")), None);
+    assert_eq!(calendar.is_session(date("2020-09-04")), None);
+    assert_eq!(calendar.is_early_close(date("2020-09-04")), None);
+    assert_eq!(calendar.is_session(date("2020-09-07")), Some(false));
+    assert_eq!(calendar.previous_trading_day(date("2020-09-08")), None);
+    assert_eq!(calendar.next_trading_day(date("2020-09-03")), None);
+    assert_eq!(
+        calendar.next_trading_day(date("2020-09-06")),
+        Some(date("2020-09-08"))
+    );
+
+    //Nor are any after the latest, such as the next Christmas
+    assert_eq!(calendar.end(), Some(date("2021-01-01")));
+    assert_eq!(calendar.is_session(date("2021-01-01")), Some(false));
+    assert_eq!(calendar.is_session(date("2021-01-04")), None);
+    assert_eq!(calendar.is_session(date("2021-12-24")), None);
+    assert_eq!(calendar.is_early_close(date("2021-11-26")), None);
+    assert_eq!(calendar.next_trading_day(date("2020-12-31")), None);
+    assert_eq!(
+        calendar.previous_trading_day(date("2021-01-01")),
+        Some(date("2020-12-31"))
+    );
+    assert_eq!(
+        TradingCalendar::default().is_session(date("2020-11-25")),
+        None
+    );
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate};
+use chrono::{DateTime, NaiveDate, Utc};
 
 use crate::rest::{
     error::ErrorCode,
@@ -15,6 +15,18 @@ impl Verification {
             Parameter::From => ErrorCode::DateFromError,
             Parameter::To => ErrorCode::DateToError,
             Parameter::Date => ErrorCode::DateError,
+            Parameter::PayDate | Parameter::PayDateFrom | Parameter::PayDateTo => {
+                ErrorCode::PayDateError
+            }
+            Parameter::RecordDate | Parameter::RecordDateFrom | Parameter::RecordDateTo => {
+                ErrorCode::RecordDateError
+            }
+            Parameter::DeclarationDate
+            | Parameter::DeclarationDateFrom
+            | Parameter::DeclarationDateTo => ErrorCode::DeclarationDateError,
+            Parameter::PeriodOfReportDate
+            | Parameter::PeriodOfReportDateFrom
+            | Parameter::PeriodOfReportDateTo => ErrorCode::PeriodOfReportDateError,
             _ => ErrorCode::WrongParameterType,
         }
     }
@@ -71,10 +83,117 @@ impl Verification {
         Ok(())
     }
 
-    fn verify_to_from_ticker() -> Result<(), ErrorCode> {
+    fn verify_to_from_cash_amount(parameters: &Parameters) -> Result<(), ErrorCode> {
+        if let (Some(from), Some(to)) = (&parameters.cash_amount_from, &parameters.cash_amount_to) {
+            if to < from {
+                return Err(ErrorCode::CashAmountToError);
+            }
+        }
         Ok(())
     }
 
+    fn verify_date_range(
+        from: &Option<String>,
+        to: &Option<String>,
+        error: ErrorCode,
+    ) -> Result<(), ErrorCode> {
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+        let parse = |date: &str| match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+            Ok(d) => d.and_hms_opt(0, 0, 0).map(|d| d.and_utc()),
+            Err(_) => date.parse::<i64>().ok().map(DateTime::from_timestamp_nanos),
+        };
+        match (parse(from), parse(to)) {
+            (Some(from), Some(to)) if to >= from => Ok(()),
+            _ => Err(error),
+        }
+    }
+
+    //Dates are midnight UTC, anything else must carry its offset as RFC3339
+    fn timestamp(value: &str) -> Option<DateTime<Utc>> {
+        if RegexPatterns::string_date().is_match(value) {
+            return NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|d| d.and_utc());
+        }
+        DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|d| d.with_timezone(&Utc))
+    }
+
+    fn verify_published_utc<T: ToString>(value: &T) -> Result<(), ErrorCode> {
+        match Self::timestamp(value.to_string().as_str()) {
+            Some(_) => Ok(()),
+            None => Err(ErrorCode::PublishedUtcError),
+        }
+    }
+
+    //An exact value and a range over the same field conflict, so neither is dropped in favour of the other
+    fn verify_value_or_range<T>(
+        value: &Option<T>,
+        from: &Option<T>,
+        to: &Option<T>,
+        error: ErrorCode,
+    ) -> Result<(), ErrorCode> {
+        if value.is_some() && (from.is_some() || to.is_some()) {
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn verify_to_from_published_utc(parameters: &Parameters) -> Result<(), ErrorCode> {
+        Self::verify_value_or_range(
+            &parameters.published_utc,
+            &parameters.published_utc_from,
+            &parameters.published_utc_to,
+            ErrorCode::PublishedUtcRangeError,
+        )?;
+        let (from, to) = match (&parameters.published_utc_from, &parameters.published_utc_to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+        match (Self::timestamp(from), Self::timestamp(to)) {
+            (Some(from), Some(to)) if to >= from => Ok(()),
+            _ => Err(ErrorCode::PublishedUtcError),
+        }
+    }
+
+    fn verify_to_from_dates(parameters: &Parameters) -> Result<(), ErrorCode> {
+        Self::verify_to_from_published_utc(parameters)?;
+        Self::verify_date_range(
+            &parameters.pay_date_from,
+            &parameters.pay_date_to,
+            ErrorCode::PayDateError,
+        )?;
+        Self::verify_date_range(
+            &parameters.record_date_from,
+            &parameters.record_date_to,
+            ErrorCode::RecordDateError,
+        )?;
+        Self::verify_date_range(
+            &parameters.declaration_date_from,
+            &parameters.declaration_date_to,
+            ErrorCode::DeclarationDateError,
+        )?;
+        Self::verify_date_range(
+            &parameters.period_of_report_date_from,
+            &parameters.period_of_report_date_to,
+            ErrorCode::PeriodOfReportDateError,
+        )
+    }
+
+    fn verify_to_from_ticker(parameters: &Parameters) -> Result<(), ErrorCode> {
+        Self::verify_value_or_range(
+            &parameters.ticker,
+            &parameters.ticker_from,
+            &parameters.ticker_to,
+            ErrorCode::TickerRangeError,
+        )
+    }
+
     fn verify_api_key(parameters: &Parameters) -> Result<(), ErrorCode> {
         if !RegexPatterns::api_key().is_match(&parameters.api_key.as_str()) {
             return Err(ErrorCode::APIError);
@@ -107,6 +226,30 @@ impl Verification {
         }
     }
 
+    fn verify_pattern<T: ToString>(value: &T, parameter_type: &Parameter) -> Result<(), ErrorCode> {
+        let value = value.to_string();
+        let (pattern, error) = match parameter_type {
+            Parameter::Exchange => (RegexPatterns::exchange_mic(), ErrorCode::ExchangeError),
+            Parameter::Cik => (RegexPatterns::cik(), ErrorCode::CIKError),
+            Parameter::Cusip => (RegexPatterns::cusip(), ErrorCode::CUSIPError),
+            Parameter::Sic => (RegexPatterns::sic(), ErrorCode::SICError),
+            Parameter::Identifier => {
+                //A ticker, CUSIP or composite FIGI all identify the same company
+                if RegexPatterns::cusip().is_match(value.as_str())
+                    || RegexPatterns::figi().is_match(value.as_str())
+                {
+                    return Ok(());
+                }
+                (RegexPatterns::stocks_ticker(), ErrorCode::IdentifierError)
+            }
+            _ => return Err(ErrorCode::WrongParameterType),
+        };
+        match pattern.is_match(value.as_str()) {
+            true => Ok(()),
+            false => Err(error),
+        }
+    }
+
     fn verify_stock_ticker(ticker: String) -> Result<(), ErrorCode> {
         if !RegexPatterns::stocks_ticker().is_match(ticker.as_str()) {
             return Err(ErrorCode::TickerError);
@@ -374,6 +517,26 @@ impl Verification {
                 Parameter::To => Self::verify_date(p, parameter_type),
                 Parameter::From => Self::verify_date(p, parameter_type),
                 Parameter::Timestamp => Self::verify_timestamp(p, parameter_type),
+                Parameter::Exchange => Self::verify_pattern(p, parameter_type),
+                Parameter::Cik => Self::verify_pattern(p, parameter_type),
+                Parameter::Cusip => Self::verify_pattern(p, parameter_type),
+                Parameter::Sic => Self::verify_pattern(p, parameter_type),
+                Parameter::Identifier => Self::verify_pattern(p, parameter_type),
+                Parameter::PayDate
+                | Parameter::PayDateFrom
+                | Parameter::PayDateTo
+                | Parameter::RecordDate
+                | Parameter::RecordDateFrom
+                | Parameter::RecordDateTo
+                | Parameter::DeclarationDate
+                | Parameter::DeclarationDateFrom
+                | Parameter::DeclarationDateTo
+                | Parameter::PeriodOfReportDate
+                | Parameter::PeriodOfReportDateFrom
+                | Parameter::PeriodOfReportDateTo => Self::verify_date(p, parameter_type),
+                Parameter::PublishedUtc
+                | Parameter::PublishedUtcFrom
+                | Parameter::PublishedUtcTo => Self::verify_published_utc(p),
                 _ => Ok(()),
             },
             None => {
@@ -396,6 +559,41 @@ impl Verification {
                         Parameter::Precision => return Err(ErrorCode::PrecisionNotSet),
                         Parameter::Direction => return Err(ErrorCode::DirectionNotSet),
                         Parameter::TickerType => return Err(ErrorCode::TickerTypeNotSet),
+                        Parameter::Search => return Err(ErrorCode::SearchNotSet),
+                        Parameter::Market => return Err(ErrorCode::MarketNotSet),
+                        Parameter::Exchange => return Err(ErrorCode::ExchangeNotSet),
+                        Parameter::Type => return Err(ErrorCode::TypeNotSet),
+                        Parameter::Cik => return Err(ErrorCode::CIKNotSet),
+                        Parameter::Cusip => return Err(ErrorCode::CUSIPNotSet),
+                        Parameter::Active => return Err(ErrorCode::ActiveNotSet),
+                        Parameter::PayDate => return Err(ErrorCode::PayDateNotSet),
+                        Parameter::RecordDate => return Err(ErrorCode::RecordDateNotSet),
+                        Parameter::DeclarationDate => {
+                            return Err(ErrorCode::DeclarationDateNotSet)
+                        }
+                        Parameter::CashAmount => return Err(ErrorCode::CashAmountNotSet),
+                        Parameter::Frequency => return Err(ErrorCode::FrequencyNotSet),
+                        Parameter::DividendType => return Err(ErrorCode::DividendTypeNotSet),
+                        Parameter::ReverseSplit => return Err(ErrorCode::ReverseSplitNotSet),
+                        Parameter::CompanyName => return Err(ErrorCode::CompanyNameNotSet),
+                        Parameter::Sic => return Err(ErrorCode::SICNotSet),
+                        Parameter::PeriodOfReportDate => {
+                            return Err(ErrorCode::PeriodOfReportDateNotSet)
+                        }
+                        Parameter::FinancialTimeframe => {
+                            return Err(ErrorCode::FinancialTimeframeNotSet)
+                        }
+                        Parameter::IncludeSources => return Err(ErrorCode::IncludeSourcesNotSet),
+                        Parameter::AssetClass => return Err(ErrorCode::AssetClassNotSet),
+                        Parameter::Locale => return Err(ErrorCode::LocaleNotSet),
+                        Parameter::DataType => return Err(ErrorCode::DataTypeNotSet),
+                        Parameter::Id => return Err(ErrorCode::IdNotSet),
+                        Parameter::Sip => return Err(ErrorCode::SipNotSet),
+                        Parameter::Identifier => return Err(ErrorCode::IdentifierNotSet),
+                        Parameter::EventType => return Err(ErrorCode::EventTypeNotSet),
+                        Parameter::PublishedUtc
+                        | Parameter::PublishedUtcFrom
+                        | Parameter::PublishedUtcTo => return Err(ErrorCode::PublishedUtcNotSet),
                         _ => return Err(ErrorCode::WrongParameterType),
                     }
                 };
@@ -409,277 +607,424 @@ impl Verification {
         parameter_requirements: &'static [&'static ParameterRequirment],
         parameters: &Parameters,
     ) -> Result<(), ErrorCode> {
-        if let Err(check) = Self::verify_api_key(parameters) {
-            return Err(check);
-        }
+        Self::verify_api_key(parameters)?;
         for parameter in parameter_requirements {
             match parameter.parameter {
                 Parameter::Ticker => {
-                    if let Err(check) =
-                        Self::verify_ticker(parameter.required, ticker_types, parameters)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify_ticker(parameter.required, ticker_types, parameters)?;
                 }
                 Parameter::Tickers => {
-                    if let Err(check) =
-                        Self::verify_tickers(parameter.required, ticker_types, parameters)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify_tickers(parameter.required, ticker_types, parameters)?;
                 }
                 Parameter::UnderlyingAsset => {
-                    if let Err(check) =
-                        Self::verify_underlying_asset(parameter.required, parameters)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify_underlying_asset(parameter.required, parameters)?;
                 }
                 Parameter::TickerFrom => {}
                 Parameter::TickerTo => {}
                 Parameter::Date => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.date, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.date, &parameter.parameter)?;
                 }
                 Parameter::TickerType => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.ticker_type,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Adjusted => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.adjusted,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Sort => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.sort, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.sort, &parameter.parameter)?;
                 }
                 Parameter::Limit => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.adjusted,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Timespan => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.timespan,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::From => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.from, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.from, &parameter.parameter)?;
                 }
                 Parameter::To => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.to, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.to, &parameter.parameter)?;
                 }
                 Parameter::Multiplier => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.multiplier,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::IncludeOTC => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.include_otc,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Order => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.order, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.order, &parameter.parameter)?;
                 }
                 Parameter::Sortv3 => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.sortv3, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.sortv3, &parameter.parameter)?;
                 }
                 Parameter::Timestamp => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.timestamp,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::ContractType => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.contract_type,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::StrikePrice => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.strike_price,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::StrikePriceFrom => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.strike_price_from,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::StrikePriceTo => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.strike_price_to,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Amount => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.amount, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.amount, &parameter.parameter)?;
                 }
                 Parameter::Precision => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.precision,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Direction => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.direction,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::ExpandUnderlying => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.expand_underlying,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::SeriesType => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.series_type,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::Window => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.window, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.window, &parameter.parameter)?;
                 }
                 Parameter::LongWindow => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.long_window,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::ShortWindow => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.short_window,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::SignalWindow => {
-                    if let Err(check) = Self::verify(
+                    Self::verify(
                         parameter.required,
                         &parameters.signal_window,
                         &parameter.parameter,
-                    ) {
-                        return Err(check);
-                    }
+                    )?;
                 }
                 Parameter::AsOf => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.as_of, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(parameter.required, &parameters.as_of, &parameter.parameter)?;
                 }
                 Parameter::Expired => {
-                    if let Err(check) =
-                        Self::verify(parameter.required, &parameters.expired, &parameter.parameter)
-                    {
-                        return Err(check);
-                    }
+                    Self::verify(
+                        parameter.required,
+                        &parameters.expired,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Search => {
+                    Self::verify(parameter.required, &parameters.search, &parameter.parameter)?;
+                }
+                Parameter::Market => {
+                    Self::verify(parameter.required, &parameters.market, &parameter.parameter)?;
+                }
+                Parameter::Exchange => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.exchange,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Type => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.type_code,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Cik => {
+                    Self::verify(parameter.required, &parameters.cik, &parameter.parameter)?;
+                }
+                Parameter::Cusip => {
+                    Self::verify(parameter.required, &parameters.cusip, &parameter.parameter)?;
+                }
+                Parameter::Active => {
+                    Self::verify(parameter.required, &parameters.active, &parameter.parameter)?;
+                }
+                Parameter::PayDate => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.pay_date,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PayDateFrom => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.pay_date_from,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PayDateTo => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.pay_date_to,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::RecordDate => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.record_date,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::RecordDateFrom => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.record_date_from,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::RecordDateTo => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.record_date_to,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::DeclarationDate => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.declaration_date,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::DeclarationDateFrom => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.declaration_date_from,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::DeclarationDateTo => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.declaration_date_to,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::CashAmount => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.cash_amount,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::CashAmountFrom => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.cash_amount_from,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::CashAmountTo => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.cash_amount_to,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Frequency => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.frequency,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::DividendType => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.dividend_type,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::ReverseSplit => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.reverse_split,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::CompanyName => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.company_name,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Sic => {
+                    Self::verify(parameter.required, &parameters.sic, &parameter.parameter)?;
+                }
+                Parameter::PeriodOfReportDate => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.period_of_report_date,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PeriodOfReportDateFrom => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.period_of_report_date_from,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PeriodOfReportDateTo => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.period_of_report_date_to,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::FinancialTimeframe => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.financial_timeframe,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::IncludeSources => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.include_sources,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::AssetClass => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.asset_class,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Locale => {
+                    Self::verify(parameter.required, &parameters.locale, &parameter.parameter)?;
+                }
+                Parameter::DataType => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.data_type,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::Id => {
+                    Self::verify(parameter.required, &parameters.id, &parameter.parameter)?;
+                }
+                Parameter::Sip => {
+                    Self::verify(parameter.required, &parameters.sip, &parameter.parameter)?;
+                }
+                Parameter::Identifier => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.identifier,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::EventType => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.event_type,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PublishedUtc => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.published_utc,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PublishedUtcFrom => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.published_utc_from,
+                        &parameter.parameter,
+                    )?;
+                }
+                Parameter::PublishedUtcTo => {
+                    Self::verify(
+                        parameter.required,
+                        &parameters.published_utc_to,
+                        &parameter.parameter,
+                    )?;
                 }
             }
         }
-        if let Err(check) = Self::verify_to_from(parameters) {
-            return Err(check);
-        }
-        if let Err(check) = Self::verify_to_from_strike_price(parameters) {
-            return Err(check);
-        }
-        if let Err(check) = Self::verify_to_from_ticker() {
-            return Err(check);
-        }
+        Self::verify_to_from(parameters)?;
+        Self::verify_to_from_strike_price(parameters)?;
+        Self::verify_to_from_cash_amount(parameters)?;
+        Self::verify_to_from_dates(parameters)?;
+        Self::verify_to_from_ticker(parameters)?;
         Ok(())
     }
 }
@@ -746,6 +1091,87 @@ fn test_verify_to_from_strike_price() {
     );
 }
 
+#[test]
+fn test_verify_to_from_cash_amount() {
+    let mut parameters = Parameters::default();
+    parameters.cash_amount_from = Some(0.1);
+    parameters.cash_amount_to = Some(1.0);
+    assert_eq!(
+        Verification::verify_to_from_cash_amount(&parameters),
+        Ok(())
+    );
+    parameters.cash_amount_from = Some(1.0);
+    parameters.cash_amount_to = Some(0.1);
+    assert_eq!(
+        Verification::verify_to_from_cash_amount(&parameters),
+        Err(ErrorCode::CashAmountToError)
+    );
+}
+
+#[test]
+fn test_verify_to_from_dates() {
+    let mut parameters = Parameters::default();
+    parameters.pay_date_from = Some(String::from("2023-01-01"));
+    parameters.pay_date_to = Some(String::from("2023-02-01"));
+    assert_eq!(Verification::verify_to_from_dates(&parameters), Ok(()));
+    parameters.record_date_from = Some(String::from("2023-02-01"));
+    parameters.record_date_to = Some(String::from("2023-01-01"));
+    assert_eq!(
+        Verification::verify_to_from_dates(&parameters),
+        Err(ErrorCode::RecordDateError)
+    );
+}
+
+#[test]
+fn test_verify_published_utc() {
+    let verify = |value: &str| Verification::verify(true, &Some(value), &Parameter::PublishedUtc);
+    assert_eq!(verify("2024-06-24"), Ok(()));
+    assert_eq!(verify("2024-06-24T13:30:00Z"), Ok(()));
+    assert_eq!(verify("2024-06-24T09:30:00.5-04:00"), Ok(()));
+    assert_eq!(
+        verify("1719235800000000000"),
+        Err(ErrorCode::PublishedUtcError)
+    );
+    assert_eq!(
+        verify("2024-06-24T13:30:00"),
+        Err(ErrorCode::PublishedUtcError)
+    );
+    assert_eq!(
+        Verification::verify(true, &None::<String>, &Parameter::PublishedUtcFrom),
+        Err(ErrorCode::PublishedUtcNotSet)
+    );
+    let mut parameters = Parameters::default();
+    parameters.published_utc_from = Some(String::from("2024-06-24T09:30:00-04:00"));
+    parameters.published_utc_to = Some(String::from("2024-06-24T13:45:00Z"));
+    assert_eq!(Verification::verify_to_from_dates(&parameters), Ok(()));
+    parameters.published_utc_to = Some(String::from("2024-06-24T13:15:00Z"));
+    assert_eq!(
+        Verification::verify_to_from_dates(&parameters),
+        Err(ErrorCode::PublishedUtcError)
+    );
+    parameters.published_utc_to = None;
+    parameters.published_utc = Some(String::from("2024-06-24"));
+    assert_eq!(
+        Verification::verify_to_from_dates(&parameters),
+        Err(ErrorCode::PublishedUtcRangeError)
+    );
+}
+
+#[test]
+fn test_verify_to_from_ticker() {
+    let mut parameters = Parameters::default();
+    parameters.ticker = Some(String::from("AAPL"));
+    assert_eq!(Verification::verify_to_from_ticker(&parameters), Ok(()));
+    parameters.ticker_to = Some(String::from("MSFT"));
+    assert_eq!(
+        Verification::verify_to_from_ticker(&parameters),
+        Err(ErrorCode::TickerRangeError)
+    );
+    parameters.ticker = None;
+    parameters.ticker_from = Some(String::from("AAPL"));
+    assert_eq!(Verification::verify_to_from_ticker(&parameters), Ok(()));
+}
+
 #[test]
 fn test_verify_api_key() {
     let mut parameters = Parameters::default();
@@ -1145,6 +1571,48 @@ fn test_verify() {
     );
 }
 
+#[test]
+fn test_verify_pattern() {
+    let exchange = Some(String::from("XNAS"));
+    assert_eq!(
+        Verification::verify(true, &exchange, &Parameter::Exchange),
+        Ok(())
+    );
+    let cik = Some(String::from("CIK0000320193"));
+    assert_eq!(
+        Verification::verify(true, &cik, &Parameter::Cik),
+        Err(ErrorCode::CIKError)
+    );
+    let cusip = Some(String::from("03783310"));
+    assert_eq!(
+        Verification::verify(true, &cusip, &Parameter::Cusip),
+        Err(ErrorCode::CUSIPError)
+    );
+    let cusip: Option<String> = None;
+    assert_eq!(
+        Verification::verify(true, &cusip, &Parameter::Cusip),
+        Err(ErrorCode::CUSIPNotSet)
+    );
+    for identifier in ["META", "30303M102", "BBG000MM2P62"] {
+        assert_eq!(
+            Verification::verify(
+                true,
+                &Some(String::from(identifier)),
+                &Parameter::Identifier
+            ),
+            Ok(())
+        );
+    }
+    assert_eq!(
+        Verification::verify(
+            true,
+            &Some(String::from("meta platforms")),
+            &Parameter::Identifier
+        ),
+        Err(ErrorCode::IdentifierError)
+    );
+}
+
 #[test]
 fn test_check_parameters() {
     let mut parameters = Parameters::default();
@@ -1,3 +1,29 @@
+pub mod client;
+pub mod event;
+pub mod manager;
 pub mod market;
+pub mod parameters;
+#[cfg(test)]
+pub mod test_server;
 
-//To be implemented
+use market::{
+    fmv::FairMarketValueSubscription, l2::L2Subscription, minute::MinuteAggregatesSubscription,
+    quotes::QuotesSubscription, second::SecondAggregatesSubscription, trades::TradesSubscription,
+    value::ValueSubscription,
+};
+
+pub struct WebSocketRequest {}
+
+impl FairMarketValueSubscription for WebSocketRequest {}
+
+impl L2Subscription for WebSocketRequest {}
+
+impl MinuteAggregatesSubscription for WebSocketRequest {}
+
+impl QuotesSubscription for WebSocketRequest {}
+
+impl SecondAggregatesSubscription for WebSocketRequest {}
+
+impl TradesSubscription for WebSocketRequest {}
+
+impl ValueSubscription for WebSocketRequest {}
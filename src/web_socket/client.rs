@@ -0,0 +1,941 @@
+use crate::data_types::connection_status::ConnectionStatus;
+use crate::rest::{
+    error::{ErrorCode, ErrorDetails},
+    parameters::{Parameter, ParameterRequirment, Parameters},
+};
+use crate::tools::{regex_patterns::RegexPatterns, retry::RetryPolicy, verification::Verification};
+use crate::web_socket::{
+    event::Event,
+    parameters::{Channel, Cluster},
+};
+use futures::{SinkExt, Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    net::TcpStream,
+    sync::mpsc::{self, error::TrySendError},
+    time::{Instant, MissedTickBehavior},
+};
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[derive(Clone, Debug)]
+pub struct WebSocketConfig {
+    pub api_key: String,
+    pub base_url: String,
+    pub cluster: Cluster,
+    pub timeout: Duration, //Applies to the connect and authentication handshake
+    pub heartbeat: Duration, //Ping interval, the connection is considered dead after two intervals without a message
+    pub reconnect: RetryPolicy,
+    pub capacity: usize, //Events and commands buffered for the consumer, must be at least 1
+    pub overflow: Overflow,
+}
+
+//What happens to market data once the event queue is full
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Overflow {
+    Backpressure, //Stops reading the socket until the consumer catches up, Polygon disconnects consumers that fall too far behind
+    Drop, //Drops new events and reports how many were lost with Event::Lagged once there is room
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            api_key: String::new(),
+            base_url: WebSocketClient::DEFAULT_BASE_URL.to_string(),
+            cluster: Cluster::Stocks,
+            timeout: Duration::from_secs(10),
+            heartbeat: Duration::from_secs(30),
+            reconnect: RetryPolicy {
+                max_retries: 10,
+                ..RetryPolicy::default()
+            },
+            capacity: 10_000,
+            overflow: Overflow::Drop,
+        }
+    }
+}
+
+enum Command {
+    Subscribe(Vec<String>),
+    Unsubscribe(Vec<String>),
+}
+
+//The socket is owned by a background task, the client only holds the command and event channels
+pub struct WebSocketClient {
+    api_key: String,
+    cluster: Cluster,
+    commands: mpsc::Sender<Command>,
+    events: mpsc::Receiver<Result<Event, ErrorCode>>,
+}
+
+//Connection events always wait for room, market data follows the configured overflow
+struct EventQueue {
+    sender: mpsc::Sender<Result<Event, ErrorCode>>,
+    overflow: Overflow,
+    dropped: u64,
+}
+
+impl WebSocketClient {
+    pub const DEFAULT_BASE_URL: &str = "wss://socket.polygon.io";
+    pub const DELAYED_BASE_URL: &str = "wss://delayed.polygon.io";
+
+    pub async fn connect(api_key: &str, cluster: Cluster) -> Result<Self, ErrorCode> {
+        Self::with_config(WebSocketConfig {
+            api_key: api_key.to_string(),
+            cluster,
+            ..WebSocketConfig::default()
+        })
+        .await
+    }
+
+    pub async fn with_config(config: WebSocketConfig) -> Result<Self, ErrorCode> {
+        if !RegexPatterns::api_key().is_match(&config.api_key) {
+            return Err(ErrorCode::APIError);
+        }
+        let (sender, events) = mpsc::channel(config.capacity);
+        let mut queue = EventQueue {
+            sender,
+            overflow: config.overflow,
+            dropped: 0,
+        };
+        let socket = Self::handshake(&config, &mut queue).await?;
+        let (commands, commands_receiver) = mpsc::channel(config.capacity);
+        let api_key = config.api_key.clone();
+        let cluster = config.cluster;
+        tokio::spawn(Self::run(config, socket, commands_receiver, queue));
+        Ok(Self {
+            api_key,
+            cluster,
+            commands,
+            events,
+        })
+    }
+
+    pub fn cluster(&self) -> Cluster {
+        self.cluster
+    }
+
+    pub fn subscribe(&self, channel: Channel, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        let params = self.params(channel, tickers)?;
+        self.send(Command::Subscribe(params))
+    }
+
+    pub fn unsubscribe(&self, channel: Channel, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        let params = self.params(channel, tickers)?;
+        self.send(Command::Unsubscribe(params))
+    }
+
+    pub async fn next_event(&mut self) -> Option<Result<Event, ErrorCode>> {
+        self.events.recv().await
+    }
+
+    fn send(&self, command: Command) -> Result<(), ErrorCode> {
+        match self.commands.try_send(command) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) => Err(ErrorCode::WebSocketQueueFull),
+            Err(TrySendError::Closed(_)) => Err(ErrorCode::WebSocketClosed),
+        }
+    }
+
+    //Channel prefixed subscription params such as T.AAPL, * subscribes to every ticker
+    fn params(&self, channel: Channel, tickers: Vec<String>) -> Result<Vec<String>, ErrorCode> {
+        let prefix = channel.prefix(&self.cluster)?;
+        let mut params = Vec::new();
+        for ticker in tickers {
+            if ticker != "*" {
+                self.verify_ticker(&ticker)?;
+            }
+            params.push(format!("{}.{}", prefix, ticker));
+        }
+        Ok(params)
+    }
+
+    //Forex and crypto streams are keyed by pair, e.g. C:EUR-USD and X:BTC-USD, rather than the REST ticker
+    fn verify_ticker(&self, ticker: &str) -> Result<(), ErrorCode> {
+        match self.cluster {
+            Cluster::Forex if RegexPatterns::forex_pair().is_match(ticker) => Ok(()),
+            Cluster::Crypto if RegexPatterns::crypto_pair().is_match(ticker) => Ok(()),
+            Cluster::Forex | Cluster::Crypto => Err(ErrorCode::TickerError),
+            _ => {
+                let ticker_parameters = Parameters {
+                    api_key: self.api_key.clone(),
+                    ticker: Some(ticker.to_string()),
+                    ..Parameters::default()
+                };
+                Verification::check_parameters(
+                    &self.cluster.ticker_types(),
+                    PARAMETERS,
+                    &ticker_parameters,
+                )
+            }
+        }
+    }
+
+    async fn handshake(
+        config: &WebSocketConfig,
+        events: &mut EventQueue,
+    ) -> Result<Socket, ErrorCode> {
+        match tokio::time::timeout(config.timeout, Self::open(config, events)).await {
+            Ok(socket) => socket,
+            Err(_) => Err(ErrorCode::Timeout(ErrorDetails {
+                message: Some("WebSocket handshake timed out".to_string()),
+                ..ErrorDetails::default()
+            })),
+        }
+    }
+
+    //Statuses never wait here, the first handshake runs before anything can drain the queue
+    async fn open(config: &WebSocketConfig, events: &mut EventQueue) -> Result<Socket, ErrorCode> {
+        let url = format!(
+            "{}/{}",
+            config.base_url.trim_end_matches('/'),
+            config.cluster.to_string().to_lowercase()
+        );
+        let (mut socket, _) = tokio_tungstenite::connect_async(url).await?;
+        let mut authenticating = false;
+        loop {
+            let text = match socket.next().await {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(Message::Close(_))) | None => return Err(ErrorCode::WebSocketClosed),
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return Err(e.into()),
+            };
+            for event in Event::parse_message(&text)? {
+                let (status, message) = match &event {
+                    Event::Status(status) => (status.status.clone(), status.message.clone()),
+                    _ => continue,
+                };
+                events.try_send(Ok(event));
+                match status.as_deref() {
+                    Some("connected") if !authenticating => {
+                        let auth = serde_json::json!({"action": "auth", "params": config.api_key});
+                        socket.send(Message::Text(auth.to_string())).await?;
+                        authenticating = true;
+                    }
+                    Some("auth_success") => return Ok(socket),
+                    Some("auth_failed") => {
+                        return Err(ErrorCode::WebSocketAuthenticationError(ErrorDetails {
+                            message,
+                            ..ErrorDetails::default()
+                        }))
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    //Owns the socket until the client is dropped, reconnecting and replaying the subscriptions after a disconnect
+    async fn run(
+        config: WebSocketConfig,
+        mut socket: Socket,
+        mut commands: mpsc::Receiver<Command>,
+        mut events: EventQueue,
+    ) {
+        let mut subscriptions = Vec::new();
+        loop {
+            let mut error = match Self::session(
+                &config,
+                &mut socket,
+                &mut commands,
+                &mut events,
+                &mut subscriptions,
+            )
+            .await
+            {
+                Some(error) => error,
+                None => return,
+            };
+            if !events
+                .send(Ok(Self::status("disconnected", error.to_string())))
+                .await
+            {
+                return;
+            }
+            let mut attempt = 0;
+            socket = loop {
+                if !config.reconnect.should_retry(attempt, &error) || events.sender.is_closed() {
+                    events.send(Err(error)).await;
+                    return;
+                }
+                tokio::time::sleep(config.reconnect.backoff(attempt, &error)).await;
+                attempt += 1;
+                match Self::resume(&config, &mut events, &subscriptions).await {
+                    Ok(socket) => break socket,
+                    Err(e) => error = e,
+                }
+            };
+        }
+    }
+
+    //Consumers can backfill over REST between the disconnected and resubscribed timestamps
+    async fn resume(
+        config: &WebSocketConfig,
+        events: &mut EventQueue,
+        subscriptions: &[String],
+    ) -> Result<Socket, ErrorCode> {
+        let mut socket = Self::handshake(config, events).await?;
+        if !subscriptions.is_empty() {
+            Self::send_action(&mut socket, "subscribe", subscriptions).await?;
+            events
+                .send(Ok(Self::status("resubscribed", subscriptions.join(","))))
+                .await;
+        }
+        Ok(socket)
+    }
+
+    //Returns the error that ended the connection, or None once the client has been dropped
+    async fn session(
+        config: &WebSocketConfig,
+        socket: &mut Socket,
+        commands: &mut mpsc::Receiver<Command>,
+        events: &mut EventQueue,
+        subscriptions: &mut Vec<String>,
+    ) -> Option<ErrorCode> {
+        let mut heartbeat =
+            tokio::time::interval_at(Instant::now() + config.heartbeat, config.heartbeat);
+        heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut last_message = Instant::now();
+        loop {
+            tokio::select! {
+                command = commands.recv() => {
+                    let (action, params) = match command {
+                        Some(Command::Subscribe(params)) => {
+                            for param in &params {
+                                if !subscriptions.contains(param) {
+                                    subscriptions.push(param.clone());
+                                }
+                            }
+                            ("subscribe", params)
+                        }
+                        Some(Command::Unsubscribe(params)) => {
+                            subscriptions.retain(|s| !params.contains(s));
+                            ("unsubscribe", params)
+                        }
+                        None => {
+                            let _ = socket.close(None).await;
+                            return None;
+                        }
+                    };
+                    if let Err(e) = Self::send_action(socket, action, &params).await {
+                        return Some(e);
+                    }
+                }
+                _ = heartbeat.tick() => {
+                    if last_message.elapsed() >= config.heartbeat * 2 {
+                        return Some(ErrorCode::Timeout(ErrorDetails {
+                            message: Some("WebSocket heartbeat timed out".to_string()),
+                            ..ErrorDetails::default()
+                        }));
+                    }
+                    if let Err(e) = socket.send(Message::Ping(Vec::new())).await {
+                        return Some(e.into());
+                    }
+                }
+                message = socket.next() => {
+                    last_message = Instant::now();
+                    match message {
+                        Some(Ok(Message::Text(text))) => {
+                            let parsed = match Event::parse_message(&text) {
+                                Ok(parsed) => parsed.into_iter().map(Ok).collect(),
+                                Err(e) => vec![Err(e)],
+                            };
+                            for event in parsed {
+                                if !events.push(event).await {
+                                    return None;
+                                }
+                            }
+                            //Time spent waiting on the consumer under backpressure is not silence from the server
+                            last_message = Instant::now();
+                        }
+                        Some(Ok(Message::Close(_))) | None => return Some(ErrorCode::WebSocketClosed),
+                        //Pings are answered by tungstenite, pongs only need to refresh the heartbeat
+                        Some(Ok(_)) => (),
+                        Some(Err(e)) => return Some(e.into()),
+                    }
+                }
+            }
+        }
+    }
+
+    async fn send_action(
+        socket: &mut Socket,
+        action: &str,
+        params: &[String],
+    ) -> Result<(), ErrorCode> {
+        let message = serde_json::json!({"action": action, "params": params.join(",")});
+        socket.send(Message::Text(message.to_string())).await?;
+        Ok(())
+    }
+
+    fn status(status: &str, message: String) -> Event {
+        Event::Status(ConnectionStatus {
+            message: Some(message),
+            status: Some(status.to_string()),
+            timestamp: Some(chrono::Utc::now().timestamp_millis()),
+        })
+    }
+}
+
+//Each returns false once the client has been dropped
+impl EventQueue {
+    async fn send(&mut self, event: Result<Event, ErrorCode>) -> bool {
+        if self.dropped > 0 {
+            if self
+                .sender
+                .send(Ok(Event::Lagged(self.dropped)))
+                .await
+                .is_err()
+            {
+                return false;
+            }
+            self.dropped = 0;
+        }
+        self.sender.send(event).await.is_ok()
+    }
+
+    fn try_send(&mut self, event: Result<Event, ErrorCode>) -> bool {
+        if self.dropped > 0 {
+            match self.sender.try_send(Ok(Event::Lagged(self.dropped))) {
+                Ok(()) => self.dropped = 0,
+                Err(TrySendError::Full(_)) => {
+                    self.dropped += 1;
+                    return true;
+                }
+                Err(TrySendError::Closed(_)) => return false,
+            }
+        }
+        match self.sender.try_send(event) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                true
+            }
+            Err(TrySendError::Closed(_)) => false,
+        }
+    }
+
+    async fn push(&mut self, event: Result<Event, ErrorCode>) -> bool {
+        match self.overflow {
+            Overflow::Backpressure => self.send(event).await,
+            Overflow::Drop => self.try_send(event),
+        }
+    }
+}
+
+impl Stream for WebSocketClient {
+    type Item = Result<Event, ErrorCode>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+const PARAMETERS: &[&ParameterRequirment] = &[&ParameterRequirment {
+    required: true,
+    parameter: Parameter::Ticker,
+}];
+
+#[tokio::test]
+async fn test_web_socket_client() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        let subscribe = TestServer::receive(&mut socket).await;
+        assert_eq!(subscribe["action"], "subscribe");
+        assert_eq!(subscribe["params"], "T.AAPL,T.MSFT");
+        let subscribe = TestServer::receive(&mut socket).await;
+        assert_eq!(subscribe["params"], "Q.*");
+        TestServer::send(
+            &mut socket,
+            serde_json::json!([
+                {"ev": "T", "sym": "AAPL", "x": 4, "p": 150.25, "s": 100, "t": 1536036818784i64},
+                {"ev": "Q", "sym": "MSFT", "bx": 4, "bp": 114.125, "ax": 7, "ap": 114.128}
+            ]),
+        )
+        .await;
+        let unsubscribe = TestServer::receive(&mut socket).await;
+        assert_eq!(unsubscribe["action"], "unsubscribe");
+        assert_eq!(unsubscribe["params"], "T.AAPL");
+        socket.close(None).await.unwrap();
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        reconnect: RetryPolicy::none(),
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    assert!(matches!(
+        client.next_event().await,
+        Some(Ok(Event::Status(_)))
+    ));
+    assert!(matches!(
+        client.next_event().await,
+        Some(Ok(Event::Status(_)))
+    ));
+    assert!(matches!(
+        client.subscribe(Channel::Trades, vec![String::from("aapl")]),
+        Err(ErrorCode::TickerError)
+    ));
+    assert!(matches!(
+        client.subscribe(Channel::Trades, vec![String::from("O:AAPL230421C00200000")]),
+        Err(ErrorCode::TickerNotValidForAPICall)
+    ));
+    client
+        .subscribe(
+            Channel::Trades,
+            vec![String::from("AAPL"), String::from("MSFT")],
+        )
+        .unwrap();
+    client
+        .subscribe(Channel::Quotes, vec![String::from("*")])
+        .unwrap();
+    match client.next().await {
+        Some(Ok(Event::Trade(trade))) => {
+            assert_eq!(trade.ticker.unwrap(), "AAPL");
+            assert_eq!(trade.price.unwrap(), 150.25);
+        }
+        other => panic!("Expected a trade event, got {:?}", other),
+    }
+    match client.next().await {
+        Some(Ok(Event::Quote(quote))) => {
+            assert_eq!(quote.ticker.unwrap(), "MSFT");
+            assert_eq!(quote.ask.unwrap(), 114.128);
+        }
+        other => panic!("Expected a quote event, got {:?}", other),
+    }
+    client
+        .unsubscribe(Channel::Trades, vec![String::from("AAPL")])
+        .unwrap();
+    handle.await.unwrap();
+    match client.next_event().await {
+        Some(Ok(Event::Status(status))) => {
+            assert_eq!(status.status.unwrap(), "disconnected");
+            assert!(status.timestamp.is_some());
+        }
+        other => panic!("Expected a disconnected status, got {:?}", other),
+    }
+    assert!(matches!(
+        client.next_event().await,
+        Some(Err(ErrorCode::WebSocketClosed))
+    ));
+}
+
+#[tokio::test]
+async fn test_web_socket_authentication_failed() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    tokio::spawn(async move {
+        let mut socket = server.accept("/options").await;
+        TestServer::send(
+            &mut socket,
+            serde_json::json!([{"ev": "status", "status": "connected", "message": "Connected Successfully"}]),
+        )
+        .await;
+        TestServer::receive(&mut socket).await;
+        TestServer::send(
+            &mut socket,
+            serde_json::json!([{"ev": "status", "status": "auth_failed", "message": "authentication failed"}]),
+        )
+        .await;
+    });
+    let client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        cluster: Cluster::Options,
+        ..WebSocketConfig::default()
+    })
+    .await;
+    match client {
+        Err(ErrorCode::WebSocketAuthenticationError(details)) => {
+            assert_eq!(details.message.unwrap(), "authentication failed")
+        }
+        _ => panic!("Expected an authentication error"),
+    }
+}
+
+#[tokio::test]
+async fn test_web_socket_aggregates() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/crypto").await;
+        TestServer::authenticate(&mut socket).await;
+        let subscribe = TestServer::receive(&mut socket).await;
+        assert_eq!(subscribe["params"], "XA.X:BTC-USD");
+        let subscribe = TestServer::receive(&mut socket).await;
+        assert_eq!(subscribe["params"], "XAS.*");
+        TestServer::send(
+            &mut socket,
+            serde_json::json!([
+                {"ev": "XA", "pair": "BTC-USD", "v": 1.5, "vw": 27000.5, "o": 27000.0, "c": 27001.0, "h": 27002.0, "l": 26999.0, "s": 1610144640000i64, "e": 1610144700000i64},
+                {"ev": "XAS", "pair": "ETH-USD", "v": 2.0, "o": 1800.0, "c": 1801.0, "h": 1801.0, "l": 1800.0, "s": 1610144640000i64, "e": 1610144641000i64}
+            ]),
+        )
+        .await;
+        socket.close(None).await.unwrap();
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        cluster: Cluster::Crypto,
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    client.next_event().await;
+    client.next_event().await;
+    assert!(matches!(
+        client.subscribe(Channel::Minute, vec![String::from("X:BTCUSD")]),
+        Err(ErrorCode::TickerError)
+    ));
+    assert!(matches!(
+        client.subscribe(Channel::Trades, vec![String::from("X:BTC-USD")]),
+        Err(ErrorCode::ChannelNotValidForCluster)
+    ));
+    client
+        .subscribe(Channel::Minute, vec![String::from("X:BTC-USD")])
+        .unwrap();
+    client
+        .subscribe(Channel::Second, vec![String::from("*")])
+        .unwrap();
+    match client.next().await {
+        Some(Ok(Event::MinuteAggregate(aggregate))) => {
+            assert_eq!(aggregate.ticker.clone().unwrap(), "BTC-USD");
+            let bar = aggregate.to_bar();
+            assert_eq!(bar.high.unwrap(), 27002.0);
+            assert_eq!(bar.timestamp.unwrap(), 1610144640000);
+        }
+        other => panic!("Expected a minute aggregate event, got {:?}", other),
+    }
+    assert!(matches!(
+        client.next().await,
+        Some(Ok(Event::SecondAggregate(_)))
+    ));
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_web_socket_reconnect() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        TestServer::receive(&mut socket).await;
+        TestServer::receive(&mut socket).await;
+        TestServer::receive(&mut socket).await;
+        socket.close(None).await.unwrap();
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        let replay = TestServer::receive(&mut socket).await;
+        assert_eq!(replay["action"], "subscribe");
+        assert_eq!(replay["params"], "T.AAPL,AM.*");
+        TestServer::send(
+            &mut socket,
+            serde_json::json!([{"ev": "T", "sym": "AAPL", "p": 150.25, "s": 100}]),
+        )
+        .await;
+        socket.close(None).await.unwrap();
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        reconnect: RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(10),
+            jitter: false,
+            ..RetryPolicy::default()
+        },
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    client
+        .subscribe(
+            Channel::Trades,
+            vec![String::from("AAPL"), String::from("MSFT")],
+        )
+        .unwrap();
+    client
+        .subscribe(Channel::Minute, vec![String::from("*")])
+        .unwrap();
+    client
+        .unsubscribe(Channel::Trades, vec![String::from("MSFT")])
+        .unwrap();
+    let mut statuses = Vec::new();
+    loop {
+        match client.next_event().await {
+            Some(Ok(Event::Status(status))) => statuses.push(status.status.unwrap()),
+            Some(Ok(Event::Trade(trade))) => {
+                assert_eq!(trade.ticker.unwrap(), "AAPL");
+                break;
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+    assert_eq!(
+        statuses,
+        vec![
+            "connected",
+            "auth_success",
+            "disconnected",
+            "connected",
+            "auth_success",
+            "resubscribed"
+        ]
+    );
+    drop(client);
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_web_socket_heartbeat() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        //Stop reading so pings go unanswered
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        drop(socket);
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        heartbeat: Duration::from_millis(50),
+        reconnect: RetryPolicy::none(),
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    client.next_event().await;
+    client.next_event().await;
+    match client.next_event().await {
+        Some(Ok(Event::Status(status))) => assert_eq!(status.status.unwrap(), "disconnected"),
+        other => panic!("Expected a disconnected status, got {:?}", other),
+    }
+    assert!(matches!(
+        client.next_event().await,
+        Some(Err(ErrorCode::Timeout(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_web_socket_overflow() {
+    use crate::web_socket::test_server::TestServer;
+
+    let trades = |prices: std::ops::RangeInclusive<i64>| {
+        serde_json::Value::Array(
+            prices
+                .map(|price| serde_json::json!({"ev": "T", "sym": "AAPL", "p": price, "s": 100}))
+                .collect(),
+        )
+    };
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        TestServer::send(&mut socket, trades(1..=10)).await;
+        TestServer::receive(&mut socket).await;
+        TestServer::send(&mut socket, trades(11..=11)).await;
+        socket.close(None).await.unwrap();
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        reconnect: RetryPolicy::none(),
+        capacity: 4,
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    //Let the burst arrive while nothing is draining the queue
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let mut prices = Vec::new();
+    for _ in 0..4 {
+        match client.next_event().await {
+            Some(Ok(Event::Status(_))) => (),
+            Some(Ok(Event::Trade(trade))) => prices.push(trade.price.unwrap()),
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+    assert_eq!(prices, vec![1.0, 2.0]);
+    client
+        .subscribe(Channel::Trades, vec![String::from("MSFT")])
+        .unwrap();
+    assert!(matches!(
+        client.next_event().await,
+        Some(Ok(Event::Lagged(8)))
+    ));
+    match client.next_event().await {
+        Some(Ok(Event::Trade(trade))) => assert_eq!(trade.price.unwrap(), 11.0),
+        other => panic!("Expected a trade event, got {:?}", other),
+    }
+    handle.await.unwrap();
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        TestServer::send(&mut socket, trades(1..=5)).await;
+        socket.close(None).await.unwrap();
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        reconnect: RetryPolicy::none(),
+        capacity: 2,
+        overflow: Overflow::Backpressure,
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    client.next_event().await;
+    client.next_event().await;
+    for price in 1..=5 {
+        match client.next_event().await {
+            Some(Ok(Event::Trade(trade))) => assert_eq!(trade.price.unwrap(), price as f64),
+            other => panic!("Expected a trade event, got {:?}", other),
+        }
+    }
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_web_socket_backpressure_heartbeat() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        TestServer::send(
+            &mut socket,
+            serde_json::Value::Array(
+                (1..=5)
+                    .map(
+                        |price| serde_json::json!({"ev": "T", "sym": "AAPL", "p": price, "s": 100}),
+                    )
+                    .collect(),
+            ),
+        )
+        .await;
+        //Keep reading so pings are answered until the client goes away
+        while let Some(Ok(_)) = socket.next().await {}
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        heartbeat: Duration::from_millis(50),
+        reconnect: RetryPolicy::none(),
+        capacity: 2,
+        overflow: Overflow::Backpressure,
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    //Hold the queue full for several heartbeat intervals
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    client.next_event().await;
+    client.next_event().await;
+    for price in 1..=5 {
+        match client.next_event().await {
+            Some(Ok(Event::Trade(trade))) => assert_eq!(trade.price.unwrap(), price as f64),
+            other => panic!("Expected a trade event, got {:?}", other),
+        }
+    }
+    assert!(
+        tokio::time::timeout(Duration::from_millis(300), client.next_event())
+            .await
+            .is_err()
+    );
+    drop(client);
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_web_socket_reconnect_without_subscriptions() {
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        socket.close(None).await.unwrap();
+        let mut socket = server.accept("/stocks").await;
+        TestServer::authenticate(&mut socket).await;
+        TestServer::send(
+            &mut socket,
+            serde_json::json!([{"ev": "T", "sym": "AAPL", "p": 150.25, "s": 100}]),
+        )
+        .await;
+        socket.close(None).await.unwrap();
+    });
+
+    let mut client = WebSocketClient::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        reconnect: RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(10),
+            jitter: false,
+            ..RetryPolicy::default()
+        },
+        ..WebSocketConfig::default()
+    })
+    .await
+    .unwrap();
+    let mut statuses = Vec::new();
+    loop {
+        match client.next_event().await {
+            Some(Ok(Event::Status(status))) => statuses.push(status.status.unwrap()),
+            Some(Ok(Event::Trade(_))) => break,
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+    //Nothing was replayed, so there is no resubscribed status
+    assert_eq!(
+        statuses,
+        vec![
+            "connected",
+            "auth_success",
+            "disconnected",
+            "connected",
+            "auth_success"
+        ]
+    );
+    drop(client);
+    handle.await.unwrap();
+}
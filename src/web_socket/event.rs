@@ -0,0 +1,148 @@
+use crate::data_types::{
+    aggregate::Aggregate, connection_status::ConnectionStatus, fair_market_value::FairMarketValue,
+    index_value::IndexValue, l2::L2, quote::Quote, trade::Trade, Parse,
+};
+use crate::rest::error::{ErrorCode, ErrorDetails};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Event {
+    Status(ConnectionStatus),
+    Trade(Trade),
+    Quote(Quote),
+    SecondAggregate(Aggregate),
+    MinuteAggregate(Aggregate),
+    L2(L2),
+    FairMarketValue(FairMarketValue),
+    Value(IndexValue),
+    Unknown(serde_json::Value),
+    Lagged(u64), //Events dropped because the consumer fell behind, sent by the client rather than Polygon
+}
+
+impl Parse for Event {
+    fn parse(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        match Self::string_parse(map, vec!["ev"]).as_deref() {
+            Some("status") => Event::Status(ConnectionStatus::parse(map)),
+            Some("T") => Event::Trade(Trade::parse(map)),
+            Some("Q") => Event::Quote(Quote::parse(map)),
+            Some("A" | "CAS" | "XAS") => Event::SecondAggregate(Aggregate::parse(map)),
+            Some("AM" | "CA" | "XA") => Event::MinuteAggregate(Aggregate::parse(map)),
+            Some("XL2") => Event::L2(L2::parse(map)),
+            Some("FMV") => Event::FairMarketValue(FairMarketValue::parse(map)),
+            Some("V") => Event::Value(IndexValue::parse(map)),
+            _ => Event::Unknown(serde_json::Value::Object(map.clone())),
+        }
+    }
+}
+
+impl Event {
+    //Polygon batches events into a JSON array per frame
+    pub fn parse_message(text: &str) -> Result<Vec<Event>, ErrorCode> {
+        match serde_json::from_str::<serde_json::Value>(text)? {
+            serde_json::Value::Array(values) => Ok(values
+                .iter()
+                .filter_map(|v| v.as_object())
+                .map(Event::parse)
+                .collect()),
+            serde_json::Value::Object(map) => Ok(vec![Event::parse(&map)]),
+            _ => Err(ErrorCode::JSONParseError(ErrorDetails {
+                message: Some(text.to_string()),
+                ..ErrorDetails::default()
+            })),
+        }
+    }
+}
+
+#[test]
+fn test_parse_message() {
+    let events = Event::parse_message(
+        r#"[{"ev":"status","status":"connected","message":"Connected Successfully"},{"ev":"T","sym":"MSFT","x":4,"i":"12345","z":3,"p":114.125,"s":100,"c":[0,12],"t":1536036818784,"q":3681328},{"ev":"Q","sym":"MSFT","bx":4,"bp":114.125,"bs":100,"ax":7,"ap":114.128,"as":160,"c":0,"i":[604],"t":1536036818784,"q":50385480,"z":3},{"ev":"LULD"}]"#,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 4);
+    match &events[0] {
+        Event::Status(status) => {
+            assert_eq!(status.status.clone().unwrap(), "connected");
+            assert_eq!(status.message.clone().unwrap(), "Connected Successfully");
+        }
+        _ => panic!("Expected a status event"),
+    }
+    match &events[1] {
+        Event::Trade(trade) => {
+            assert_eq!(trade.ticker.clone().unwrap(), "MSFT");
+            assert_eq!(trade.exchange_id.unwrap(), 4);
+            assert_eq!(trade.trade_id.clone().unwrap(), "12345");
+            assert_eq!(trade.tape.unwrap(), 3);
+            assert_eq!(trade.price.unwrap(), 114.125);
+            assert_eq!(trade.size.unwrap(), 100);
+            assert_eq!(trade.conditions.clone().unwrap(), vec![0, 12]);
+            assert_eq!(trade.sip_timestamp.unwrap(), 1536036818784);
+            assert_eq!(trade.sequence_number.unwrap(), 3681328);
+        }
+        _ => panic!("Expected a trade event"),
+    }
+    match &events[2] {
+        Event::Quote(quote) => {
+            assert_eq!(quote.ticker.clone().unwrap(), "MSFT");
+            assert_eq!(quote.bid_exchange_id.unwrap(), 4);
+            assert_eq!(quote.bid.unwrap(), 114.125);
+            assert_eq!(quote.bid_size.unwrap(), 100);
+            assert_eq!(quote.ask_exchange_id.unwrap(), 7);
+            assert_eq!(quote.ask.unwrap(), 114.128);
+            assert_eq!(quote.ask_size.unwrap(), 160);
+            assert_eq!(quote.conditions.clone().unwrap(), vec![0]);
+            assert_eq!(quote.indicators.clone().unwrap(), vec![604]);
+            assert_eq!(quote.last_updated.unwrap(), 1536036818784);
+            assert_eq!(quote.sequence_number.unwrap(), 50385480);
+            assert_eq!(quote.tape.unwrap(), 3);
+        }
+        _ => panic!("Expected a quote event"),
+    }
+    assert!(matches!(events[3], Event::Unknown(_)));
+    let events = Event::parse_message(
+        r#"[{"ev":"A","sym":"SPCE","v":200,"av":8642007,"op":25.66,"vw":25.3981,"o":25.39,"c":25.39,"h":25.39,"l":25.39,"a":25.3714,"z":50,"s":1610144868000,"e":1610144869000},{"ev":"CA","pair":"USD/EUR","o":0.8687,"c":0.86889,"h":0.86889,"l":0.8686,"v":20,"s":1539145740000},{"ev":"XAS","pair":"BTC-USD","v":0.5,"vw":27000.5,"z":1,"o":27000.0,"c":27001.0,"h":27001.0,"l":27000.0,"s":1610144868000,"e":1610144869000}]"#,
+    )
+    .unwrap();
+    match &events[0] {
+        Event::SecondAggregate(aggregate) => {
+            assert_eq!(aggregate.ticker.clone().unwrap(), "SPCE");
+            assert_eq!(aggregate.accumulated_volume.unwrap(), 8642007.0);
+            assert_eq!(aggregate.day_volume_weighted.unwrap(), 25.3714);
+            assert_eq!(aggregate.end_timestamp.unwrap(), 1610144869000);
+        }
+        _ => panic!("Expected a second aggregate event"),
+    }
+    match &events[1] {
+        Event::MinuteAggregate(aggregate) => {
+            assert_eq!(aggregate.ticker.clone().unwrap(), "USD/EUR");
+            assert_eq!(aggregate.close.unwrap(), 0.86889);
+        }
+        _ => panic!("Expected a minute aggregate event"),
+    }
+    assert!(matches!(events[2], Event::SecondAggregate(_)));
+    let events = Event::parse_message(
+        r#"{"ev":"XL2","pair":"BTC-USD","t":1598045632000,"x":1,"b":[[11450.38,0.0128]],"a":[[11450.64,0.0009]]}"#,
+    )
+    .unwrap();
+    match &events[0] {
+        Event::L2(l2) => {
+            assert_eq!(l2.ticker.clone().unwrap(), "BTC-USD");
+            assert_eq!(l2.asks.clone().unwrap()[0].price.unwrap(), 11450.64);
+        }
+        _ => panic!("Expected a level 2 event"),
+    }
+    let events = Event::parse_message(
+        r#"[{"ev":"FMV","fmv":189.22,"sym":"AAPL","t":1678220098130},{"ev":"V","val":3988.5,"T":"I:SPX","t":1678220098130}]"#,
+    )
+    .unwrap();
+    match &events[0] {
+        Event::FairMarketValue(fmv) => assert_eq!(fmv.fair_market_value.unwrap(), 189.22),
+        _ => panic!("Expected a fair market value event"),
+    }
+    match &events[1] {
+        Event::Value(value) => assert_eq!(value.ticker.clone().unwrap(), "I:SPX"),
+        _ => panic!("Expected an index value event"),
+    }
+    assert!(Event::parse_message("1").is_err());
+    assert!(Event::parse_message("not json").is_err());
+}
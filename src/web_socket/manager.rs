@@ -0,0 +1,263 @@
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{
+    client::{WebSocketClient, WebSocketConfig},
+    event::Event,
+    parameters::{Channel, Cluster},
+};
+use futures::{Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+//One socket per cluster, opened on the first subscription routed to it
+pub struct StreamManager {
+    config: WebSocketConfig,
+    clients: Vec<WebSocketClient>,
+    next: usize,
+}
+
+impl StreamManager {
+    pub fn new(api_key: &str) -> Self {
+        Self::with_config(WebSocketConfig {
+            api_key: api_key.to_string(),
+            ..WebSocketConfig::default()
+        })
+    }
+
+    //The cluster in the config is ignored, every cluster shares the rest of the settings
+    pub fn with_config(config: WebSocketConfig) -> Self {
+        Self {
+            config,
+            clients: Vec::new(),
+            next: 0,
+        }
+    }
+
+    pub fn clusters(&self) -> Vec<Cluster> {
+        self.clients.iter().map(|c| c.cluster()).collect()
+    }
+
+    //Each cluster is connected and subscribed on its own, so the result for every cluster routed to is returned
+    //A cluster that fails leaves the others subscribed, only a channel missing from a cluster fails the whole call
+    pub async fn subscribe(
+        &mut self,
+        channel: Channel,
+        tickers: Vec<String>,
+    ) -> Result<Vec<(Cluster, Result<(), ErrorCode>)>, ErrorCode> {
+        let mut results = Vec::new();
+        for (cluster, tickers) in Self::route(&channel, tickers)? {
+            let result = match self.connect(cluster).await {
+                Ok(()) => match self.client(cluster) {
+                    Some(client) => client.subscribe(channel, tickers),
+                    None => Err(ErrorCode::WebSocketClosed),
+                },
+                Err(e) => Err(e),
+            };
+            results.push((cluster, result));
+        }
+        Ok(results)
+    }
+
+    //Wildcards carry no prefix to route on, so the cluster is given explicitly
+    pub async fn subscribe_cluster(
+        &mut self,
+        cluster: Cluster,
+        channel: Channel,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        channel.prefix(&cluster)?;
+        self.connect(cluster).await?;
+        match self.client(cluster) {
+            Some(client) => client.subscribe(channel, tickers),
+            None => Err(ErrorCode::WebSocketClosed),
+        }
+    }
+
+    pub fn unsubscribe(&self, channel: Channel, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        for (cluster, tickers) in Self::route(&channel, tickers)? {
+            if let Some(client) = self.client(cluster) {
+                client.unsubscribe(channel, tickers)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn unsubscribe_cluster(
+        &self,
+        cluster: Cluster,
+        channel: Channel,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        match self.client(cluster) {
+            Some(client) => client.unsubscribe(channel, tickers),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn next_event(&mut self) -> Option<(Cluster, Result<Event, ErrorCode>)> {
+        self.next().await
+    }
+
+    //Groups the tickers by cluster in the order they were given, checking the channel exists on each
+    fn route(
+        channel: &Channel,
+        tickers: Vec<String>,
+    ) -> Result<Vec<(Cluster, Vec<String>)>, ErrorCode> {
+        let mut routes: Vec<(Cluster, Vec<String>)> = Vec::new();
+        for ticker in tickers {
+            let cluster = Cluster::from_ticker(&ticker);
+            match routes.iter_mut().find(|(c, _)| *c == cluster) {
+                Some((_, tickers)) => tickers.push(ticker),
+                None => {
+                    channel.prefix(&cluster)?;
+                    routes.push((cluster, vec![ticker]));
+                }
+            }
+        }
+        Ok(routes)
+    }
+
+    async fn connect(&mut self, cluster: Cluster) -> Result<(), ErrorCode> {
+        if self.client(cluster).is_some() {
+            return Ok(());
+        }
+        let client = WebSocketClient::with_config(WebSocketConfig {
+            cluster,
+            ..self.config.clone()
+        })
+        .await?;
+        self.clients.push(client);
+        Ok(())
+    }
+
+    fn client(&self, cluster: Cluster) -> Option<&WebSocketClient> {
+        self.clients.iter().find(|c| c.cluster() == cluster)
+    }
+}
+
+//Polls the clusters round robin so a busy feed cannot starve the others, ends once every socket has ended
+impl Stream for StreamManager {
+    type Item = (Cluster, Result<Event, ErrorCode>);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut polled = 0;
+        while polled < self.clients.len() {
+            let index = (self.next + polled) % self.clients.len();
+            let client = &mut self.clients[index];
+            let cluster = client.cluster();
+            match client.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => {
+                    self.next = (index + 1) % self.clients.len();
+                    return Poll::Ready(Some((cluster, event)));
+                }
+                Poll::Ready(None) => {
+                    self.clients.remove(index);
+                }
+                Poll::Pending => polled += 1,
+            }
+        }
+        if self.clients.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_stream_manager() {
+    use crate::tools::retry::RetryPolicy;
+    use crate::web_socket::test_server::TestServer;
+
+    let server = TestServer::start().await;
+    let base_url = server.url();
+    let handle = tokio::spawn(async move {
+        let mut stocks = server.accept("/stocks").await;
+        TestServer::authenticate(&mut stocks).await;
+        let mut options = server.accept("/options").await;
+        TestServer::authenticate(&mut options).await;
+        let subscribe = TestServer::receive(&mut stocks).await;
+        assert_eq!(subscribe["params"], "T.AAPL,T.MSFT");
+        let subscribe = TestServer::receive(&mut options).await;
+        assert_eq!(subscribe["params"], "T.O:AAPL230421C00200000");
+        TestServer::send(
+            &mut options,
+            serde_json::json!([{"ev": "T", "sym": "O:AAPL230421C00200000", "p": 1.25, "s": 1}]),
+        )
+        .await;
+        TestServer::send(
+            &mut stocks,
+            serde_json::json!([{"ev": "T", "sym": "AAPL", "p": 150.25, "s": 100}]),
+        )
+        .await;
+        let unsubscribe = TestServer::receive(&mut options).await;
+        assert_eq!(unsubscribe["action"], "unsubscribe");
+        assert_eq!(unsubscribe["params"], "T.O:AAPL230421C00200000");
+    });
+
+    let mut manager = StreamManager::with_config(WebSocketConfig {
+        api_key: TestServer::API_KEY.to_string(),
+        base_url,
+        reconnect: RetryPolicy::none(),
+        ..WebSocketConfig::default()
+    });
+    assert!(matches!(
+        manager
+            .subscribe(
+                Channel::Quotes,
+                vec![String::from("AAPL"), String::from("I:SPX")]
+            )
+            .await,
+        Err(ErrorCode::ChannelNotValidForCluster)
+    ));
+    assert!(manager.clusters().is_empty());
+    let results = manager
+        .subscribe(
+            Channel::Trades,
+            vec![
+                String::from("AAPL"),
+                String::from("O:AAPL230421C00200000"),
+                String::from("MSFT"),
+            ],
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+    assert_eq!(manager.clusters(), vec![Cluster::Stocks, Cluster::Options]);
+    //The stock subscription goes through even though the option ticker is rejected
+    let results = manager
+        .subscribe(
+            Channel::Trades,
+            vec![String::from("GOOG"), String::from("O:GOOG")],
+        )
+        .await
+        .unwrap();
+    assert_eq!(results[0].0, Cluster::Stocks);
+    assert!(results[0].1.is_ok());
+    assert_eq!(results[1].0, Cluster::Options);
+    assert!(results[1].1.is_err());
+    let mut trades = Vec::new();
+    while trades.len() < 2 {
+        match manager.next_event().await {
+            Some((cluster, Ok(Event::Trade(trade)))) => {
+                trades.push((cluster, trade.ticker.unwrap()))
+            }
+            Some((_, Ok(Event::Status(_)))) => (),
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+    trades.sort_by_key(|(_, ticker)| ticker.clone());
+    assert_eq!(
+        trades,
+        vec![
+            (Cluster::Stocks, String::from("AAPL")),
+            (Cluster::Options, String::from("O:AAPL230421C00200000"))
+        ]
+    );
+    manager
+        .unsubscribe(Channel::Trades, vec![String::from("O:AAPL230421C00200000")])
+        .unwrap();
+    handle.await.unwrap();
+}
@@ -6,4 +6,26 @@ pub mod second;
 pub mod trades;
 pub mod value;
 
-//To be implemented
+use fmv::FairMarketValueSubscription;
+use l2::L2Subscription;
+use minute::MinuteAggregatesSubscription;
+use quotes::QuotesSubscription;
+use second::SecondAggregatesSubscription;
+use trades::TradesSubscription;
+use value::ValueSubscription;
+
+pub struct MarketSubscription {}
+
+impl FairMarketValueSubscription for MarketSubscription {}
+
+impl L2Subscription for MarketSubscription {}
+
+impl MinuteAggregatesSubscription for MarketSubscription {}
+
+impl QuotesSubscription for MarketSubscription {}
+
+impl SecondAggregatesSubscription for MarketSubscription {}
+
+impl TradesSubscription for MarketSubscription {}
+
+impl ValueSubscription for MarketSubscription {}
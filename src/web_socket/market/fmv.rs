@@ -1 +1,19 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+//Fair market value is only published to Business plan subscribers
+pub trait FairMarketValueSubscription {
+    fn subscribe_fair_market_value(
+        client: &WebSocketClient,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::FairMarketValue, tickers)
+    }
+
+    fn unsubscribe_fair_market_value(
+        client: &WebSocketClient,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::FairMarketValue, tickers)
+    }
+}
@@ -1 +1,13 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+//Only the crypto cluster publishes level 2 books, apply each event to a tools::order_book::OrderBook
+pub trait L2Subscription {
+    fn subscribe_l2(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::L2, tickers)
+    }
+
+    fn unsubscribe_l2(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::L2, tickers)
+    }
+}
@@ -1 +1,18 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+pub trait MinuteAggregatesSubscription {
+    fn subscribe_minute_aggregates(
+        client: &WebSocketClient,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::Minute, tickers)
+    }
+
+    fn unsubscribe_minute_aggregates(
+        client: &WebSocketClient,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::Minute, tickers)
+    }
+}
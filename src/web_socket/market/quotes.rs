@@ -1 +1,12 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+pub trait QuotesSubscription {
+    fn subscribe_quotes(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::Quotes, tickers)
+    }
+
+    fn unsubscribe_quotes(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::Quotes, tickers)
+    }
+}
@@ -1 +1,18 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+pub trait SecondAggregatesSubscription {
+    fn subscribe_second_aggregates(
+        client: &WebSocketClient,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::Second, tickers)
+    }
+
+    fn unsubscribe_second_aggregates(
+        client: &WebSocketClient,
+        tickers: Vec<String>,
+    ) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::Second, tickers)
+    }
+}
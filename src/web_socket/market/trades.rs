@@ -1 +1,12 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+pub trait TradesSubscription {
+    fn subscribe_trades(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::Trades, tickers)
+    }
+
+    fn unsubscribe_trades(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::Trades, tickers)
+    }
+}
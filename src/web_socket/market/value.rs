@@ -1 +1,12 @@
-//To be implemented
+use crate::rest::error::ErrorCode;
+use crate::web_socket::{client::WebSocketClient, parameters::Channel};
+
+pub trait ValueSubscription {
+    fn subscribe_value(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.subscribe(Channel::Value, tickers)
+    }
+
+    fn unsubscribe_value(client: &WebSocketClient, tickers: Vec<String>) -> Result<(), ErrorCode> {
+        client.unsubscribe(Channel::Value, tickers)
+    }
+}
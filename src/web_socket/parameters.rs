@@ -0,0 +1,114 @@
+use crate::rest::{error::ErrorCode, parameters::TickerTypes};
+use crate::tools::regex_patterns::RegexPatterns;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+//Polygon serves every asset class from its own endpoint, mirroring TickerType
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq, Eq, Hash)]
+pub enum Cluster {
+    Stocks,
+    Options,
+    Forex,
+    Crypto,
+    Indices,
+}
+
+impl Cluster {
+    pub fn ticker_types(&self) -> TickerTypes {
+        match self {
+            Cluster::Stocks => TickerTypes::stocks(),
+            Cluster::Options => TickerTypes::options(),
+            Cluster::Forex => TickerTypes::forex(),
+            Cluster::Crypto => TickerTypes::crypto(),
+            Cluster::Indices => TickerTypes::indicies(),
+        }
+    }
+
+    //Follows the ticker prefixes, anything without one is a stock
+    pub fn from_ticker(ticker: &str) -> Cluster {
+        if RegexPatterns::options_check().is_match(ticker) {
+            Cluster::Options
+        } else if RegexPatterns::forex_check().is_match(ticker) {
+            Cluster::Forex
+        } else if RegexPatterns::crypto_check().is_match(ticker) {
+            Cluster::Crypto
+        } else if RegexPatterns::indicies_check().is_match(ticker) {
+            Cluster::Indices
+        } else {
+            Cluster::Stocks
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Display, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Trades,
+    Quotes,
+    Second,
+    Minute,
+    L2,
+    FairMarketValue,
+    Value,
+}
+
+impl Channel {
+    pub fn prefix(&self, cluster: &Cluster) -> Result<&'static str, ErrorCode> {
+        match (self, cluster) {
+            (Channel::Trades, Cluster::Stocks | Cluster::Options) => Ok("T"),
+            (Channel::Quotes, Cluster::Stocks | Cluster::Options) => Ok("Q"),
+            (Channel::Second, Cluster::Stocks | Cluster::Options | Cluster::Indices) => Ok("A"),
+            (Channel::Second, Cluster::Forex) => Ok("CAS"),
+            (Channel::Second, Cluster::Crypto) => Ok("XAS"),
+            (Channel::Minute, Cluster::Stocks | Cluster::Options | Cluster::Indices) => Ok("AM"),
+            (Channel::Minute, Cluster::Forex) => Ok("CA"),
+            (Channel::Minute, Cluster::Crypto) => Ok("XA"),
+            (Channel::L2, Cluster::Crypto) => Ok("XL2"),
+            (
+                Channel::FairMarketValue,
+                Cluster::Stocks | Cluster::Options | Cluster::Forex | Cluster::Crypto,
+            ) => Ok("FMV"),
+            (Channel::Value, Cluster::Indices) => Ok("V"),
+            _ => Err(ErrorCode::ChannelNotValidForCluster),
+        }
+    }
+}
+
+#[test]
+fn test_channel_prefix() {
+    assert_eq!(Channel::Trades.prefix(&Cluster::Stocks), Ok("T"));
+    assert_eq!(Channel::Quotes.prefix(&Cluster::Options), Ok("Q"));
+    assert_eq!(Channel::Second.prefix(&Cluster::Indices), Ok("A"));
+    assert_eq!(Channel::Minute.prefix(&Cluster::Stocks), Ok("AM"));
+    assert_eq!(Channel::Second.prefix(&Cluster::Forex), Ok("CAS"));
+    assert_eq!(Channel::Minute.prefix(&Cluster::Crypto), Ok("XA"));
+    assert_eq!(Channel::L2.prefix(&Cluster::Crypto), Ok("XL2"));
+    assert_eq!(
+        Channel::FairMarketValue.prefix(&Cluster::Options),
+        Ok("FMV")
+    );
+    assert_eq!(Channel::Value.prefix(&Cluster::Indices), Ok("V"));
+    assert_eq!(
+        Channel::Value.prefix(&Cluster::Stocks),
+        Err(ErrorCode::ChannelNotValidForCluster)
+    );
+    assert_eq!(
+        Channel::L2.prefix(&Cluster::Stocks),
+        Err(ErrorCode::ChannelNotValidForCluster)
+    );
+    assert_eq!(
+        Channel::Trades.prefix(&Cluster::Indices),
+        Err(ErrorCode::ChannelNotValidForCluster)
+    );
+}
+
+#[test]
+fn test_cluster_from_ticker() {
+    assert_eq!(Cluster::from_ticker("AAPL"), Cluster::Stocks);
+    assert_eq!(
+        Cluster::from_ticker("O:AAPL230421C00200000"),
+        Cluster::Options
+    );
+    assert_eq!(Cluster::from_ticker("C:EUR-USD"), Cluster::Forex);
+    assert_eq!(Cluster::from_ticker("X:BTC-USD"), Cluster::Crypto);
+    assert_eq!(Cluster::from_ticker("I:SPX"), Cluster::Indices);
+}
@@ -0,0 +1,70 @@
+use futures::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{
+    tungstenite::{
+        handshake::server::{Request, Response},
+        Message,
+    },
+    WebSocketStream,
+};
+
+//Local stand-in for the Polygon WebSocket servers
+pub struct TestServer {
+    listener: TcpListener,
+}
+
+impl TestServer {
+    pub const API_KEY: &'static str = "ak123456789012345678901234567890";
+
+    pub async fn start() -> Self {
+        Self {
+            listener: TcpListener::bind("127.0.0.1:0").await.unwrap(),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.listener.local_addr().unwrap())
+    }
+
+    //Accepts the next connection and checks it was made to the expected cluster path
+    pub async fn accept(&self, path: &str) -> WebSocketStream<TcpStream> {
+        let (stream, _) = self.listener.accept().await.unwrap();
+        let path = path.to_string();
+        tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response: Response| {
+            assert_eq!(request.uri().path(), path);
+            Ok(response)
+        })
+        .await
+        .unwrap()
+    }
+
+    pub async fn authenticate(socket: &mut WebSocketStream<TcpStream>) {
+        Self::send(
+            socket,
+            serde_json::json!([{"ev": "status", "status": "connected", "message": "Connected Successfully"}]),
+        )
+        .await;
+        let auth = Self::receive(socket).await;
+        assert_eq!(auth["action"], "auth");
+        assert_eq!(auth["params"], Self::API_KEY);
+        Self::send(
+            socket,
+            serde_json::json!([{"ev": "status", "status": "auth_success", "message": "authenticated"}]),
+        )
+        .await;
+    }
+
+    pub async fn send(socket: &mut WebSocketStream<TcpStream>, value: serde_json::Value) {
+        socket.send(Message::Text(value.to_string())).await.unwrap();
+    }
+
+    pub async fn receive(socket: &mut WebSocketStream<TcpStream>) -> serde_json::Value {
+        loop {
+            match socket.next().await {
+                Some(Ok(Message::Text(text))) => return serde_json::from_str(&text).unwrap(),
+                Some(Ok(_)) => continue,
+                other => panic!("Expected a text message, got {:?}", other),
+            }
+        }
+    }
+}